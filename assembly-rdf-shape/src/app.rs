@@ -1,17 +1,75 @@
 /// Proporciona funciones y estructuras necesarias para realizar solicitudes HTTP, manejar las respuestas y
 /// procesar los datos recibidos.
 pub(crate) mod api;
+/// Valida varios ficheros de datos con el mismo esquema y ShapeMap con una concurrencia limitada.
+pub(crate) mod batch_validation;
+/// Representa los errores de sintaxis de las entradas con su línea y columna.
+pub(crate) mod diagnostics;
+/// Mantiene los documentos abiertos en las pestañas de los paneles de edición.
+pub(crate) mod document_tabs;
 /// Gestiona los ejemplos de datos utilizados dentro de la aplicación.
-mod examples_manager;
+pub(crate) mod examples_manager;
+/// Detecta el formato de las entradas RDF, ShEx y ShapeMap a partir de su contenido.
+pub(crate) mod format_detection;
+/// Vuelve a validar solo los nodos a los que afecta un cambio en los datos.
+pub(crate) mod incremental_validation;
+/// Analiza documentos N-Triples para trabajar con las tripletas de los datos RDF.
+pub(crate) mod ntriples;
+/// Expande los selectores de consulta de los ShapeMaps (patrones FOCUS y SPARQL) a nodos concretos.
+pub(crate) mod query_shapemap;
+/// Construye y coloca el grafo de los datos RDF coloreado con el resultado de validación.
+pub(crate) mod rdf_graph;
+/// Calcula las estadísticas detalladas de los datos RDF para el modal de análisis.
+pub(crate) mod rdf_statistics;
+/// Configura el tiempo máximo, los reintentos y la caché de las solicitudes a la API.
+pub(crate) mod request_policy;
+/// Guarda las respuestas de análisis y validación por el hash del contenido enviado.
+pub(crate) mod response_cache;
+/// Compara el resultado de validar los mismos datos con varias versiones del esquema.
+pub(crate) mod schema_comparison;
+/// Construye el diagrama de clases de las formas de un esquema ShEx y lo exporta a SVG y PlantUML.
+pub(crate) mod schema_diagram;
+/// Traduce esquemas entre ShEx y SHACL señalando las construcciones que no se pueden expresar.
+pub(crate) mod schema_translation;
+/// Interpreta grafos de formas e informes de validación SHACL.
+pub(crate) mod shacl;
+/// Analiza ShapeMaps en formato compacto y JSON.
+pub(crate) mod shapemap;
+/// Propone ShapeMaps a partir de los tipos de los datos RDF y las formas del esquema.
+pub(crate) mod shapemap_generator;
+/// Analiza los esquemas ShEx: formas declaradas, referencias entre formas y prefijos sin usar.
+pub(crate) mod shex_analysis;
+/// Importa los tests de validación de la batería oficial de ShEx (manifiestos de shexTest).
+pub(crate) mod shex_test_suite;
+/// Lee la información de los esquemas ShEx en formato ShExJ.
+pub(crate) mod shexj;
+/// Guarda en el navegador la biblioteca de ejemplos definidos por el usuario.
+pub(crate) mod user_examples;
+/// Guarda en el navegador las pestañas de los paneles de edición entre sesiones.
+pub(crate) mod workspace;
 
 use std::{thread::sleep, time::Duration};
 
-use crate::components::{editors::Editor, header::Header, modal::Modal, result_table::ResultTable};
-use examples_manager::{load_example, ExampleData};
+use crate::components::schema_diagram::exportText;
+use crate::components::{
+    comparison_matrix::ComparisonMatrix, editors::Editor, header::Header, modal::Modal,
+    result_table::ResultTable,
+};
+use api::ValidationParameters;
+use batch_validation::{BatchFile, BatchValidation};
+use document_tabs::DocumentTab;
+use examples_manager::{load_example, ExampleData, ExampleInfo};
+use incremental_validation::{IncrementalSummary, ValidatedData};
 use log::*;
+use ntriples::Triple;
+use request_policy::{RequestOptions, RequestPolicy, RetryStatus};
+use schema_comparison::SchemaComparison;
 use serde::{Deserialize, Serialize};
+use shex_test_suite::ShexTestCase;
 use strum_macros::{EnumIter, ToString};
+use user_examples::{UserExample, UserExampleAction};
 use wasm_bindgen::prelude::*;
+use web_sys::AbortController;
 use yew::prelude::*;
 
 /// Definición del componente principal App
@@ -19,8 +77,26 @@ pub struct App {
     link: ComponentLink<Self>,
     state: State,
     rdf_parameters: Vec<String>,
+    rdf_conversion_parameters: Vec<String>,
     shex_parameters: Vec<String>,
+    shex_conversion_parameters: Vec<String>,
     shapemap_parameters: Vec<String>,
+    inference_parameters: Vec<String>,
+    engine_parameters: Vec<String>,
+    examples: Vec<ExampleInfo>,
+    user_examples: Vec<UserExample>,
+    test_cases: Vec<ShexTestCase>,
+    active_test: Option<usize>,
+    batch: Option<BatchValidation>,
+    comparison: Option<SchemaComparison>,
+    request_id: u32,
+    abort_controller: Option<AbortController>,
+    retry_status: Option<RetryStatus>,
+    last_validation: Option<(String, ValidationParameters)>,
+    incremental: bool,
+    validated: Option<ValidatedData>,
+    incremental_summary: Option<IncrementalSummary>,
+    request_policy: RequestPolicy,
 }
 
 /// Asignación editor Yate a elemento del DOM
@@ -64,6 +140,17 @@ extern "C" {
     fn getYashe() -> String;
 }
 
+/// Recuperación del valor seleccionado en un desplegable
+#[wasm_bindgen(inline_js = "
+    export function getSelectValue(id) {
+        const element = document.getElementById(id);
+        return element ? element.value : '';
+    }
+    ")]
+extern "C" {
+    fn getSelectValue(id: &str) -> String;
+}
+
 /// ScroLL automático a elemento del DOM
 #[wasm_bindgen(inline_js = "
     export function scrollToElement(id) {
@@ -104,6 +191,8 @@ pub struct State {
     edit_value: String,
     search_text: String,
     validation_result: Option<api::ValidationResult>,
+    start_shape: Option<String>,
+    inference: String,
     api_error: String,
     show_modal: bool,
     modal_info: ModalInfo,
@@ -113,20 +202,64 @@ pub struct State {
 
 /// Enum para los mensajes que se pueden enviar al componente
 pub enum Msg {
-    /// Solicita la validación de los datos actualmente cargados en los editores.
-    Validate(String, String, String),
+    /// Solicita la validación de los datos indicados con el esquema actualmente cargado en el editor.
+    /// Contiene los datos RDF y los parámetros de la validación.
+    Validate(String, ValidationParameters),
+    /// Solicita la validación por lotes de los ficheros de datos indicados (nombre, contenido y formato) con el
+    /// esquema actual. Los parámetros son los mismos que en `Validate`, salvo el formato de los datos.
+    ValidateBatch(Vec<BatchFile>, ValidationParameters),
+    /// Resultado de la validación de uno de los ficheros del lote: identificador de la solicitud, posición del
+    /// fichero, resultado y error.
+    BatchFileValidated(u32, usize, api::ValidationResult, String),
+    /// Solicita la validación de los datos con cada una de las versiones del esquema indicadas, que sustituyen
+    /// al esquema de los parámetros. El resto de parámetros son los mismos que en `Validate`.
+    CompareSchemas(Vec<DocumentTab>, String, ValidationParameters),
+    /// Resultado de la validación con una de las versiones del esquema: identificador de la solicitud, posición
+    /// de la versión, resultado y error.
+    SchemaVersionValidated(u32, usize, api::ValidationResult, String),
     /// Resultado de una solicitud de validación.
-    /// Contiene `api::ValidationResult` con el resultado de la validación y un `String` que puede contener un mensaje de error.
-    ValidationResult(api::ValidationResult, String),
+    /// Contiene el identificador de la solicitud, `api::ValidationResult` con el resultado de la validación y un
+    /// `String` que puede contener un mensaje de error. Las respuestas de solicitudes anteriores se ignoran.
+    ValidationResult(u32, api::ValidationResult, String),
+    /// Forma inicial del esquema validado, si el ShapeMap usa asociaciones `@START`, con el identificador de la solicitud.
+    ReceiveStartShape(u32, Option<String>),
+    /// Cancela la validación en curso.
+    CancelValidation,
+    /// Repite la última validación enviándola al servidor aunque su resultado esté en la caché.
+    RevalidateWithoutCache,
+    /// Activa o desactiva la validación incremental, que solo vuelve a validar los nodos afectados por los cambios.
+    ToggleIncremental,
+    /// Resultado de una validación incremental: identificador de la solicitud, resultado combinado, error,
+    /// resumen de los nodos validados (`None` si se han validado todos) y tripletas de los datos validados, si
+    /// se han convertido a N-Triples.
+    IncrementalValidationResult(
+        u32,
+        api::ValidationResult,
+        String,
+        Option<IncrementalSummary>,
+        Option<Vec<Triple>>,
+    ),
+    /// Aviso de que una llamada de la solicitud indicada se va a repetir tras un error transitorio.
+    Retrying(u32, RetryStatus),
+    /// Guarda la nueva configuración de las solicitudes a la API.
+    UpdateRequestPolicy(RequestPolicy),
     /// Actualiza el valor actual del ShapeMap con el nuevo valor proporcionado.
     /// `String` contiene el nuevo valor de ShapeMap.
     UpdateShapeMapValue(String),
+    /// Recibe las entradas del manifiesto de ejemplos o el error si no se ha podido cargar.
+    ReceiveExamples(Result<Vec<ExampleInfo>, String>),
     /// Carga un ejemplo específico.
     /// `String` contiene el identificador del ejemplo a cargar.
     LoadExample(String),
     /// Notifica que un ejemplo ha sido cargado.
     /// `Result<ExampleData, String>` contiene los datos del ejemplo o un mensaje de error en caso de fallo.
     ExampleLoaded(Result<ExampleData, String>),
+    /// Guarda, carga, renombra, elimina, importa o exporta los ejemplos del usuario.
+    UserExample(UserExampleAction),
+    /// Importa los tests de un manifiesto de shexTest a partir de los ficheros seleccionados (nombre y contenido).
+    ImportShexTests(Vec<(String, String)>),
+    /// Carga en los editores el test de shexTest en la posición indicada.
+    LoadTestCase(usize),
     /// Cierra la alerta actualmente mostrada, por ejemplo, un mensaje de error.
     CloseAlert,
     /// Abre un modal con información detallada.
@@ -155,6 +288,8 @@ impl Component for App {
             shapemap_format: "Compact".to_string(),
             search_text: "".into(),
             validation_result: None,
+            start_shape: None,
+            inference: "NONE".to_string(),
             api_error: "".into(),
             modal_info: Default::default(),
             is_loading: false,
@@ -175,8 +310,46 @@ impl Component for App {
                 "html-rdfa11".to_string(),
                 "html-microdata".to_string(),
             ],
-            shex_parameters: vec!["ShExC".to_string(), "ShExJ".to_string()],
+            rdf_conversion_parameters: vec![
+                "Turtle".to_string(),
+                "N-Triples".to_string(),
+                "N-Quads".to_string(),
+                "TriG".to_string(),
+                "JSON-LD".to_string(),
+                "RDF/XML".to_string(),
+                "RDF/JSON".to_string(),
+            ],
+            shex_parameters: vec![
+                "ShExC".to_string(),
+                "ShExJ".to_string(),
+                "Turtle".to_string(),
+            ],
+            shex_conversion_parameters: vec![
+                "ShExC".to_string(),
+                "ShExJ".to_string(),
+                "Turtle".to_string(),
+            ],
             shapemap_parameters: vec!["Compact".to_string(), "JSON".to_string()],
+            inference_parameters: vec!["NONE".to_string(), "RDFS".to_string(), "OWL".to_string()],
+            engine_parameters: vec![
+                "ShEx".to_string(),
+                "SHACLex".to_string(),
+                "JenaSHACL".to_string(),
+            ],
+            examples: examples_manager::default_examples(),
+            user_examples: user_examples::load_library(),
+            test_cases: Vec::new(),
+            active_test: None,
+            batch: None,
+            comparison: None,
+            request_id: 0,
+            abort_controller: None,
+            retry_status: None,
+            last_validation: None,
+            incremental: false,
+            validated: None,
+            incremental_summary: None,
+            request_policy: RequestPolicy::load(),
         }
     }
 
@@ -188,28 +361,100 @@ impl Component for App {
     /// Método para manejar los mensajes enviados al componente
     fn update(&mut self, msg: Self::Message) -> ShouldRender {
         match msg {
-            Msg::Validate(rdf_param, shex_param, shapemap_param) => {
+            Msg::Validate(rdf_content, parameters) => {
+                self.validate(rdf_content, parameters, false);
+            }
+            Msg::RevalidateWithoutCache => match self.last_validation.clone() {
+                Some((rdf_content, parameters)) => self.validate(rdf_content, parameters, true),
+                None => return false,
+            },
+            Msg::ToggleIncremental => {
+                self.incremental = !self.incremental;
+            }
+            Msg::IncrementalValidationResult(request_id, result, error, summary, triples) => {
+                if request_id != self.request_id {
+                    return false;
+                }
+                self.incremental_summary = summary;
+                let should_render = self.update(Msg::ValidationResult(request_id, result, error));
+                if let Some(validated) = self.validated.as_mut() {
+                    validated.triples = triples;
+                }
+                return should_render;
+            }
+            Msg::ValidateBatch(files, parameters) => {
+                if files.is_empty() {
+                    return false;
+                }
                 self.state.api_error = "".to_string();
                 self.state.validation_result = Default::default();
                 self.state.show_result = true;
                 self.state.is_loading = true;
                 self.state.scroll_needed = true;
-                let rdf_content = getYate();
-                let shex_content = getYashe();
-                let shapemap_content = self.state.shapemap_value.clone();
-                let link = self.link.clone();
-                wasm_bindgen_futures::spawn_local(async move {
-                    let result = api::call_validation_api(
-                        rdf_content,
-                        shex_content,
-                        shapemap_content,
-                        rdf_param,
-                        shex_param,
-                        shapemap_param,
-                    )
-                    .await;
-                    link.send_message(Msg::ValidationResult(result.0, result.1));
-                });
+                self.state.start_shape = None;
+                self.state.inference = parameters.inference.clone();
+                self.active_test = None;
+                self.comparison = None;
+                self.incremental_summary = None;
+                self.begin_request();
+                self.load_start_shape(&parameters);
+                self.batch = Some(BatchValidation::new(files, parameters));
+                self.validate_next_files();
+            }
+            Msg::CompareSchemas(versions, rdf_content, parameters) => {
+                self.state.api_error = "".to_string();
+                self.state.validation_result = Default::default();
+                self.state.show_result = true;
+                self.state.is_loading = true;
+                self.state.scroll_needed = true;
+                self.state.inference = parameters.inference.clone();
+                self.active_test = None;
+                self.batch = None;
+                self.incremental_summary = None;
+                self.state.start_shape = None;
+                self.begin_request();
+                self.comparison = Some(SchemaComparison::new(versions, rdf_content, parameters));
+                self.validate_next_versions();
+            }
+            Msg::SchemaVersionValidated(request_id, index, result, error) => {
+                let comparison = match self.comparison.as_mut() {
+                    Some(comparison) if request_id == self.request_id => comparison,
+                    _ => return false,
+                };
+                self.retry_status = None;
+                comparison.record(index, result.result.shape_map, error);
+                if comparison.is_finished() {
+                    self.abort_controller = None;
+                    self.state.is_loading = false;
+                    self.state.scroll_needed = true;
+                    scrollToElement("comparison");
+                } else {
+                    self.validate_next_versions();
+                }
+            }
+            Msg::BatchFileValidated(request_id, index, mut result, error) => {
+                let batch = match self.batch.as_mut() {
+                    Some(batch) if request_id == self.request_id => batch,
+                    _ => return false,
+                };
+                self.retry_status = None;
+                batch.record(index, &mut result.result.shape_map, error);
+                let finished = batch.is_finished();
+                match self.state.validation_result.as_mut() {
+                    Some(merged) => {
+                        merged.result.valid &= result.result.valid;
+                        merged.result.shape_map.append(&mut result.result.shape_map);
+                    }
+                    None => self.state.validation_result = Some(result),
+                }
+                if finished {
+                    self.abort_controller = None;
+                    self.state.is_loading = false;
+                    self.state.scroll_needed = true;
+                    scrollToElement("result-table");
+                } else {
+                    self.validate_next_files();
+                }
             }
             Msg::CloseAlert => {
                 self.state.api_error = "".to_string();
@@ -230,20 +475,83 @@ impl Component for App {
                 self.state.shapemap_value = new_value;
                 false;
             }
-            Msg::ValidationResult(result, error) => {
+            Msg::ValidationResult(request_id, result, error) => {
+                if request_id != self.request_id {
+                    return false;
+                }
+                self.abort_controller = None;
+                self.retry_status = None;
                 self.state.is_loading = false;
                 if !error.is_empty() {
                     self.state.api_error = error;
+                    self.validated = None;
                 } else {
+                    self.validated =
+                        self.last_validation
+                            .clone()
+                            .map(|(rdf, parameters)| ValidatedData {
+                                rdf,
+                                parameters,
+                                result: result.clone(),
+                                triples: None,
+                            });
+                    if let Some(test) = self
+                        .active_test
+                        .and_then(|index| self.test_cases.get_mut(index))
+                    {
+                        test.matched = test.check(&result.result.shape_map);
+                    }
                     self.state.validation_result = Some(result);
                     self.state.scroll_needed = true;
                     scrollToElement("result-table");
                 }
             }
-            Msg::LoadExample(file) => {
+            Msg::ReceiveStartShape(request_id, start_shape) => {
+                if request_id != self.request_id {
+                    return false;
+                }
+                self.state.start_shape = start_shape;
+            }
+            Msg::CancelValidation => {
+                if let Some(controller) = self.abort_controller.take() {
+                    controller.abort();
+                }
+                // Las respuestas que lleguen después de cancelar quedan obsoletas
+                self.request_id += 1;
+                self.state.is_loading = false;
+                self.state.show_result = false;
+                self.batch = None;
+                self.comparison = None;
+            }
+            Msg::Retrying(request_id, status) => {
+                if request_id != self.request_id {
+                    return false;
+                }
+                self.retry_status = Some(status);
+            }
+            Msg::UpdateRequestPolicy(policy) => {
+                self.request_policy = policy;
+                if let Err(error) = policy.save() {
+                    warn!("{}", error);
+                }
+            }
+            Msg::ReceiveExamples(result) => match result {
+                Ok(examples) if !examples.is_empty() => self.examples = examples,
+                Ok(_) => warn!("The examples manifest is empty, using the default examples"),
+                Err(error) => warn!("{}, using the default examples", error),
+            },
+            Msg::LoadExample(id) => {
+                let example = match self.examples.iter().find(|example| example.id == id) {
+                    Some(example) => example.clone(),
+                    None => {
+                        self.state.api_error = format!("Example '{}' does not exist.", id);
+                        return true;
+                    }
+                };
+                self.active_test = None;
                 let link = self.link.clone();
                 wasm_bindgen_futures::spawn_local(async move {
-                    let result = load_example(file).await;
+                    let result = load_example(example).await;
                     link.send_message(Msg::ExampleLoaded(result));
                 });
             }
@@ -261,6 +569,117 @@ impl Component for App {
                     self.state.api_error = error;
                 }
             },
+            Msg::UserExample(action) => {
+                let mut library = self.user_examples.clone();
+                match action {
+                    UserExampleAction::Save(name) => {
+                        if name.trim().is_empty() {
+                            self.state.api_error =
+                                "Please enter a name for the example.".to_string();
+                            return true;
+                        }
+                        let selected = |id: &str, current: &str| {
+                            let value = getSelectValue(id);
+                            if value.is_empty() {
+                                current.to_string()
+                            } else {
+                                value
+                            }
+                        };
+                        let data = ExampleData {
+                            rdf: getYate(),
+                            shex: getYashe(),
+                            shapemap: self.state.shapemap_value.clone(),
+                            rdf_format: selected("select-rdf", &self.state.rdf_format),
+                            shex_format: selected("select-shex", &self.state.shex_format),
+                            shapemap_format: selected(
+                                "select-shapemap",
+                                &self.state.shapemap_format,
+                            ),
+                        };
+                        user_examples::upsert(
+                            &mut library,
+                            UserExample {
+                                name: name.trim().to_string(),
+                                data,
+                            },
+                        );
+                    }
+                    UserExampleAction::Load(index) => {
+                        if let Some(example) = library.get(index) {
+                            self.active_test = None;
+                            self.link
+                                .send_message(Msg::ExampleLoaded(Ok(example.data.clone())));
+                        }
+                        return false;
+                    }
+                    UserExampleAction::Rename(index, name) => {
+                        let name = name.trim().to_string();
+                        if name.is_empty() {
+                            return false;
+                        }
+                        if library
+                            .iter()
+                            .enumerate()
+                            .any(|(other, example)| other != index && example.name == name)
+                        {
+                            self.state.api_error =
+                                format!("There is already an example named '{}'.", name);
+                            return true;
+                        }
+                        if let Some(example) = library.get_mut(index) {
+                            example.name = name;
+                        }
+                    }
+                    UserExampleAction::Delete(index) => {
+                        if index < library.len() {
+                            library.remove(index);
+                        }
+                    }
+                    UserExampleAction::Import(json) => {
+                        if let Err(error) = user_examples::import_library(&mut library, &json) {
+                            self.state.api_error = error;
+                            return true;
+                        }
+                    }
+                    UserExampleAction::Export => {
+                        exportText(
+                            &user_examples::export_library(&library),
+                            "examples.json",
+                            "application/json",
+                        );
+                        return false;
+                    }
+                }
+                if let Err(error) = user_examples::save_library(&library) {
+                    self.state.api_error = error;
+                }
+                self.user_examples = library;
+            }
+            Msg::ImportShexTests(files) => match shex_test_suite::import_manifest(&files) {
+                Ok(imported) => {
+                    for skipped in imported.skipped.iter() {
+                        warn!("shexTest skipped: {}", skipped);
+                    }
+                    if imported.tests.is_empty() {
+                        self.state.api_error = format!(
+                            "No validation test could be imported ({} skipped). Select the manifest together with its schema, data and map files.",
+                            imported.skipped.len()
+                        );
+                    }
+                    self.test_cases = imported.tests;
+                    self.active_test = None;
+                }
+                Err(error) => self.state.api_error = error,
+            },
+            Msg::LoadTestCase(index) => {
+                if let Some(test) = self.test_cases.get(index) {
+                    self.active_test = Some(index);
+                    self.state.validation_result = None;
+                    self.link
+                        .send_message(Msg::ExampleLoaded(Ok(test.data.clone())));
+                }
+            }
             Msg::ResetExampleLoaded => {
                 self.state.example_loaded = false;
             }
@@ -268,8 +687,17 @@ impl Component for App {
         true
     }
 
-    /// Método que se llama después de que el componente se haya renderizado
+    /// Método que se llama después de que el componente se haya renderizado.
+    /// En el primer renderizado solicita el manifiesto de ejemplos.
     fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            let link = self.link.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                link.send_message(Msg::ReceiveExamples(
+                    examples_manager::load_manifest().await,
+                ));
+            });
+        }
         if self.state.scroll_needed && !first_render {
             scrollToElement("result-table");
             self.state.scroll_needed = false;
@@ -283,25 +711,46 @@ impl Component for App {
             <div class="todomvc-wrapper">
                 <section class="app">
                     <Header
+                        examples=self.examples.clone()
+                        user_examples=self.user_examples.clone()
+                        on_user_example=self.link.callback(Msg::UserExample)
+                        test_cases=self.test_cases.clone()
+                        on_import_shex_tests=self.link.callback(Msg::ImportShexTests)
+                        on_load_test_case=self.link.callback(Msg::LoadTestCase)
                         on_load_example=self.link.callback(Msg::LoadExample)
                         on_open_modal=self.link.callback(|(title, content)| Msg::OpenModal(title, content))
+                        request_policy=self.request_policy
+                        on_update_request_policy=self.link.callback(Msg::UpdateRequestPolicy)
                     />
                     <div class="content">
                     <Editor
                         shapemap_value=self.state.shapemap_value.clone()
+                        request_policy=self.request_policy
                         rdf_format=self.state.rdf_format.clone()
                         shex_format=self.state.shex_format.clone()
                         shapemap_format=self.state.shapemap_format.clone()
                         on_update_shapemap_value=self.link.callback(Msg::UpdateShapeMapValue)
-                        on_validate=self.link.callback(|(rdf_param, shex_param, shapemap_param)| Msg::Validate(rdf_param, shex_param, shapemap_param))
+                        on_validate=self.link.callback(|(rdf_content, parameters)| Msg::Validate(rdf_content, parameters))
+                        on_compare_schemas=self.link.callback(|(versions, rdf_content, parameters)| Msg::CompareSchemas(versions, rdf_content, parameters))
+                        on_validate_batch=self.link.callback(|(files, parameters)| Msg::ValidateBatch(files, parameters))
                         on_open_modal=self.link.callback(|(title, content)| Msg::OpenModal(title, content))
                         rdf_parameters=self.rdf_parameters.clone()
+                        rdf_conversion_parameters=self.rdf_conversion_parameters.clone()
                         shex_parameters=self.shex_parameters.clone()
+                        shex_conversion_parameters=self.shex_conversion_parameters.clone()
                         shapemap_parameters=self.shapemap_parameters.clone()
+                        inference_parameters=self.inference_parameters.clone()
+                        engine_parameters=self.engine_parameters.clone()
                         example_loaded=self.state.example_loaded
                         reset_example_loaded=self.link.callback(|_| Msg::ResetExampleLoaded)
+                        validation_entries=self.state.validation_result.as_ref().map(|result| result.result.shape_map.clone()).unwrap_or_default()
                     />
                         <div class="footer-options">
+                            <label class="incremental-option">
+                                <input id="incremental-validation" type="checkbox" checked=self.incremental
+                                    onclick=self.link.callback(|_| Msg::ToggleIncremental) />
+                                { "Incremental validation: after editing the data, re-validate only the affected nodes" }
+                            </label>
                         </div>
                         <div class="result-container">
                             {self.render_result()}
@@ -322,6 +771,10 @@ impl App {
                     html! {
                         <div class="spinner-container">
                             <div class="spinner"></div>
+                            <button id="cancel-validation" class="analyze-btn" onclick=self.link.callback(|_| Msg::CancelValidation)>
+                                { "Cancel" }
+                            </button>
+                            { self.render_progress() }
                         </div>
                     }
                 } else {
@@ -341,14 +794,24 @@ impl App {
                             } else {
                                 html! { <></> }
                             }}
-                            { if self.state.show_result && self.state.validation_result.is_some() {
+                            { if let (true, Some(comparison)) = (self.state.show_result, &self.comparison) {
+                                html! { <ComparisonMatrix comparison=comparison.clone() /> }
+                            } else if self.state.show_result && self.state.validation_result.is_some() {
                                 let entries = self.state.validation_result.as_ref().unwrap().result.shape_map.clone();
                                 html! {
+                                    <>
+                                    { self.render_test_outcome() }
+                                    { self.render_cache_notice() }
+                                    { self.render_incremental_summary() }
                                     <ResultTable
                                         entries={entries.clone()}
                                         search_text={self.state.search_text.clone()}
+                                        start_shape={self.state.start_shape.clone()}
+                                        inference={self.state.inference.clone()}
+                                        batch_summary={self.batch.as_ref().map(|batch| batch.summaries.clone()).unwrap_or_default()}
                                         on_open_modal={self.link.callback(|(title, content)| Msg::OpenModal(title, content))}
                                     />
+                                    </>
                                 }
                             } else if !self.state.api_error.is_empty() {
                                 html! {
@@ -366,6 +829,234 @@ impl App {
             </>
         }
     }
+
+    /// Indica que el resultado de la validación procede de la caché y permite repetirla en el servidor.
+    fn render_cache_notice(&self) -> Html {
+        match &self.state.validation_result {
+            Some(result) if result.cached && self.batch.is_none() => html! {
+                <p id="cached-result" class="cached-result">
+                    <i class="fas fa-database"></i>
+                    { " Cached result: these data were already validated with the same schema and ShapeMap. " }
+                    <button id="revalidate-without-cache" class="cached-result-btn"
+                        onclick=self.link.callback(|_| Msg::RevalidateWithoutCache)>
+                        { "Validate again" }
+                    </button>
+                </p>
+            },
+            _ => html! { <></> },
+        }
+    }
+
+    /// Indica cuántas asociaciones del ShapeMap se han vuelto a validar en la última validación incremental.
+    fn render_incremental_summary(&self) -> Html {
+        match &self.incremental_summary {
+            Some(summary) => html! {
+                <p id="incremental-summary" class="cached-result">
+                    { format!(
+                        "Incremental validation: {} changed triples, {} of {} associations re-validated.",
+                        summary.changed_triples, summary.revalidated, summary.total
+                    ) }
+                </p>
+            },
+            None => html! { <></> },
+        }
+    }
+
+    /// Renderiza el progreso de la validación en curso: ficheros o versiones validados y reintentos.
+    fn render_progress(&self) -> Html {
+        html! {
+            <>
+                { match (&self.batch, &self.comparison) {
+                    (Some(batch), _) => html! {
+                        <p id="batch-progress" class="batch-progress">
+                            { format!("Validated {} of {} files", batch.completed(), batch.summaries.len()) }
+                        </p>
+                    },
+                    (_, Some(comparison)) => html! {
+                        <p id="batch-progress" class="batch-progress">
+                            { format!("Validated {} of {} schema versions", comparison.completed(), comparison.versions.len()) }
+                        </p>
+                    },
+                    _ => html! { <></> },
+                }}
+                { match &self.retry_status {
+                    Some(status) => html! {
+                        <p id="retry-status" class="batch-progress">
+                            { format!("Retrying ({}/{})… {}", status.attempt, status.max_attempts, status.reason) }
+                        </p>
+                    },
+                    None => html! { <></> },
+                }}
+            </>
+        }
+    }
+
+    /// Valida los datos con los parámetros indicados y guarda la solicitud para poder repetirla sin la caché.
+    fn validate(
+        &mut self,
+        rdf_content: String,
+        parameters: ValidationParameters,
+        bypass_cache: bool,
+    ) {
+        self.state.api_error = "".to_string();
+        self.state.validation_result = Default::default();
+        self.state.show_result = true;
+        self.state.is_loading = true;
+        self.state.scroll_needed = true;
+        self.state.start_shape = None;
+        self.state.inference = parameters.inference.clone();
+        self.batch = None;
+        self.comparison = None;
+        self.incremental_summary = None;
+        self.last_validation = Some((rdf_content.clone(), parameters.clone()));
+        let request_id = self.begin_request();
+        let options = RequestOptions {
+            bypass_cache,
+            ..self.request_options()
+        };
+        self.load_start_shape(&parameters);
+        let link = self.link.clone();
+        match self.validated.clone() {
+            Some(previous)
+                if self.incremental
+                    && !bypass_cache
+                    && incremental_validation::can_revalidate(
+                        &previous,
+                        &rdf_content,
+                        &parameters,
+                    ) =>
+            {
+                wasm_bindgen_futures::spawn_local(async move {
+                    let (result, error, summary, triples) =
+                        incremental_validation::revalidate(previous, rdf_content, options).await;
+                    link.send_message(Msg::IncrementalValidationResult(
+                        request_id, result, error, summary, triples,
+                    ));
+                });
+            }
+            _ => {
+                wasm_bindgen_futures::spawn_local(async move {
+                    let result =
+                        batch_validation::validate_data(rdf_content, parameters, options).await;
+                    link.send_message(Msg::ValidationResult(request_id, result.0, result.1));
+                });
+            }
+        }
+    }
+
+    /// Envía al servidor los siguientes ficheros del lote, sin superar el número máximo de validaciones a la vez.
+    fn validate_next_files(&mut self) {
+        let options = self.request_options();
+        let batch = match self.batch.as_mut() {
+            Some(batch) => batch,
+            None => return,
+        };
+        for (index, rdf, parameters) in batch.next_files() {
+            let request_id = self.request_id;
+            let options = options.clone();
+            let link = self.link.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                let (result, error) =
+                    batch_validation::validate_data(rdf, parameters, options).await;
+                link.send_message(Msg::BatchFileValidated(request_id, index, result, error));
+            });
+        }
+    }
+
+    /// Envía al servidor las siguientes versiones del esquema, sin superar el número máximo de validaciones a la vez.
+    fn validate_next_versions(&mut self) {
+        let options = self.request_options();
+        let comparison = match self.comparison.as_mut() {
+            Some(comparison) => comparison,
+            None => return,
+        };
+        for (index, parameters) in comparison.next_versions() {
+            let rdf = comparison.rdf.clone();
+            let request_id = self.request_id;
+            let options = options.clone();
+            let link = self.link.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                let (result, error) =
+                    batch_validation::validate_data(rdf, parameters, options).await;
+                link.send_message(Msg::SchemaVersionValidated(
+                    request_id, index, result, error,
+                ));
+            });
+        }
+    }
+
+    /// Inicia una nueva solicitud de validación: cancela la que esté en curso y crea su `AbortController`.
+    ///
+    /// # Retorna
+    /// Devuelve el identificador de la nueva solicitud.
+    fn begin_request(&mut self) -> u32 {
+        if let Some(controller) = self.abort_controller.take() {
+            controller.abort();
+        }
+        self.request_id += 1;
+        self.abort_controller = AbortController::new().ok();
+        self.retry_status = None;
+        self.request_id
+    }
+
+    /// Opciones de las llamadas de la solicitud de validación en curso: la configuración de las solicitudes, la
+    /// señal con la que se cancelan y el aviso de sus reintentos. Las validaciones no se repiten al agotarse el
+    /// tiempo de espera.
+    fn request_options(&self) -> RequestOptions {
+        let request_id = self.request_id;
+        RequestOptions {
+            policy: self.request_policy,
+            retry_timeouts: false,
+            abort_signal: self.abort_controller.as_ref().map(AbortController::signal),
+            on_retry: Some(
+                self.link
+                    .callback(move |status| Msg::Retrying(request_id, status)),
+            ),
+            ..RequestOptions::default()
+        }
+    }
+
+    /// Carga la forma inicial del esquema si el ShapeMap usa asociaciones `@START`.
+    fn load_start_shape(&self, parameters: &ValidationParameters) {
+        if parameters.engine != "ShEx" || !parameters.shapemap.to_uppercase().contains("@START") {
+            return;
+        }
+        let shex = parameters.shex.clone();
+        let format = parameters.shex_format.clone();
+        let request_id = self.request_id;
+        let options = RequestOptions::with_policy(self.request_policy);
+        let link = self.link.clone();
+        wasm_bindgen_futures::spawn_local(async move {
+            let start_shape = shexj::load_start_shape(shex, format, &options)
+                .await
+                .map(|start| start.describe(&[]));
+            link.send_message(Msg::ReceiveStartShape(request_id, start_shape));
+        });
+    }
+
+    /// Renderiza si el resultado de la validación coincide con el esperado por el test de shexTest cargado.
+    fn render_test_outcome(&self) -> Html {
+        let test = match self
+            .active_test
+            .and_then(|index| self.test_cases.get(index))
+        {
+            Some(test) => test,
+            None => return html! { <></> },
+        };
+        let (class, outcome) = match test.matched {
+            Some(true) => ("test-passed", "matched"),
+            Some(false) => ("test-failed", "did not match"),
+            None => (
+                "test-failed",
+                "could not be checked: the focus node is not in the result",
+            ),
+        };
+        html! {
+            <div id="test-outcome" class=format!("test-outcome {}", class)>
+                { format!("Test {}: expected {} {}@{}, result {}.", test.name, test.expected(), test.focus, test.shape, outcome) }
+            </div>
+        }
+    }
 }
 
 /// Enum que contiene los distintos tipos de entrada
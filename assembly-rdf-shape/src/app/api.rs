@@ -1,8 +1,30 @@
 // src/api.rs
-use reqwasm::http::Request;
+use crate::app::request_policy::{self, ApiResponse, RequestOptions};
+use crate::app::response_cache;
 use web_sys::console;
 
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Parámetros con los que se validan unos datos RDF.
+///
+/// # Campos
+/// * `shex` - Esquema ShEx o grafo de formas SHACL
+/// * `shapemap` - Contenido del ShapeMap
+/// * `rdf_format`, `shex_format`, `shapemap_format` - Formatos de las entradas
+/// * `inference` - Régimen de inferencia aplicado a los datos (`NONE`, `RDFS` u `OWL`)
+/// * `engine` - Motor de validación (`ShEx` o uno de los motores SHACL)
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ValidationParameters {
+    pub shex: String,
+    pub shapemap: String,
+    pub rdf_format: String,
+    pub shex_format: String,
+    pub shapemap_format: String,
+    pub inference: String,
+    pub engine: String,
+}
 
 /// Objeto Data de la respuesta al andpoint validation
 #[derive(Serialize, Deserialize)]
@@ -31,12 +53,15 @@ pub struct ShapeMap {
 }
 
 /// Define objeto TriggerMode
+///
+/// Las validaciones SHACL usan las declaraciones de objetivos del grafo de formas (`TargetDecls`)
+/// y no envían ShapeMap.
 #[derive(Serialize, Deserialize)]
 pub struct TriggerMode {
     #[serde(rename = "type")]
     pub trigger_type: String,
-    #[serde(rename = "shape-map")]
-    pub shape_map: ShapeMap,
+    #[serde(rename = "shape-map", default, skip_serializing_if = "Option::is_none")]
+    pub shape_map: Option<ShapeMap>,
 }
 
 /// Define el esquema para el body de Validation
@@ -53,6 +78,9 @@ pub struct RequestBody {
 #[serde(rename_all = "camelCase")]
 pub struct ValidationResult {
     pub result: ApiResult,
+    /// Indica si el resultado se ha obtenido de la caché de respuestas en lugar del servidor.
+    #[serde(skip)]
+    pub cached: bool,
 }
 
 /// Define el esquema para el objeto Result de la respuesta del método Validation
@@ -61,17 +89,61 @@ pub struct ValidationResult {
 pub struct ApiResult {
     pub valid: bool,
     pub message: String,
+    #[serde(default)]
     pub shape_map: Vec<ShapeMapEntry>,
+    /// Informe de validación SHACL (grafo RDF serializado o JSON-LD), ausente en las validaciones ShEx.
+    #[serde(
+        default,
+        alias = "validationReport",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub report: Option<serde_json::Value>,
 }
 
 /// Define el esquema para cada ShapeMap de la respuesta del método Validation
-#[derive(Serialize, Deserialize, Default, Clone)]
+#[derive(Serialize, Deserialize, Default, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct ShapeMapEntry {
     pub node: String,
     pub shape: String,
     pub status: String,
     pub reason: String,
+    /// Severidad del resultado SHACL (`Violation`, `Warning` o `Info`), vacía en las validaciones ShEx.
+    #[serde(default)]
+    pub severity: String,
+    /// Ruta (`sh:resultPath`) del resultado SHACL.
+    #[serde(default)]
+    pub path: String,
+    /// Valor (`sh:value`) que ha provocado el resultado SHACL.
+    #[serde(default)]
+    pub value: String,
+    /// Fichero de datos del que procede el resultado en las validaciones por lotes; vacío en el resto.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub source: String,
+    /// Nodo completo tal y como lo devuelve el servidor, antes de acortarlo para mostrarlo.
+    #[serde(skip)]
+    pub node_iri: String,
+    /// Forma completa tal y como la devuelve el servidor, antes de acortarla para mostrarla.
+    #[serde(skip)]
+    pub shape_iri: String,
+}
+
+impl ShapeMapEntry {
+    /// Nodo y forma completos del resultado, que lo identifican aunque coincidan sus nombres acortados.
+    /// Si el resultado no guarda los valores completos se usan los que se muestran.
+    pub fn key(&self) -> (&str, &str) {
+        let node = if self.node_iri.is_empty() {
+            &self.node
+        } else {
+            &self.node_iri
+        };
+        let shape = if self.shape_iri.is_empty() {
+            &self.shape
+        } else {
+            &self.shape_iri
+        };
+        (node, shape)
+    }
 }
 
 /// Define el esquema para el body del método data/info
@@ -97,6 +169,9 @@ pub struct InfoRdfRequestContent {
 pub struct InfoRdfResponse {
     pub message: String,
     pub result: InfoRdfResult,
+    /// Indica si la respuesta se ha obtenido de la caché de respuestas en lugar del servidor.
+    #[serde(skip)]
+    pub cached: bool,
 }
 
 /// Define el esquema objeto result para la respuesta del método data/info
@@ -129,6 +204,9 @@ pub struct InfoShexRequestContent {
 pub struct InfoShexResponse {
     pub message: String,
     pub result: InfoShexResult,
+    /// Indica si la respuesta se ha obtenido de la caché de respuestas en lugar del servidor.
+    #[serde(skip)]
+    pub cached: bool,
 }
 
 /// Define el esquema el objeto result la respuesta del método schema/info
@@ -140,53 +218,198 @@ pub struct InfoShexResult {
 }
 
 /// Define el esquema el objeto Prefix la respuesta del método schema/info
-#[derive(Serialize, Deserialize, Default, Clone)]
+#[derive(Serialize, Deserialize, Default, Clone, Debug, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Prefix {
-    prefix_name: String,
-    prefix_IRI: String,
+    /// Nombre del prefijo, sin los dos puntos.
+    pub prefix_name: String,
+    /// IRI del espacio de nombres (`prefixIRI` en la respuesta de la API).
+    #[serde(rename = "prefixIRI")]
+    pub prefix_iri: String,
+}
+
+/// Define el esquema para el body del método data/convert
+#[derive(Serialize, Deserialize, Default, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertRdfRequest {
+    pub data: InfoRdfRequestContent,
+    pub target_format: String,
+}
+
+/// Define el esquema para la respuesta del método data/convert
+#[derive(Serialize, Deserialize, Default, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertRdfResponse {
+    pub message: String,
+    pub result: ConvertRdfResult,
+}
+
+/// Define el esquema objeto result para la respuesta del método data/convert
+#[derive(Serialize, Deserialize, Default, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ConvertRdfResult {
+    pub content: String,
+}
+
+/// Define el esquema para el body del método schema/convert
+#[derive(Serialize, Deserialize, Default, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertShexRequest {
+    pub schema: InfoShexRequestContent,
+    pub target_format: String,
+    pub target_engine: String,
+}
+
+/// Define el esquema para la respuesta del método schema/convert
+#[derive(Serialize, Deserialize, Default, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertShexResponse {
+    pub message: String,
+    pub result: ConvertShexResult,
+}
+
+/// Define el esquema objeto result para la respuesta del método schema/convert
+#[derive(Serialize, Deserialize, Default, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ConvertShexResult {
+    pub content: String,
+}
+
+/// Define el esquema para el body del método data/query
+#[derive(Serialize, Deserialize, Default, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryRdfRequest {
+    pub data: InfoRdfRequestContent,
+    pub query: QueryContent,
+}
+
+/// Define el esquema para el objeto query del body del método data/query
+#[derive(Serialize, Deserialize, Default, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryContent {
+    pub content: String,
+    pub source: String,
+}
+
+/// Define el esquema para la respuesta del método data/query
+#[derive(Serialize, Deserialize, Default, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryRdfResponse {
+    pub message: String,
+    pub result: QueryRdfResult,
+}
+
+/// Define el esquema objeto result (resultados SPARQL en JSON) para la respuesta del método data/query
+#[derive(Serialize, Deserialize, Default, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct QueryRdfResult {
+    pub head: QueryHead,
+    pub results: QueryResults,
+}
+
+/// Define el esquema objeto head de los resultados SPARQL
+#[derive(Serialize, Deserialize, Default, Clone)]
+#[serde(default)]
+pub struct QueryHead {
+    pub vars: Vec<String>,
+}
+
+/// Define el esquema objeto results de los resultados SPARQL
+#[derive(Serialize, Deserialize, Default, Clone)]
+#[serde(default)]
+pub struct QueryResults {
+    pub bindings: Vec<HashMap<String, QueryBinding>>,
+}
+
+/// Define el esquema de cada valor ligado a una variable en los resultados SPARQL
+#[derive(Serialize, Deserialize, Default, Clone)]
+#[serde(default)]
+pub struct QueryBinding {
+    #[serde(rename = "type")]
+    pub binding_type: String,
+    pub value: String,
+    pub datatype: Option<String>,
+    #[serde(rename = "xml:lang")]
+    pub lang: Option<String>,
+}
+
+/// Define el esquema para las respuestas de error de la API
+#[derive(Serialize, Deserialize, Default, Clone)]
+pub struct ApiError {
+    pub error: String,
+}
+
+/// Motivo por el que ha fallado una solicitud a la API.
+#[derive(Clone, Debug, PartialEq)]
+pub enum RequestError {
+    /// La API ha rechazado el contenido enviado (por ejemplo, por un error de sintaxis).
+    Rejected(String),
+    /// No se ha obtenido respuesta: error de red, tiempo agotado, cancelación o error del servidor.
+    Unavailable(String),
+}
+
+impl RequestError {
+    /// Devuelve el mensaje del error.
+    pub fn into_message(self) -> String {
+        match self {
+            RequestError::Rejected(message) | RequestError::Unavailable(message) => message,
+        }
+    }
 }
 
 /// Construye el cuerpo de la solicitud para validar RDF, ShEx y ShapeMap.
 ///
+/// El ShapeMap puede ser fijo o de consulta. El editor expande los selectores `{FOCUS p o}`,
+/// `{s p FOCUS}` (con `_` como comodín) y `SPARQL "..."` antes de validar; los que no se pueden
+/// expandir en el cliente se envían tal cual para que el servidor los resuelva sobre los datos.
+///
 /// # Parámetros
 /// * `rdf_content` - Contenido RDF
-/// * `shex_content` - Esquema ShEx
-/// * `shapemap_content` - Contenido del ShapeMap
+/// * `parameters` - Esquema, ShapeMap (fijo o de consulta), formatos, inferencia y motor de validación
 ///
 /// # Retorna
 /// Devuelve un `RequestBody` estructurado con los datos proporcionados.
 pub fn create_validation_request_body(
     rdf_content: String,
-    shex_content: String,
-    shapemap_content: String,
-    rdf_format: String,
-    shex_format: String,
-    shapemap_format: String,
+    parameters: ValidationParameters,
 ) -> RequestBody {
+    let ValidationParameters {
+        shex: shex_content,
+        shapemap: shapemap_content,
+        rdf_format,
+        shex_format,
+        shapemap_format,
+        inference,
+        engine,
+    } = parameters;
     let data = Data {
         content: rdf_content,
         source: "byText".to_string(),
         format: rdf_format,
-        inference: "NONE".to_string(),
+        inference,
     };
 
     let schema = Schema {
         content: shex_content,
         source: "byText".to_string(),
         format: shex_format,
-        engine: "ShEx".to_string(),
-    };
-
-    let shape_map = ShapeMap {
-        content: shapemap_content,
-        source: "byText".to_string(),
-        format: shapemap_format,
+        engine: engine.clone(),
     };
 
-    let trigger_mode = TriggerMode {
-        trigger_type: "ShapeMap".to_string(),
-        shape_map,
+    let trigger_mode = if engine == "ShEx" {
+        TriggerMode {
+            trigger_type: "ShapeMap".to_string(),
+            shape_map: Some(ShapeMap {
+                content: shapemap_content,
+                source: "byText".to_string(),
+                format: shapemap_format,
+            }),
+        }
+    } else {
+        TriggerMode {
+            trigger_type: "TargetDecls".to_string(),
+            shape_map: None,
+        }
     };
 
     let request_body = RequestBody {
@@ -200,55 +423,36 @@ pub fn create_validation_request_body(
 
 /// Realiza una llamada a la API para validar RDF, ShEx y ShapeMap y maneja los resultados.
 ///
+/// Si los mismos datos ya se han validado con el mismo esquema y ShapeMap, el resultado se toma de la caché
+/// de respuestas, salvo que `options` indique lo contrario.
+///
 /// # Parámetros
 /// * `rdf_content` - Contenido RDF
-/// * `shex_content` - Esquema ShEx
-/// * `shapemap_content` - Contenido del ShapeMap
+/// * `parameters` - Esquema, ShapeMap, formatos, inferencia y motor de validación
+/// * `options` - Señal con la que se puede cancelar la solicitud, aviso de los reintentos y uso de la caché
 ///
 /// # Retorna
 /// Retorna un tuple `(ValidationResult, String)` donde `ValidationResult` es el resultado de la validación
 /// y `String` es un mensaje de error, si ocurrió alguno durante la operación.
 pub async fn call_validation_api(
     rdf_content: String,
-    shex_content: String,
-    shapemap_content: String,
-    rdf_format: String,
-    shex_format: String,
-    shapemap_format: String,
+    parameters: ValidationParameters,
+    options: RequestOptions,
 ) -> (ValidationResult, String) {
     let mut error_message = "".to_string();
-    let request_body = create_validation_request_body(
-        rdf_content,
-        shex_content,
-        shapemap_content,
-        rdf_format,
-        shex_format,
-        shapemap_format,
-    );
+    let request_body = create_validation_request_body(rdf_content, parameters);
 
     let validation_endpoint = "https://api.rdfshape.weso.es/api/schema/validate";
     let request_body_json = serde_json::to_string(&request_body).unwrap();
     let mut validation_result: ValidationResult = Default::default();
 
-    let response = Request::post(validation_endpoint)
-        .body(request_body_json)
-        .send()
-        .await;
-
-    match response {
-        Ok(response) => {
-            let json: Result<ValidationResult, _> = response.json().await;
-            match json {
-                Ok(vr) => {
-                    validation_result = vr;
-                }
-                Err(e) => {
-                    error_message = e.to_string();
-                }
-            }
+    match post_cached::<ValidationResult>(validation_endpoint, request_body_json, &options).await {
+        Ok((vr, cached)) => {
+            validation_result = vr;
+            validation_result.cached = cached;
         }
         Err(e) => {
-            error_message = e.to_string();
+            error_message = e.into_message();
         }
     }
 
@@ -268,28 +472,148 @@ pub async fn call_validation_api(
 /// # Parámetros
 /// * `rdf` - El contenido RDF a analizar.
 /// * `format` - El formato del RDF a analizar.
+/// * `inference` - Régimen de inferencia aplicado a los datos.
+/// * `options` - Configuración de la solicitud y señal con la que se puede cancelar.
 /// # Retorna
 /// Retorna un tuple (`InfoRdfResponse`, `String`), donde `InfoRdfResponse` contiene la
 /// respuesta de la API y `String` contiene un mensaje de error en caso de que ocurra uno.
-pub async fn call_rdf_info_api(rdf: String, format: String) -> (InfoRdfResponse, String) {
-    let mut error_message = "".to_string();
-    let request_body = create_rdf_info_request_body(rdf, format);
+pub async fn call_rdf_info_api(
+    rdf: String,
+    format: String,
+    inference: String,
+    options: &RequestOptions,
+) -> (InfoRdfResponse, String) {
+    let (info_response, error_message) =
+        match request_rdf_info(rdf, format, inference, options).await {
+            Ok(info_response) => (info_response, String::new()),
+            Err(e) => (Default::default(), e.into_message()),
+        };
 
+    let printvresult = serde_json::to_string(&info_response).unwrap();
+    console::log_1(&printvresult.into());
+
+    (info_response, error_message)
+}
+
+/// Solicita a la API la información de un contenido RDF distinguiendo el motivo de los errores.
+///
+/// # Parámetros
+/// * `rdf` - El contenido RDF a analizar.
+/// * `format` - El formato del RDF a analizar.
+/// * `inference` - Régimen de inferencia aplicado a los datos.
+/// * `options` - Configuración de la solicitud y señal con la que se puede cancelar.
+///
+/// # Retorna
+/// Devuelve la respuesta de la API o un `RequestError` que indica si la API ha rechazado el contenido
+/// o no se ha podido obtener respuesta.
+pub async fn request_rdf_info(
+    rdf: String,
+    format: String,
+    inference: String,
+    options: &RequestOptions,
+) -> Result<InfoRdfResponse, RequestError> {
+    let request_body = create_rdf_info_request_body(rdf, format, inference);
     let info_endpoint = "https://api.rdfshape.weso.es/api/data/info";
     let request_body_json = serde_json::to_string(&request_body).unwrap();
-    let mut info_response: InfoRdfResponse = Default::default();
 
-    let response = Request::post(info_endpoint)
-        .body(request_body_json)
-        .send()
-        .await;
+    let (mut info_response, cached) =
+        post_cached::<InfoRdfResponse>(info_endpoint, request_body_json, options).await?;
+    info_response.cached = cached;
+    Ok(info_response)
+}
+
+/// Realiza una solicitud a la API para obtener información sobre ShEx.
+///
+/// Envía una solicitud POST para obtener detalles de un esquema ShEx, como las formas y
+/// mapas de prefijos utilizados en el esquema.
+///
+/// # Parámetros
+/// * `shex` - El contenido ShEx a analizar.
+/// * `format` - El formato del ShEx a analizar.
+/// * `options` - Configuración de la solicitud y señal con la que se puede cancelar.
+///
+/// # Retorna
+/// Retorna un tuple (`InfoShexResponse`, `String`), donde `InfoShexResponse` contiene la
+/// respuesta de la API y `String` contiene un mensaje de error en caso de que ocurra uno.
+pub async fn call_shex_info_api(
+    shex: String,
+    format: String,
+    options: &RequestOptions,
+) -> (InfoShexResponse, String) {
+    let (info_response, error_message) = match request_shex_info(shex, format, options).await {
+        Ok(info_response) => (info_response, String::new()),
+        Err(e) => (Default::default(), e.into_message()),
+    };
+
+    let printvresult = serde_json::to_string(&info_response).unwrap();
+    console::log_1(&printvresult.into());
+
+    (info_response, error_message)
+}
+
+/// Solicita a la API la información de un esquema ShEx distinguiendo el motivo de los errores.
+///
+/// # Parámetros
+/// * `shex` - El contenido ShEx a analizar.
+/// * `format` - El formato del ShEx a analizar.
+/// * `options` - Configuración de la solicitud y señal con la que se puede cancelar.
+///
+/// # Retorna
+/// Devuelve la respuesta de la API o un `RequestError` que indica si la API ha rechazado el esquema
+/// o no se ha podido obtener respuesta.
+pub async fn request_shex_info(
+    shex: String,
+    format: String,
+    options: &RequestOptions,
+) -> Result<InfoShexResponse, RequestError> {
+    let request_body = create_shex_info_request_body(shex, format);
+    let info_endpoint = "https://api.rdfshape.weso.es/api/schema/info";
+    let request_body_json = serde_json::to_string(&request_body).unwrap();
+
+    let (mut info_response, cached) =
+        post_cached::<InfoShexResponse>(info_endpoint, request_body_json, options).await?;
+    info_response.cached = cached;
+    Ok(info_response)
+}
+
+/// Realiza una solicitud a la API para convertir RDF a otra serialización.
+///
+/// Envía una solicitud POST al endpoint de conversión de datos, esperando recibir
+/// el mismo grafo RDF serializado en el formato de destino.
+///
+/// # Parámetros
+/// * `rdf` - El contenido RDF a convertir.
+/// * `format` - El formato actual del RDF.
+/// * `target_format` - El formato al que se desea convertir el RDF.
+/// * `options` - Configuración de la solicitud y señal con la que se puede cancelar.
+///
+/// # Retorna
+/// Retorna un tuple (`ConvertRdfResponse`, `String`), donde `ConvertRdfResponse` contiene la
+/// respuesta de la API y `String` contiene un mensaje de error en caso de que ocurra uno.
+pub async fn call_rdf_convert_api(
+    rdf: String,
+    format: String,
+    target_format: String,
+    options: &RequestOptions,
+) -> (ConvertRdfResponse, String) {
+    let mut error_message = "".to_string();
+    let request_body = create_rdf_convert_request_body(rdf, format, target_format);
+
+    let convert_endpoint = "https://api.rdfshape.weso.es/api/data/convert";
+    let request_body_json = serde_json::to_string(&request_body).unwrap();
+    let mut convert_response: ConvertRdfResponse = Default::default();
+
+    let response = request_policy::post(convert_endpoint, request_body_json, options).await;
 
     match response {
+        Ok(response) if !response.ok() => {
+            error_message = read_error_message(&response);
+        }
         Ok(response) => {
-            let json: Result<InfoRdfResponse, _> = response.json().await;
+            let json: Result<ConvertRdfResponse, _> = response.json();
             match json {
                 Ok(vr) => {
-                    info_response = vr;
+                    convert_response = vr;
                 }
                 Err(e) => {
                     error_message = e.to_string();
@@ -297,47 +621,91 @@ pub async fn call_rdf_info_api(rdf: String, format: String) -> (InfoRdfResponse,
             }
         }
         Err(e) => {
-            error_message = e.to_string();
+            error_message = e;
         }
     }
 
-    let printvresult = serde_json::to_string(&info_response).unwrap();
-    console::log_1(&printvresult.into());
+    (convert_response, error_message)
+}
 
-    (info_response, error_message)
+/// Realiza una solicitud a la API para convertir un esquema ShEx a otro formato.
+///
+/// Envía una solicitud POST al endpoint de conversión de esquemas, esperando recibir
+/// el mismo esquema serializado en el formato de destino (ShExC, ShExJ o ShExR).
+///
+/// # Parámetros
+/// * `shex` - El contenido ShEx a convertir.
+/// * `format` - El formato actual del ShEx.
+/// * `target_format` - El formato al que se desea convertir el ShEx.
+/// * `options` - Configuración de la solicitud y señal con la que se puede cancelar.
+///
+/// # Retorna
+/// Retorna un tuple (`ConvertShexResponse`, `String`), donde `ConvertShexResponse` contiene la
+/// respuesta de la API y `String` contiene un mensaje de error en caso de que ocurra uno.
+pub async fn call_shex_convert_api(
+    shex: String,
+    format: String,
+    target_format: String,
+    options: &RequestOptions,
+) -> (ConvertShexResponse, String) {
+    call_schema_translation_api(
+        shex,
+        format,
+        "ShEx".to_string(),
+        target_format,
+        "ShEx".to_string(),
+        options,
+    )
+    .await
 }
 
-/// Realiza una solicitud a la API para obtener información sobre ShEx.
+/// Realiza una solicitud a la API para traducir un esquema entre motores (ShEx y SHACL).
 ///
-/// Envía una solicitud POST para obtener detalles de un esquema ShEx, como las formas y
-/// mapas de prefijos utilizados en el esquema.
+/// Usa el mismo endpoint de conversión de esquemas indicando el motor de origen y el de destino.
 ///
 /// # Parámetros
-/// * `shex` - El contenido ShEx a analizar.
-/// * `format` - El formato del ShEx a analizar.
+/// * `schema` - El contenido del esquema a traducir.
+/// * `format` - El formato actual del esquema.
+/// * `engine` - El motor del esquema (`ShEx` o un motor SHACL).
+/// * `target_format` - El formato de destino.
+/// * `target_engine` - El motor de destino.
+/// * `options` - Configuración de la solicitud y señal con la que se puede cancelar.
 ///
 /// # Retorna
-/// Retorna un tuple (`InfoShexResponse`, `String`), donde `InfoShexResponse` contiene la
+/// Retorna un tuple (`ConvertShexResponse`, `String`), donde `ConvertShexResponse` contiene la
 /// respuesta de la API y `String` contiene un mensaje de error en caso de que ocurra uno.
-pub async fn call_shex_info_api(shex: String, format: String) -> (InfoShexResponse, String) {
+pub async fn call_schema_translation_api(
+    schema: String,
+    format: String,
+    engine: String,
+    target_format: String,
+    target_engine: String,
+    options: &RequestOptions,
+) -> (ConvertShexResponse, String) {
     let mut error_message = "".to_string();
-    let request_body = create_shex_info_request_body(shex, format);
+    let request_body = create_schema_translation_request_body(
+        schema,
+        format,
+        engine,
+        target_format,
+        target_engine,
+    );
 
-    let info_endpoint = "https://api.rdfshape.weso.es/api/schema/info";
+    let convert_endpoint = "https://api.rdfshape.weso.es/api/schema/convert";
     let request_body_json = serde_json::to_string(&request_body).unwrap();
-    let mut info_response: InfoShexResponse = Default::default();
+    let mut convert_response: ConvertShexResponse = Default::default();
 
-    let response = Request::post(info_endpoint)
-        .body(request_body_json)
-        .send()
-        .await;
+    let response = request_policy::post(convert_endpoint, request_body_json, options).await;
 
     match response {
+        Ok(response) if !response.ok() => {
+            error_message = read_error_message(&response);
+        }
         Ok(response) => {
-            let json: Result<InfoShexResponse, _> = response.json().await;
+            let json: Result<ConvertShexResponse, _> = response.json();
             match json {
                 Ok(vr) => {
-                    info_response = vr;
+                    convert_response = vr;
                 }
                 Err(e) => {
                     error_message = e.to_string();
@@ -345,28 +713,79 @@ pub async fn call_shex_info_api(shex: String, format: String) -> (InfoShexRespon
             }
         }
         Err(e) => {
-            error_message = e.to_string();
+            error_message = e;
         }
     }
 
-    let printvresult = serde_json::to_string(&info_response).unwrap();
-    console::log_1(&printvresult.into());
+    (convert_response, error_message)
+}
 
-    (info_response, error_message)
+/// Realiza una consulta SPARQL sobre los datos RDF a través de la API.
+///
+/// # Parámetros
+/// * `rdf` - El contenido RDF sobre el que se ejecuta la consulta.
+/// * `format` - El formato del RDF.
+/// * `query` - La consulta SPARQL.
+/// * `options` - Configuración de la solicitud y señal con la que se puede cancelar.
+///
+/// # Retorna
+/// Retorna un tuple (`QueryRdfResponse`, `String`), donde `QueryRdfResponse` contiene la
+/// respuesta de la API y `String` contiene un mensaje de error en caso de que ocurra uno.
+pub async fn call_rdf_query_api(
+    rdf: String,
+    format: String,
+    query: String,
+    options: &RequestOptions,
+) -> (QueryRdfResponse, String) {
+    let mut error_message = "".to_string();
+    let request_body = create_rdf_query_request_body(rdf, format, query);
+
+    let query_endpoint = "https://api.rdfshape.weso.es/api/data/query";
+    let request_body_json = serde_json::to_string(&request_body).unwrap();
+    let mut query_response: QueryRdfResponse = Default::default();
+
+    let response = request_policy::post(query_endpoint, request_body_json, options).await;
+
+    match response {
+        Ok(response) if !response.ok() => {
+            error_message = read_error_message(&response);
+        }
+        Ok(response) => {
+            let json: Result<QueryRdfResponse, _> = response.json();
+            match json {
+                Ok(vr) => {
+                    query_response = vr;
+                }
+                Err(e) => {
+                    error_message = e.to_string();
+                }
+            }
+        }
+        Err(e) => {
+            error_message = e;
+        }
+    }
+
+    (query_response, error_message)
 }
 
 /// Construye el cuerpo de la solicitud para obtener información RDF.
 ///
 /// # Parámetros
 /// * `rdf` - El contenido RDF
+/// * `inference` - Régimen de inferencia aplicado a los datos
 ///
 /// # Retorna
 /// Retorna una estructura `InfoRdfRequest` preparada para ser enviada a la API.
-pub fn create_rdf_info_request_body(rdf: String, format: String) -> InfoRdfRequest {
+pub fn create_rdf_info_request_body(
+    rdf: String,
+    format: String,
+    inference: String,
+) -> InfoRdfRequest {
     let data_request = InfoRdfRequestContent {
         content: rdf,
         format: format,
-        inference: "NONE".to_string(),
+        inference,
         source: "byText".to_string(),
     };
 
@@ -375,6 +794,63 @@ pub fn create_rdf_info_request_body(rdf: String, format: String) -> InfoRdfReque
     request_body
 }
 
+/// Construye el cuerpo de la solicitud para convertir RDF.
+///
+/// # Parámetros
+/// * `rdf` - El contenido RDF
+/// * `format` - El formato actual del RDF
+/// * `target_format` - El formato de destino
+///
+/// # Retorna
+/// Retorna una estructura `ConvertRdfRequest` preparada para ser enviada a la API.
+pub fn create_rdf_convert_request_body(
+    rdf: String,
+    format: String,
+    target_format: String,
+) -> ConvertRdfRequest {
+    let data_request = InfoRdfRequestContent {
+        content: rdf,
+        format,
+        inference: "NONE".to_string(),
+        source: "byText".to_string(),
+    };
+
+    ConvertRdfRequest {
+        data: data_request,
+        target_format,
+    }
+}
+
+/// Construye el cuerpo de la solicitud para consultar RDF con SPARQL.
+///
+/// # Parámetros
+/// * `rdf` - El contenido RDF
+/// * `format` - El formato del RDF
+/// * `query` - La consulta SPARQL
+///
+/// # Retorna
+/// Retorna una estructura `QueryRdfRequest` preparada para ser enviada a la API.
+pub fn create_rdf_query_request_body(
+    rdf: String,
+    format: String,
+    query: String,
+) -> QueryRdfRequest {
+    let data_request = InfoRdfRequestContent {
+        content: rdf,
+        format,
+        inference: "NONE".to_string(),
+        source: "byText".to_string(),
+    };
+
+    QueryRdfRequest {
+        data: data_request,
+        query: QueryContent {
+            content: query,
+            source: "byText".to_string(),
+        },
+    }
+}
+
 /// Construye el cuerpo de la solicitud para obtener información ShEx.
 ///
 /// # Parámetros
@@ -398,6 +874,99 @@ pub fn create_shex_info_request_body(shex: String, format_param: String) -> Info
     request_body
 }
 
+/// Construye el cuerpo de la solicitud para traducir un esquema entre motores.
+///
+/// # Parámetros
+/// * `schema` - El contenido del esquema
+/// * `format` - El formato actual del esquema
+/// * `engine` - El motor del esquema
+/// * `target_format` - El formato de destino
+/// * `target_engine` - El motor de destino
+///
+/// # Retorna
+/// Retorna una estructura `ConvertShexRequest` preparada para ser enviada a la API.
+pub fn create_schema_translation_request_body(
+    schema: String,
+    format: String,
+    engine: String,
+    target_format: String,
+    target_engine: String,
+) -> ConvertShexRequest {
+    let schema_request = InfoShexRequestContent {
+        content: schema,
+        engine,
+        format,
+        source: "byText".to_string(),
+    };
+
+    ConvertShexRequest {
+        schema: schema_request,
+        target_format,
+        target_engine,
+    }
+}
+
+/// Envía una solicitud POST a la API guardando la respuesta en la caché de respuestas.
+///
+/// La respuesta se busca antes en la caché con el hash del cuerpo de la solicitud, salvo que
+/// `options.bypass_cache` lo impida, y solo se reutiliza si el cuerpo guardado con ella es el mismo.
+/// Solo se guardan las respuestas correctas que se han podido interpretar.
+///
+/// # Parámetros
+/// * `endpoint` - URL del endpoint
+/// * `body` - Cuerpo JSON de la solicitud
+/// * `options` - Opciones de la solicitud
+///
+/// # Retorna
+/// Devuelve la respuesta interpretada e indica si procede de la caché, o el motivo del error.
+async fn post_cached<T: DeserializeOwned>(
+    endpoint: &str,
+    body: String,
+    options: &RequestOptions,
+) -> Result<(T, bool), RequestError> {
+    let key = response_cache::cache_key(endpoint, &body);
+    if !options.bypass_cache {
+        if let Some(cached) = response_cache::lookup(&key, &body, &options.policy).await {
+            if let Ok(response) = serde_json::from_str(&cached) {
+                return Ok((response, true));
+            }
+        }
+    }
+
+    let response = request_policy::post(endpoint, body.clone(), options)
+        .await
+        .map_err(RequestError::Unavailable)?;
+    if !response.ok() {
+        let message = read_error_message(&response);
+        return Err(if response.status >= 500 {
+            RequestError::Unavailable(message)
+        } else {
+            RequestError::Rejected(message)
+        });
+    }
+    let parsed = response
+        .json()
+        .map_err(|e| RequestError::Unavailable(e.to_string()))?;
+    response_cache::store(&key, &body, &response.body, &options.policy).await;
+    Ok((parsed, false))
+}
+
+/// Obtiene el mensaje de error de una respuesta fallida de la API.
+///
+/// # Parámetros
+/// * `response` - Respuesta HTTP con un estado distinto de 2xx.
+///
+/// # Retorna
+/// Devuelve el campo `error` del cuerpo si existe, el cuerpo completo en otro caso o,
+/// si está vacío, el código de estado HTTP.
+fn read_error_message(response: &ApiResponse) -> String {
+    match response.json::<ApiError>() {
+        Ok(api_error) if !api_error.error.is_empty() => api_error.error,
+        _ if !response.body.trim().is_empty() => response.body.clone(),
+        _ => format!("HTTP {}", response.status),
+    }
+}
+
 /// Formatea las entradas de ShapeMap para mejorar la legibilidad en la visualización final.
 ///
 /// # Parámetros
@@ -409,6 +978,8 @@ pub fn format_shape_maps(response: ValidationResult) -> ValidationResult {
     let mut formatted_result = response.clone();
     let shapes = &mut formatted_result.result.shape_map;
     for entry in shapes.iter_mut() {
+        entry.node_iri = entry.node.clone();
+        entry.shape_iri = entry.shape.clone();
         entry.node = ":".to_owned() + &extract_last_segment(&entry.node);
         entry.shape = ":".to_owned() + &extract_last_segment(&entry.shape);
         entry.status = format_status(&entry.status);
@@ -433,12 +1004,14 @@ fn format_status(status: &str) -> String {
 
 /// Extrae la última parte de una URI, usada para simplificar las referencias en las visualizaciones.
 ///
+/// La URI debe ir entre `<` y `>`; en otro caso se devuelve sin cambios.
+///
 /// # Parámetros
 /// * `uri` - La URI completa desde la cual extraer el segmento.
 ///
 /// # Retorna
 /// Devuelve una cadena que representa el último segmento de la URI.
-fn extract_last_segment(uri: &str) -> String {
+pub fn extract_last_segment(uri: &str) -> String {
     if let Some(start) = uri.rfind('/') {
         if let Some(end) = uri.find('>') {
             return uri[start + 1..end].to_string();
@@ -0,0 +1,143 @@
+use crate::app::api::{self, ShapeMapEntry, ValidationParameters, ValidationResult};
+use crate::app::request_policy::RequestOptions;
+use crate::app::shacl;
+use std::collections::VecDeque;
+
+/// Número máximo de validaciones de un lote que se envían a la vez al servidor.
+pub const MAX_CONCURRENT_VALIDATIONS: usize = 3;
+
+/// Resumen de la validación de uno de los ficheros del lote.
+///
+/// # Campos
+/// * `file` - Nombre del fichero de datos
+/// * `valid`, `invalid` - Número de resultados conformes y no conformes
+/// * `error` - Mensaje de error si el fichero no se ha podido validar
+/// * `done` - Indica si ya se ha recibido la respuesta del servidor
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BatchFileSummary {
+    pub file: String,
+    pub valid: usize,
+    pub invalid: usize,
+    pub error: String,
+    pub done: bool,
+}
+
+/// Fichero de datos de una validación por lotes.
+///
+/// # Campos
+/// * `name` - Nombre del fichero
+/// * `content` - Contenido del fichero
+/// * `format` - Formato RDF con el que se valida el fichero
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BatchFile {
+    pub name: String,
+    pub content: String,
+    pub format: String,
+}
+
+/// Validación por lotes en curso: ficheros pendientes, validaciones en vuelo y resumen por fichero.
+#[derive(Clone, Debug, Default)]
+pub struct BatchValidation {
+    pub parameters: ValidationParameters,
+    pub summaries: Vec<BatchFileSummary>,
+    queue: VecDeque<(usize, BatchFile)>,
+    in_flight: usize,
+}
+
+impl BatchValidation {
+    /// Crea un lote con los ficheros indicados.
+    pub fn new(files: Vec<BatchFile>, parameters: ValidationParameters) -> Self {
+        let summaries = files
+            .iter()
+            .map(|file| BatchFileSummary {
+                file: file.name.clone(),
+                ..BatchFileSummary::default()
+            })
+            .collect();
+        BatchValidation {
+            parameters,
+            summaries,
+            queue: files.into_iter().enumerate().collect(),
+            in_flight: 0,
+        }
+    }
+
+    /// Saca de la cola los ficheros que se pueden enviar sin superar `MAX_CONCURRENT_VALIDATIONS`.
+    ///
+    /// # Retorna
+    /// Devuelve la posición de cada fichero en el lote, su contenido y los parámetros con los que se valida,
+    /// que llevan el formato del fichero.
+    pub fn next_files(&mut self) -> Vec<(usize, String, ValidationParameters)> {
+        let mut files = Vec::new();
+        while self.in_flight < MAX_CONCURRENT_VALIDATIONS {
+            match self.queue.pop_front() {
+                Some((index, file)) => {
+                    self.in_flight += 1;
+                    files.push((
+                        index,
+                        file.content,
+                        ValidationParameters {
+                            rdf_format: file.format,
+                            ..self.parameters.clone()
+                        },
+                    ));
+                }
+                None => break,
+            }
+        }
+        files
+    }
+
+    /// Registra el resultado de un fichero y marca sus entradas con el nombre del fichero.
+    pub fn record(&mut self, index: usize, entries: &mut [ShapeMapEntry], error: String) {
+        self.in_flight = self.in_flight.saturating_sub(1);
+        let summary = match self.summaries.get_mut(index) {
+            Some(summary) => summary,
+            None => return,
+        };
+        for entry in entries.iter_mut() {
+            entry.source = summary.file.clone();
+        }
+        summary.valid = entries
+            .iter()
+            .filter(|entry| entry.status == "Valid")
+            .count();
+        summary.invalid = entries.len() - summary.valid;
+        summary.error = error;
+        summary.done = true;
+    }
+
+    /// Número de ficheros cuya validación ha terminado.
+    pub fn completed(&self) -> usize {
+        self.summaries.iter().filter(|summary| summary.done).count()
+    }
+
+    /// Indica si ya se han validado todos los ficheros del lote.
+    pub fn is_finished(&self) -> bool {
+        self.completed() == self.summaries.len()
+    }
+}
+
+/// Valida unos datos RDF con los parámetros indicados.
+///
+/// En las validaciones SHACL, las filas del resultado se obtienen del informe de validación.
+/// La solicitud se cancela con la señal de `options`, que también recibe los avisos de reintento.
+///
+/// # Retorna
+/// Devuelve el resultado de la validación y un mensaje de error, vacío si no ha habido ninguno.
+pub async fn validate_data(
+    rdf: String,
+    parameters: ValidationParameters,
+    options: RequestOptions,
+) -> (ValidationResult, String) {
+    let is_shex = parameters.engine == "ShEx";
+    let mut result = api::call_validation_api(rdf, parameters, options.clone()).await;
+    if let (false, Some(report)) = (is_shex, result.0.result.report.clone()) {
+        match shacl::load_report_entries(report, &options).await {
+            Ok(entries) => result.0.result.shape_map = entries,
+            Err(error) if result.1.is_empty() => result.1 = error,
+            Err(_) => {}
+        }
+    }
+    result
+}
@@ -0,0 +1,118 @@
+use crate::app::shapemap::{self, ShapeMapError};
+use serde::{Deserialize, Serialize};
+
+/// Problema de sintaxis detectado en una de las entradas.
+///
+/// # Campos
+/// * `line` - Línea del error (empezando en 1), si se conoce
+/// * `column` - Columna del error (empezando en 1), si se conoce
+/// * `message` - Descripción del error
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Diagnostic {
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub message: String,
+}
+
+impl Diagnostic {
+    /// Construye un diagnóstico a partir del mensaje de error devuelto por la API.
+    ///
+    /// Reconoce las posiciones con la forma `line 3:5`, `line: 3, column: 5` o `[line: 3, col: 5]`
+    /// que generan los analizadores de RDF y ShEx.
+    pub fn from_message(message: &str) -> Self {
+        let lowercase = message.to_lowercase();
+        let line = number_after(&lowercase, "line");
+        let column = number_after(&lowercase, "column")
+            .or_else(|| number_after(&lowercase, "col"))
+            .or_else(|| {
+                let start = lowercase.find("line")?;
+                let rest = &lowercase[start..];
+                let colon = rest.find(|c: char| c.is_ascii_digit())?;
+                let after_line = &rest[colon..];
+                let digits_end = after_line
+                    .find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(after_line.len());
+                after_line[digits_end..]
+                    .strip_prefix(':')
+                    .and_then(leading_number)
+            });
+
+        Self {
+            line,
+            column,
+            message: message.trim().to_string(),
+        }
+    }
+
+    /// Devuelve el texto que se muestra en la lista de errores bajo cada editor.
+    pub fn describe(&self) -> String {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => {
+                format!("Line {}, column {}: {}", line, column, self.message)
+            }
+            (Some(line), None) => format!("Line {}: {}", line, self.message),
+            _ => self.message.clone(),
+        }
+    }
+}
+
+impl From<ShapeMapError> for Diagnostic {
+    fn from(error: ShapeMapError) -> Self {
+        Self {
+            line: Some(error.line),
+            column: Some(error.column),
+            message: error.message,
+        }
+    }
+}
+
+/// Comprueba la sintaxis de un ShapeMap de forma local.
+///
+/// # Parámetros
+/// * `content` - Contenido del ShapeMap.
+/// * `format` - Formato del ShapeMap (`Compact` o `JSON`).
+///
+/// # Retorna
+/// Devuelve la lista de problemas encontrados, vacía si el ShapeMap es correcto.
+pub fn check_shapemap(content: &str, format: &str) -> Vec<Diagnostic> {
+    let result = if format == "JSON" {
+        shapemap::parse_json_shapemap(content)
+    } else {
+        shapemap::parse_shapemap(content)
+    };
+    match result {
+        Ok(_) => Vec::new(),
+        Err(error) => vec![error.into()],
+    }
+}
+
+/// Busca el primer número que sigue a la palabra indicada.
+///
+/// La palabra tiene que aparecer completa (no como parte de otra, como `col` en `collection`) y estar
+/// separada del número por `:`, `=` o espacios.
+fn number_after(text: &str, word: &str) -> Option<usize> {
+    let mut search_from = 0;
+    while let Some(found) = text[search_from..].find(word) {
+        let word_start = search_from + found;
+        let start = word_start + word.len();
+        search_from = start;
+        let starts_word = text[..word_start]
+            .chars()
+            .next_back()
+            .is_none_or(|c| !c.is_alphanumeric());
+        let rest = &text[start..];
+        let number = rest.trim_start_matches([':', '=', ' ']);
+        if starts_word && number.len() < rest.len() {
+            if let Some(number) = leading_number(number) {
+                return Some(number);
+            }
+        }
+    }
+    None
+}
+
+/// Lee el número entero con el que comienza el texto, si lo hay.
+fn leading_number(text: &str) -> Option<usize> {
+    let digits: String = text.chars().take_while(|c| c.is_ascii_digit()).collect();
+    digits.parse().ok()
+}
@@ -0,0 +1,169 @@
+use serde::{Deserialize, Serialize};
+
+/// Documento abierto en una pestaña de un panel de edición.
+///
+/// # Campos
+/// * `name` - Nombre de la pestaña
+/// * `content` - Contenido del documento; el de la pestaña activa se guarda al cambiar de pestaña
+/// * `included` - Indica si el documento participa en la validación
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct DocumentTab {
+    pub name: String,
+    pub content: String,
+    #[serde(default = "included_by_default")]
+    pub included: bool,
+}
+
+impl Default for DocumentTab {
+    fn default() -> Self {
+        DocumentTab {
+            name: String::new(),
+            content: String::new(),
+            included: included_by_default(),
+        }
+    }
+}
+
+/// Los documentos nuevos participan en la validación.
+fn included_by_default() -> bool {
+    true
+}
+
+/// Pestañas de un panel de edición. Solo el documento de la pestaña activa está en el editor.
+///
+/// # Campos
+/// * `label` - Prefijo del nombre de las pestañas nuevas (`Schema 2`, `Schema 3`...)
+/// * `tabs` - Documentos abiertos; siempre hay al menos uno
+/// * `active` - Posición de la pestaña activa
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct DocumentTabs {
+    pub label: String,
+    pub tabs: Vec<DocumentTab>,
+    pub active: usize,
+}
+
+impl DocumentTabs {
+    /// Crea las pestañas de un panel con un único documento vacío.
+    pub fn new(label: &str) -> Self {
+        DocumentTabs {
+            label: label.to_string(),
+            tabs: vec![DocumentTab {
+                name: format!("{} 1", label),
+                ..DocumentTab::default()
+            }],
+            active: 0,
+        }
+    }
+
+    /// Corrige unas pestañas leídas del almacenamiento del navegador: lleva la pestaña activa a la última si
+    /// apunta más allá del final.
+    ///
+    /// # Retorna
+    /// Devuelve las pestañas corregidas, o `None` si no hay ninguna.
+    pub fn validated(mut self) -> Option<Self> {
+        if self.tabs.is_empty() {
+            return None;
+        }
+        self.active = self.active.min(self.tabs.len() - 1);
+        Some(self)
+    }
+
+    /// Documento de la pestaña activa.
+    pub fn active_tab(&self) -> Option<&DocumentTab> {
+        self.tabs.get(self.active)
+    }
+
+    /// Guarda el contenido actual del editor en la pestaña activa.
+    fn store_active(&mut self, current: String) {
+        if let Some(tab) = self.tabs.get_mut(self.active) {
+            tab.content = current;
+        }
+    }
+
+    /// Activa la pestaña indicada guardando antes el contenido actual del editor en la pestaña activa.
+    ///
+    /// # Retorna
+    /// Devuelve el contenido que hay que cargar en el editor, o `None` si la pestaña ya estaba activa o no existe.
+    pub fn select(&mut self, index: usize, current: String) -> Option<String> {
+        if index == self.active || index >= self.tabs.len() {
+            return None;
+        }
+        self.store_active(current);
+        self.active = index;
+        Some(self.tabs[index].content.clone())
+    }
+
+    /// Añade una pestaña vacía y la activa, guardando antes el contenido actual del editor.
+    pub fn add(&mut self, current: String) {
+        self.store_active(current);
+        let mut number = self.tabs.len() + 1;
+        while self
+            .tabs
+            .iter()
+            .any(|tab| tab.name == format!("{} {}", self.label, number))
+        {
+            number += 1;
+        }
+        self.tabs.push(DocumentTab {
+            name: format!("{} {}", self.label, number),
+            ..DocumentTab::default()
+        });
+        self.active = self.tabs.len() - 1;
+    }
+
+    /// Cambia el nombre de la pestaña indicada; los nombres vacíos se ignoran.
+    pub fn rename(&mut self, index: usize, name: &str) {
+        let name = name.trim();
+        if let (false, Some(tab)) = (name.is_empty(), self.tabs.get_mut(index)) {
+            tab.name = name.to_string();
+        }
+    }
+
+    /// Cierra la pestaña indicada. La última pestaña no se puede cerrar.
+    ///
+    /// # Retorna
+    /// Devuelve el contenido que hay que cargar en el editor si se ha cerrado la pestaña activa.
+    pub fn close(&mut self, index: usize) -> Option<String> {
+        if self.tabs.len() <= 1 || index >= self.tabs.len() {
+            return None;
+        }
+        self.tabs.remove(index);
+        if index < self.active {
+            self.active -= 1;
+            None
+        } else if index == self.active {
+            self.active = self.active.min(self.tabs.len() - 1);
+            Some(self.tabs[self.active].content.clone())
+        } else {
+            None
+        }
+    }
+
+    /// Incluye o excluye de la validación el documento de la pestaña indicada.
+    pub fn toggle_included(&mut self, index: usize) {
+        if let Some(tab) = self.tabs.get_mut(index) {
+            tab.included = !tab.included;
+        }
+    }
+
+    /// Documentos de todas las pestañas, con el contenido actual del editor en la pestaña activa.
+    pub fn documents(&self, current: String) -> Vec<DocumentTab> {
+        let mut tabs = self.tabs.clone();
+        if let Some(tab) = tabs.get_mut(self.active) {
+            tab.content = current;
+        }
+        tabs
+    }
+
+    /// Documentos que participan en la validación. Si no se ha incluido ninguno, participa el de la pestaña activa.
+    pub fn participating(&self, current: String) -> Vec<DocumentTab> {
+        let documents = self.documents(current);
+        let active = documents.get(self.active).cloned();
+        let included: Vec<DocumentTab> = documents.into_iter().filter(|tab| tab.included).collect();
+        if included.is_empty() {
+            active.into_iter().collect()
+        } else {
+            included
+        }
+    }
+}
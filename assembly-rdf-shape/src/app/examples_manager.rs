@@ -1,6 +1,12 @@
 use gloo_net::http::Request;
 use serde::{Deserialize, Serialize};
-use std::env;
+
+/// URL pública de la aplicación desplegada, de la que se descargan los ejemplos que no están en el origen propio.
+///
+/// Puede cambiarse al compilar con la variable de entorno `PUBLIC_URL`.
+const DEFAULT_PUBLIC_URL: &str = "https://uo271080.github.io/TFG_UO271080/";
+/// Ruta del manifiesto de ejemplos, relativa al origen de la aplicación.
+const MANIFEST_PATH: &str = "examples.json";
 
 /// Datos del fichero ejemplo
 ///
@@ -8,23 +14,106 @@ use std::env;
 /// * `rdf` - Emtrada RDF
 /// * `shex` - Emtrada Shex
 /// * `shapemap` - Emtrada ShapeMap
-#[derive(Serialize, Deserialize, Clone, Debug)]
+/// * `rdf_format`, `shex_format`, `shapemap_format` - Formatos de las entradas; si faltan se toman del manifiesto
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct ExampleData {
     pub rdf: String,
     pub shex: String,
     pub shapemap: String,
+    #[serde(default)]
     pub rdf_format: String,
+    #[serde(default)]
     pub shex_format: String,
+    #[serde(default)]
     pub shapemap_format: String,
 }
 
-/// Carga un ejemplo desde un archivo JSON específico.
+/// Entrada del manifiesto de ejemplos.
+///
+/// # Campos
+/// * `id` - Identificador del ejemplo; también es el nombre de su fichero JSON si no se indica `file`
+/// * `title` - Título que se muestra en el menú
+/// * `description` - Descripción breve del ejemplo
+/// * `tags` - Etiquetas; la primera es la categoría con la que se agrupa en el menú
+/// * `formats` - Formatos RDF, ShEx y ShapeMap del ejemplo, en ese orden
+/// * `file` - Nombre del fichero JSON del ejemplo, sin extensión
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct ExampleInfo {
+    pub id: String,
+    pub title: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub formats: Vec<String>,
+    #[serde(default)]
+    pub file: Option<String>,
+}
+
+impl ExampleInfo {
+    /// Categoría del ejemplo en el menú (su primera etiqueta).
+    pub fn category(&self) -> String {
+        self.tags
+            .first()
+            .cloned()
+            .unwrap_or_else(|| "Other".to_string())
+    }
+
+    /// Indica si el ejemplo encaja con el texto de búsqueda (título, descripción, etiquetas o formatos).
+    pub fn matches(&self, text: &str) -> bool {
+        let text = text.trim().to_lowercase();
+        text.is_empty()
+            || [&self.title, &self.description]
+                .iter()
+                .copied()
+                .chain(self.tags.iter())
+                .chain(self.formats.iter())
+                .any(|field| field.to_lowercase().contains(&text))
+    }
+}
+
+/// Ejemplos que se muestran mientras se carga el manifiesto o si no se puede cargar.
+pub fn default_examples() -> Vec<ExampleInfo> {
+    (1..=3)
+        .map(|index| ExampleInfo {
+            id: index.to_string(),
+            title: format!("Example {}", index),
+            file: Some(format!("example{}", index)),
+            ..ExampleInfo::default()
+        })
+        .collect()
+}
+
+/// Carga el manifiesto de ejemplos (`examples.json`) desde el origen de la aplicación.
+///
+/// # Retorna
+/// Devuelve las entradas del manifiesto o un mensaje de error si no se ha podido descargar o leer.
+pub async fn load_manifest() -> Result<Vec<ExampleInfo>, String> {
+    let response = Request::get(MANIFEST_PATH)
+        .send()
+        .await
+        .map_err(|err| format!("Failed to fetch the examples manifest: {:?}", err))?;
+    if !response.ok() {
+        return Err(format!(
+            "Failed to load the examples manifest: HTTP {}",
+            response.status()
+        ));
+    }
+    response
+        .json()
+        .await
+        .map_err(|err| format!("Failed to parse the examples manifest: {:?}", err))
+}
+
+/// Carga un ejemplo del manifiesto.
 ///
-/// La función realiza una solicitud HTTP GET para obtener los datos del archivo
-/// especificado en formato JSON y los deserializa en un `ExampleData`.
+/// El fichero JSON del ejemplo se busca primero en el origen de la aplicación y, si no está, en la
+/// URL pública de la aplicación desplegada. Los formatos que falten en el fichero se completan con
+/// los del manifiesto.
 ///
 /// # Parámetros
-/// * `file` - El nombre del archivo a cargar
+/// * `example` - Entrada del manifiesto del ejemplo a cargar
 /// # Retorna
 /// Esta función retorna un `Result` que es `Ok` conteniendo `ExampleData` si la carga es exitosa.
 /// Retorna `Err` con un mensaje de error si la carga falla debido a problemas de red o de deserialización.
@@ -32,16 +121,44 @@ pub struct ExampleData {
 /// # Errores
 /// * Retorna un error si la solicitud HTTP no se puede completar o si el estado HTTP no es 200.
 /// * Retorna un error si la deserialización del JSON falla.
-pub async fn load_example(file: String) -> Result<ExampleData, String> {
-    let mut public_url = env::var("https://uo271080.github.io/TFG_UO271080/")
-        .unwrap_or("https://uo271080.github.io/TFG_UO271080/".to_string());
+pub async fn load_example(example: ExampleInfo) -> Result<ExampleData, String> {
+    let file = format!("{}.json", example.file.as_ref().unwrap_or(&example.id));
+    let mut public_url = option_env!("PUBLIC_URL")
+        .unwrap_or(DEFAULT_PUBLIC_URL)
+        .to_string();
     if !public_url.ends_with('/') {
         public_url.push('/');
     }
 
-    let path = format!("{}static/{}.json", public_url, file);
+    let mut data = match fetch_example(&file).await {
+        Ok(data) => data,
+        Err(_) => fetch_example(&format!("{}static/{}", public_url, file))
+            .await
+            .map_err(|err| format!("Example '{}' could not be loaded. {}", example.title, err))?,
+    };
+
+    let format = |index: usize, default: &str| {
+        example
+            .formats
+            .get(index)
+            .cloned()
+            .unwrap_or_else(|| default.to_string())
+    };
+    if data.rdf_format.is_empty() {
+        data.rdf_format = format(0, "Turtle");
+    }
+    if data.shex_format.is_empty() {
+        data.shex_format = format(1, "ShExC");
+    }
+    if data.shapemap_format.is_empty() {
+        data.shapemap_format = format(2, "Compact");
+    }
+    Ok(data)
+}
 
-    let response = Request::get(&path)
+/// Descarga y deserializa el fichero JSON de un ejemplo.
+async fn fetch_example(path: &str) -> Result<ExampleData, String> {
+    let response = Request::get(path)
         .send()
         .await
         .map_err(|err| format!("Failed to fetch: {:?}", err))?;
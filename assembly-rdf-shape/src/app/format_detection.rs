@@ -0,0 +1,248 @@
+use crate::app::rdf_statistics;
+
+/// Espacio de nombres del vocabulario de ShEx usado en ShExR.
+const SHEX_NAMESPACE: &str = "http://www.w3.org/ns/shex#";
+
+/// Tipos de los nodos de un esquema ShExR.
+const SHEXR_TYPES: [&str; 10] = [
+    "Schema",
+    "ShapeDecl",
+    "Shape",
+    "ShapeAnd",
+    "ShapeOr",
+    "ShapeNot",
+    "NodeConstraint",
+    "TripleConstraint",
+    "EachOf",
+    "OneOf",
+];
+
+/// Detecta el formato RDF más probable a partir del contenido de la entrada.
+///
+/// La detección se basa en heurísticas sobre el contenido (JSON, XML, HTML, N-Quads,
+/// N-Triples, TriG o Turtle), por lo que solo debe usarse como sugerencia.
+///
+/// # Parámetros
+/// * `content` - Contenido RDF a analizar.
+///
+/// # Retorna
+/// Devuelve `Some(String)` con el nombre del formato detectado o `None` si la entrada está vacía.
+pub fn detect_rdf_format(content: &str) -> Option<String> {
+    let trimmed = content.trim();
+    if trimmed.is_empty() {
+        return None;
+    }
+
+    let lowercase = trimmed.to_lowercase();
+    if trimmed.starts_with('{') || trimmed.starts_with('[') {
+        if is_rdf_json(trimmed) {
+            return Some("RDF/JSON".to_string());
+        }
+        return Some("JSON-LD".to_string());
+    }
+    if lowercase.starts_with("<!doctype html") || lowercase.starts_with("<html") {
+        return Some("html-rdfa11".to_string());
+    }
+    if lowercase.starts_with("<?xml") || lowercase.starts_with("<rdf:rdf") {
+        return Some("RDF/XML".to_string());
+    }
+
+    let statements: Vec<&str> = trimmed
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .collect();
+    if statements.iter().all(|line| is_ntriples_line(line)) {
+        if statements.iter().any(|line| count_terms(line) == 4) {
+            return Some("N-Quads".to_string());
+        }
+        return Some("N-Triples".to_string());
+    }
+    if statements.iter().any(|line| is_trig_graph_line(line)) {
+        return Some("TriG".to_string());
+    }
+
+    Some("Turtle".to_string())
+}
+
+/// Detecta el formato ShEx más probable a partir del contenido de la entrada.
+///
+/// Un esquema ShExJ es un objeto JSON y uno ShExR es un documento Turtle con nodos de tipo `shex:Schema`,
+/// `shex:Shape`... Que el contenido solo mencione el espacio de nombres de ShEx (por ejemplo, en un
+/// `PREFIX` de un esquema ShExC) no basta para considerarlo ShExR.
+///
+/// # Parámetros
+/// * `content` - Esquema ShEx a analizar.
+///
+/// # Retorna
+/// Devuelve `Some(String)` con `ShExJ`, `Turtle` (ShExR) o `ShExC`, o `None` si la entrada está vacía.
+pub fn detect_shex_format(content: &str) -> Option<String> {
+    let trimmed = content.trim();
+    if trimmed.is_empty() {
+        return None;
+    }
+
+    if trimmed.starts_with('{') {
+        Some("ShExJ".to_string())
+    } else if has_shex_typed_nodes(trimmed) {
+        Some("Turtle".to_string())
+    } else {
+        Some("ShExC".to_string())
+    }
+}
+
+/// Detecta el formato ShapeMap más probable a partir del contenido de la entrada.
+///
+/// # Parámetros
+/// * `content` - ShapeMap a analizar.
+///
+/// # Retorna
+/// Devuelve `Some(String)` con `JSON` o `Compact`, o `None` si la entrada está vacía.
+pub fn detect_shapemap_format(content: &str) -> Option<String> {
+    let trimmed = content.trim();
+    if trimmed.is_empty() {
+        return None;
+    }
+
+    if trimmed.starts_with('[') {
+        Some("JSON".to_string())
+    } else {
+        Some("Compact".to_string())
+    }
+}
+
+/// Comprueba si el formato RDF seleccionado es capaz de leer el formato detectado.
+///
+/// Turtle, TriG y N-Quads aceptan documentos N-Triples, TriG acepta Turtle y el
+/// formato `mixed` acepta cualquier entrada, por lo que no se consideran un conflicto.
+///
+/// # Parámetros
+/// * `selected` - Formato seleccionado por el usuario.
+/// * `detected` - Formato detectado a partir del contenido.
+///
+/// # Retorna
+/// Devuelve `true` si no es necesario cambiar el formato seleccionado.
+pub fn is_rdf_format_compatible(selected: &str, detected: &str) -> bool {
+    selected == detected
+        || selected == "mixed"
+        || (detected == "N-Triples" && ["Turtle", "TriG", "N-Quads"].contains(&selected))
+        || (detected == "Turtle" && selected == "TriG")
+        || (detected == "html-rdfa11" && selected == "html-microdata")
+}
+
+/// Comprueba si un documento contiene tripletas Turtle `a <tipo>` o `rdf:type <tipo>` cuyo tipo pertenece al
+/// vocabulario de ShExR, escrito como IRI completa o con un prefijo declarado para el espacio de nombres de ShEx.
+fn has_shex_typed_nodes(content: &str) -> bool {
+    let shex_prefixes: Vec<String> = rdf_statistics::declared_prefixes(content)
+        .into_iter()
+        .filter(|prefix| prefix.prefix_iri == SHEX_NAMESPACE)
+        .map(|prefix| prefix.prefix_name)
+        .collect();
+    let is_shex_type = |token: &str| {
+        let token = token.trim_end_matches([';', ',', '.']);
+        let local = match token
+            .strip_prefix('<')
+            .and_then(|iri| iri.strip_suffix('>'))
+        {
+            Some(iri) => iri.strip_prefix(SHEX_NAMESPACE),
+            None => token.split_once(':').and_then(|(prefix, local)| {
+                shex_prefixes
+                    .iter()
+                    .any(|name| name == prefix)
+                    .then_some(local)
+            }),
+        };
+        local.is_some_and(|local| SHEXR_TYPES.contains(&local))
+    };
+    let tokens: Vec<&str> = content.split_whitespace().collect();
+    tokens.windows(2).any(|pair| {
+        [
+            "a",
+            "rdf:type",
+            "<http://www.w3.org/1999/02/22-rdf-syntax-ns#type>",
+        ]
+        .contains(&pair[0])
+            && is_shex_type(pair[1])
+    })
+}
+
+/// Comprueba si un objeto JSON tiene la estructura de RDF/JSON (sujeto → predicado → valores con `type`).
+fn is_rdf_json(content: &str) -> bool {
+    match serde_json::from_str::<serde_json::Value>(content) {
+        Ok(serde_json::Value::Object(subjects)) => {
+            !subjects.is_empty()
+                && subjects.iter().all(|(subject, predicates)| {
+                    !subject.starts_with('@')
+                        && predicates.as_object().is_some_and(|predicates| {
+                            predicates.values().all(|values| {
+                                values.as_array().is_some_and(|values| {
+                                    values.iter().all(|value| value.get("type").is_some())
+                                })
+                            })
+                        })
+                })
+        }
+        _ => false,
+    }
+}
+
+/// Comprueba si una línea tiene la forma de una sentencia N-Triples o N-Quads.
+fn is_ntriples_line(line: &str) -> bool {
+    (line.starts_with('<') || line.starts_with("_:"))
+        && line.ends_with('.')
+        && (3..=4).contains(&count_terms(line))
+}
+
+/// Comprueba si una línea abre un grafo con nombre de TriG.
+fn is_trig_graph_line(line: &str) -> bool {
+    if line.to_lowercase().starts_with("graph ") {
+        return true;
+    }
+    match line.strip_suffix('{') {
+        Some(graph) => graph.split_whitespace().count() <= 1,
+        None => false,
+    }
+}
+
+/// Cuenta los términos de una sentencia N-Triples o N-Quads, ignorando el punto final.
+fn count_terms(line: &str) -> usize {
+    let mut count = 0;
+    let mut chars = line.trim_end_matches('.').trim().chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            ' ' | '\t' => continue,
+            '<' => {
+                for c in chars.by_ref() {
+                    if c == '>' {
+                        break;
+                    }
+                }
+            }
+            '"' => {
+                let mut escaped = false;
+                for c in chars.by_ref() {
+                    if c == '"' && !escaped {
+                        break;
+                    }
+                    escaped = c == '\\' && !escaped;
+                }
+                while let Some(&next) = chars.peek() {
+                    if next == ' ' || next == '\t' {
+                        break;
+                    }
+                    chars.next();
+                }
+            }
+            _ => {
+                while let Some(&next) = chars.peek() {
+                    if next == ' ' || next == '\t' {
+                        break;
+                    }
+                    chars.next();
+                }
+            }
+        }
+        count += 1;
+    }
+    count
+}
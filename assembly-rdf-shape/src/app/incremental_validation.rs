@@ -0,0 +1,337 @@
+use crate::app::api::{self, Prefix, ShapeMapEntry, ValidationParameters, ValidationResult};
+use crate::app::batch_validation;
+use crate::app::ntriples::{self, Term, Triple};
+use crate::app::query_shapemap;
+use crate::app::request_policy::RequestOptions;
+use crate::app::shapemap::{self, NodeSelector, ShapeMapAssociation};
+use crate::app::shexj;
+use std::collections::HashSet;
+
+/// Datos validados por última vez, con los parámetros y el resultado de la validación.
+///
+/// `triples` guarda los datos convertidos a N-Triples en la última validación incremental, para no volver a
+/// convertirlos en la siguiente; es `None` si todavía no se han convertido.
+#[derive(Clone, Default)]
+pub struct ValidatedData {
+    pub rdf: String,
+    pub parameters: ValidationParameters,
+    pub result: ValidationResult,
+    pub triples: Option<Vec<Triple>>,
+}
+
+/// Resumen de una validación incremental.
+///
+/// # Campos
+/// * `changed_triples` - Tripletas añadidas o eliminadas respecto a los datos validados por última vez
+/// * `revalidated` - Asociaciones del ShapeMap que se han vuelto a validar
+/// * `total` - Asociaciones del ShapeMap
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct IncrementalSummary {
+    pub changed_triples: usize,
+    pub revalidated: usize,
+    pub total: usize,
+}
+
+/// Asociaciones que hay que volver a validar tras un cambio en los datos, con las tripletas de los datos
+/// actuales.
+struct IncrementalPlan {
+    changed_triples: usize,
+    associations: Vec<ShapeMapAssociation>,
+    total: usize,
+    triples: Vec<Triple>,
+}
+
+/// Indica si la validación de unos datos se puede resolver de forma incremental a partir de la anterior:
+/// mismo esquema ShEx, mismo ShapeMap y mismos parámetros, con datos distintos y sin inferencia (las
+/// tripletas inferidas pueden cambiar el resultado de nodos que no aparecen en las tripletas modificadas).
+pub fn can_revalidate(
+    previous: &ValidatedData,
+    rdf: &str,
+    parameters: &ValidationParameters,
+) -> bool {
+    parameters.engine == "ShEx"
+        && parameters.inference == "NONE"
+        && previous.parameters == *parameters
+        && previous.rdf != rdf
+}
+
+/// Valida los datos volviendo a validar solo los nodos a los que puede afectar el cambio respecto a la
+/// última validación, y combina su resultado con el anterior.
+///
+/// Si no se puede determinar qué nodos están afectados (selectores de consulta en el ShapeMap, datos o
+/// esquema que no se pueden convertir, cambios en tripletas con nodos en blanco) o el cambio afecta a todos
+/// los nodos, se validan todos los datos.
+///
+/// # Parámetros
+/// * `previous` - Última validación
+/// * `rdf` - Datos RDF modificados
+/// * `options` - Opciones de las solicitudes a la API
+///
+/// # Retorna
+/// Devuelve el resultado, el mensaje de error (vacío si no ha habido ninguno), el resumen de la validación
+/// incremental, o `None` si se han validado todos los datos, y las tripletas de los datos si se han convertido.
+pub async fn revalidate(
+    previous: ValidatedData,
+    rdf: String,
+    options: RequestOptions,
+) -> (
+    ValidationResult,
+    String,
+    Option<IncrementalSummary>,
+    Option<Vec<Triple>>,
+) {
+    let plan = match plan(&previous, &rdf, &options).await {
+        Some(plan) if plan.associations.len() < plan.total => plan,
+        plan => {
+            let (result, error) =
+                batch_validation::validate_data(rdf, previous.parameters, options).await;
+            return (result, error, None, plan.map(|plan| plan.triples));
+        }
+    };
+    let summary = IncrementalSummary {
+        changed_triples: plan.changed_triples,
+        revalidated: plan.associations.len(),
+        total: plan.total,
+    };
+    if plan.associations.is_empty() {
+        let result = ValidationResult {
+            cached: false,
+            ..previous.result
+        };
+        return (result, String::new(), Some(summary), Some(plan.triples));
+    }
+
+    let parameters = ValidationParameters {
+        shapemap: plan
+            .associations
+            .iter()
+            .map(|association| shapemap::format_association(&association.node, &association.shape))
+            .collect::<Vec<String>>()
+            .join(",\n"),
+        shapemap_format: "Compact".to_string(),
+        ..previous.parameters
+    };
+    let (partial, error) = batch_validation::validate_data(rdf, parameters, options).await;
+    if !error.is_empty() {
+        return (partial, error, None, None);
+    }
+    (
+        merge_results(previous.result, partial),
+        error,
+        Some(summary),
+        Some(plan.triples),
+    )
+}
+
+/// Calcula las asociaciones del ShapeMap a las que afecta el cambio en los datos.
+async fn plan(
+    previous: &ValidatedData,
+    rdf: &str,
+    options: &RequestOptions,
+) -> Option<IncrementalPlan> {
+    let parameters = &previous.parameters;
+    let associations = if parameters.shapemap_format == "JSON" {
+        shapemap::parse_json_shapemap(&parameters.shapemap).ok()?
+    } else {
+        shapemap::parse_shapemap(&parameters.shapemap).ok()?
+    };
+    // Los nodos que seleccionan las consultas pueden cambiar con los datos
+    if query_shapemap::has_query_selectors(&associations) {
+        return None;
+    }
+
+    let (info, error) = api::call_shex_info_api(
+        parameters.shex.clone(),
+        parameters.shex_format.clone(),
+        options,
+    )
+    .await;
+    if !error.is_empty() {
+        return None;
+    }
+    let old_triples = match &previous.triples {
+        Some(triples) => triples.clone(),
+        None => load_triples(previous.rdf.clone(), parameters.rdf_format.clone(), options).await?,
+    };
+    let new_triples = load_triples(rdf.to_string(), parameters.rdf_format.clone(), options).await?;
+    // Las etiquetas de los nodos en blanco no son estables entre conversiones y desde el ShapeMap no se puede
+    // hacer referencia a ellos, así que no se sabe qué asociaciones afectan
+    if changed_triples(&old_triples, &new_triples)
+        .iter()
+        .any(|triple| triple.subject.is_blank_node() || triple.object.is_blank_node())
+    {
+        return None;
+    }
+    let (response, error) = api::call_shex_convert_api(
+        parameters.shex.clone(),
+        parameters.shex_format.clone(),
+        "ShExJ".to_string(),
+        options,
+    )
+    .await;
+    let predicates = if error.is_empty() {
+        shexj::shape_reference_predicates(&response.result.content)
+    } else {
+        None
+    };
+
+    // Los nombres prefijados del ShapeMap se resuelven con los prefijos de los datos; si cambian, los mismos
+    // nombres pueden designar otros nodos
+    let prefixes = query_shapemap::shapemap_prefixes(rdf, info.result.prefix_map.clone());
+    if prefixes != query_shapemap::shapemap_prefixes(&previous.rdf, info.result.prefix_map) {
+        return None;
+    }
+    let (changed_triples, affected) =
+        affected_nodes(&old_triples, &new_triples, predicates.as_deref());
+    let total = associations.len();
+    let associations = associations
+        .into_iter()
+        .filter(|association| is_affected(association, &affected, &prefixes))
+        .collect();
+    Some(IncrementalPlan {
+        changed_triples,
+        associations,
+        total,
+        triples: new_triples,
+    })
+}
+
+/// Tripletas añadidas o eliminadas entre dos versiones de los datos.
+fn changed_triples<'a>(old: &'a [Triple], new: &'a [Triple]) -> Vec<&'a Triple> {
+    let old_set: HashSet<&Triple> = old.iter().collect();
+    let new_set: HashSet<&Triple> = new.iter().collect();
+    old_set.symmetric_difference(&new_set).copied().collect()
+}
+
+/// Convierte los datos a N-Triples con la API y los analiza.
+async fn load_triples(
+    rdf: String,
+    format: String,
+    options: &RequestOptions,
+) -> Option<Vec<Triple>> {
+    let (response, error) =
+        api::call_rdf_convert_api(rdf, format, "N-Triples".to_string(), options).await;
+    if error.is_empty() {
+        Some(ntriples::parse_ntriples(&response.result.content))
+    } else {
+        None
+    }
+}
+
+/// Calcula los nodos cuyo resultado puede cambiar entre dos versiones de los datos.
+///
+/// Están afectados los sujetos y objetos de las tripletas añadidas o eliminadas y, de forma transitiva, los
+/// nodos que hacen referencia a un nodo afectado a través de un predicado cuyo valor se valida con otra forma
+/// (o que reciben la referencia, si la restricción es inversa). Sin la lista de predicados se consideran todos.
+///
+/// # Parámetros
+/// * `old` - Tripletas de los datos validados por última vez
+/// * `new` - Tripletas de los datos actuales
+/// * `predicates` - Predicados que hacen referencia a otras formas, con la indicación de si son inversos
+///
+/// # Retorna
+/// Devuelve el número de tripletas que cambian y los nodos afectados.
+pub fn affected_nodes(
+    old: &[Triple],
+    new: &[Triple],
+    predicates: Option<&[(String, bool)]>,
+) -> (usize, HashSet<Term>) {
+    let changed = changed_triples(old, new);
+
+    let mut affected: HashSet<Term> = HashSet::new();
+    for triple in changed.iter() {
+        affected.insert(triple.subject.clone());
+        if !triple.object.is_literal() {
+            affected.insert(triple.object.clone());
+        }
+    }
+
+    let references = |predicate: &str, inverse: bool| match predicates {
+        Some(predicates) => predicates.iter().any(|(candidate, candidate_inverse)| {
+            candidate == predicate && *candidate_inverse == inverse
+        }),
+        None => true,
+    };
+    let edges: HashSet<&Triple> = old.iter().chain(new.iter()).collect();
+    loop {
+        let mut added = false;
+        for triple in edges.iter() {
+            if references(&triple.predicate, false)
+                && affected.contains(&triple.object)
+                && affected.insert(triple.subject.clone())
+            {
+                added = true;
+            }
+            if references(&triple.predicate, true)
+                && !triple.object.is_literal()
+                && affected.contains(&triple.subject)
+                && affected.insert(triple.object.clone())
+            {
+                added = true;
+            }
+        }
+        if !added {
+            break;
+        }
+    }
+    (changed.len(), affected)
+}
+
+/// Indica si hay que volver a validar la asociación; las que no se pueden resolver se validan siempre.
+fn is_affected(
+    association: &ShapeMapAssociation,
+    affected: &HashSet<Term>,
+    prefixes: &[Prefix],
+) -> bool {
+    match &association.node {
+        NodeSelector::Node(node) => match query_shapemap::pattern_term(node, prefixes) {
+            Ok(Some(term)) => affected.contains(&term),
+            _ => true,
+        },
+        _ => true,
+    }
+}
+
+/// Sustituye en el resultado anterior las entradas de los pares nodo-forma que se han vuelto a validar.
+///
+/// Los pares se comparan con el nodo y la forma completos, no con sus nombres acortados. Las entradas nuevas
+/// ocupan el lugar de la primera entrada anterior del mismo par; las de pares que no estaban en el resultado
+/// anterior se añaden al final.
+pub fn merge_results(previous: ValidationResult, partial: ValidationResult) -> ValidationResult {
+    let revalidated: HashSet<(&str, &str)> = partial
+        .result
+        .shape_map
+        .iter()
+        .map(ShapeMapEntry::key)
+        .collect();
+    let mut merged: Vec<ShapeMapEntry> = Vec::new();
+    let mut inserted: HashSet<(&str, &str)> = HashSet::new();
+    for entry in previous.result.shape_map.iter() {
+        let key = entry.key();
+        if !revalidated.contains(&key) {
+            merged.push(entry.clone());
+        } else if inserted.insert(key) {
+            merged.extend(
+                partial
+                    .result
+                    .shape_map
+                    .iter()
+                    .filter(|new_entry| new_entry.key() == key)
+                    .cloned(),
+            );
+        }
+    }
+    merged.extend(
+        partial
+            .result
+            .shape_map
+            .iter()
+            .filter(|entry| !inserted.contains(&entry.key()))
+            .cloned(),
+    );
+
+    let mut result = partial;
+    result.result.valid = merged.iter().all(|entry| entry.status == "Valid");
+    result.result.shape_map = merged;
+    result
+}
@@ -0,0 +1,178 @@
+use serde::{Deserialize, Serialize};
+
+/// IRI del predicado `rdf:type`.
+pub const RDF_TYPE: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#type";
+
+/// Término RDF de una tripleta.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Term {
+    /// IRI sin los delimitadores `<` y `>`.
+    Iri(String),
+    /// Nodo en blanco sin el prefijo `_:`.
+    BlankNode(String),
+    /// Literal con su tipo de dato o etiqueta de idioma opcionales.
+    Literal {
+        value: String,
+        datatype: Option<String>,
+        language: Option<String>,
+    },
+}
+
+impl Term {
+    /// Devuelve el término en sintaxis N-Triples.
+    pub fn to_ntriples(&self) -> String {
+        match self {
+            Term::Iri(iri) => format!("<{}>", iri),
+            Term::BlankNode(label) => format!("_:{}", label),
+            Term::Literal {
+                value,
+                datatype,
+                language,
+            } => match (datatype, language) {
+                (_, Some(language)) => format!("\"{}\"@{}", escape(value), language),
+                (Some(datatype), None) => format!("\"{}\"^^<{}>", escape(value), datatype),
+                (None, None) => format!("\"{}\"", escape(value)),
+            },
+        }
+    }
+
+    /// Indica si el término es un literal.
+    pub fn is_literal(&self) -> bool {
+        matches!(self, Term::Literal { .. })
+    }
+
+    /// Indica si el término es un nodo en blanco.
+    pub fn is_blank_node(&self) -> bool {
+        matches!(self, Term::BlankNode(_))
+    }
+}
+
+/// Tripleta RDF obtenida de un documento N-Triples.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Triple {
+    pub subject: Term,
+    pub predicate: String,
+    pub object: Term,
+}
+
+/// Analiza un documento N-Triples.
+///
+/// Las líneas vacías, los comentarios y las líneas que no forman una tripleta válida se ignoran.
+///
+/// # Parámetros
+/// * `content` - Documento N-Triples (normalmente obtenido con el endpoint de conversión).
+///
+/// # Retorna
+/// Devuelve la lista de tripletas del documento.
+pub fn parse_ntriples(content: &str) -> Vec<Triple> {
+    content.lines().filter_map(parse_line).collect()
+}
+
+/// Analiza una línea N-Triples.
+fn parse_line(line: &str) -> Option<Triple> {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') {
+        return None;
+    }
+
+    let mut rest = line;
+    let subject = parse_term(&mut rest)?;
+    let predicate = match parse_term(&mut rest)? {
+        Term::Iri(iri) => iri,
+        _ => return None,
+    };
+    let object = parse_term(&mut rest)?;
+    if subject.is_literal() || !rest.trim_start().starts_with('.') {
+        return None;
+    }
+
+    Some(Triple {
+        subject,
+        predicate,
+        object,
+    })
+}
+
+/// Analiza el siguiente término de la línea y avanza `rest` tras él.
+fn parse_term(rest: &mut &str) -> Option<Term> {
+    let input = rest.trim_start();
+    if let Some(iri) = input.strip_prefix('<') {
+        let end = iri.find('>')?;
+        *rest = &iri[end + 1..];
+        return Some(Term::Iri(iri[..end].to_string()));
+    }
+    if let Some(label) = input.strip_prefix("_:") {
+        let end = label
+            .find(|c: char| c.is_whitespace() || c == '.')
+            .unwrap_or(label.len());
+        *rest = &label[end..];
+        return Some(Term::BlankNode(label[..end].to_string()));
+    }
+    if let Some(literal) = input.strip_prefix('"') {
+        let mut value = String::new();
+        let mut chars = literal.char_indices();
+        let mut end = None;
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '\\' => {
+                    if let Some((_, escaped)) = chars.next() {
+                        value.push(unescape(escaped));
+                    }
+                }
+                '"' => {
+                    end = Some(i);
+                    break;
+                }
+                _ => value.push(c),
+            }
+        }
+        let after = &literal[end? + 1..];
+        if let Some(language) = after.strip_prefix('@') {
+            let len = language
+                .find(|c: char| c.is_whitespace() || c == '.')
+                .unwrap_or(language.len());
+            *rest = &language[len..];
+            return Some(Term::Literal {
+                value,
+                datatype: None,
+                language: Some(language[..len].to_string()),
+            });
+        }
+        if let Some(datatype) = after.strip_prefix("^^<") {
+            let len = datatype.find('>')?;
+            *rest = &datatype[len + 1..];
+            return Some(Term::Literal {
+                value,
+                datatype: Some(datatype[..len].to_string()),
+                language: None,
+            });
+        }
+        *rest = after;
+        return Some(Term::Literal {
+            value,
+            datatype: None,
+            language: None,
+        });
+    }
+    None
+}
+
+/// Escapa los caracteres especiales de un literal N-Triples.
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+        .replace('\t', "\\t")
+}
+
+/// Traduce el carácter que sigue a una barra invertida en un literal.
+fn unescape(c: char) -> char {
+    match c {
+        'n' => '\n',
+        't' => '\t',
+        'r' => '\r',
+        other => other,
+    }
+}
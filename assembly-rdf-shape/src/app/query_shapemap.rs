@@ -0,0 +1,376 @@
+use crate::app::api::{self, Prefix, QueryBinding};
+use crate::app::ntriples::{self, Term, Triple};
+use crate::app::rdf_statistics;
+use crate::app::request_policy::RequestOptions;
+use crate::app::shapemap::{self, NodeSelector, ShapeLabel, ShapeMapAssociation};
+use std::collections::{HashMap, HashSet};
+
+/// Asociación del ShapeMap con su selector expandido a la lista de nodos concretos.
+///
+/// # Campos
+/// * `selector` - Asociación original en formato compacto
+/// * `shape` - Forma de la asociación
+/// * `nodes` - Nodos seleccionados, vacío si el selector no se pudo expandir
+/// * `error` - Motivo por el que el selector no se pudo expandir localmente
+#[derive(Clone, Debug, PartialEq)]
+pub struct ResolvedAssociation {
+    pub selector: String,
+    pub shape: ShapeLabel,
+    pub nodes: Vec<Term>,
+    pub error: Option<String>,
+}
+
+/// Indica si alguna asociación usa un selector de consulta (patrón de tripleta o SPARQL).
+pub fn has_query_selectors(associations: &[ShapeMapAssociation]) -> bool {
+    associations
+        .iter()
+        .any(|association| !matches!(association.node, NodeSelector::Node(_)))
+}
+
+/// Prefijos con los que se resuelven los nombres prefijados del ShapeMap: los declarados en los datos y,
+/// después, los del esquema que no se llamen igual.
+pub fn shapemap_prefixes(rdf: &str, schema_prefixes: Vec<Prefix>) -> Vec<Prefix> {
+    let mut prefixes = rdf_statistics::declared_prefixes(rdf);
+    for prefix in schema_prefixes {
+        if !prefixes
+            .iter()
+            .any(|existing| existing.prefix_name == prefix.prefix_name)
+        {
+            prefixes.push(prefix);
+        }
+    }
+    prefixes
+}
+
+/// Expande las asociaciones de un ShapeMap compacto a nodos concretos.
+///
+/// Los datos se convierten a N-Triples para resolver los patrones de tripleta de forma local y
+/// las consultas SPARQL se ejecutan con el endpoint de consultas de la API. Ninguno de los dos
+/// tiene en cuenta la inferencia, así que con inferencia los selectores de consulta se dejan sin
+/// expandir para que los resuelva el servidor.
+///
+/// # Parámetros
+/// * `shapemap` - Contenido del ShapeMap en formato compacto.
+/// * `rdf` - Contenido RDF sobre el que se resuelven los selectores.
+/// * `rdf_format` - Formato del RDF.
+/// * `inference` - Régimen de inferencia de la validación.
+/// * `prefixes` - Prefijos con los que se resuelven los nombres prefijados del ShapeMap (ver `shapemap_prefixes`).
+/// * `options` - Configuración y señal de cancelación de las solicitudes a la API.
+///
+/// # Retorna
+/// Devuelve las asociaciones resueltas o un mensaje de error si el ShapeMap o los datos no son válidos.
+pub async fn resolve_shapemap(
+    shapemap: String,
+    rdf: String,
+    rdf_format: String,
+    inference: String,
+    prefixes: Vec<Prefix>,
+    options: &RequestOptions,
+) -> Result<Vec<ResolvedAssociation>, String> {
+    let associations = shapemap::parse_shapemap(&shapemap).map_err(|e| {
+        format!(
+            "ShapeMap is not well-formed (line {}, column {}): {}",
+            e.line, e.column, e.message
+        )
+    })?;
+    if inference != "NONE" {
+        return Ok(associations
+            .iter()
+            .map(|association| {
+                let nodes = match &association.node {
+                    NodeSelector::Node(node) => node_terms(node, &prefixes),
+                    _ => Err(format!(
+                        "Resolved by the server because {} inference is enabled",
+                        inference
+                    )),
+                };
+                resolved_association(association, nodes)
+            })
+            .collect());
+    }
+
+    let triples = if associations
+        .iter()
+        .any(|association| matches!(association.node, NodeSelector::TriplePattern { .. }))
+    {
+        let (response, error) = api::call_rdf_convert_api(
+            rdf.clone(),
+            rdf_format.clone(),
+            "N-Triples".to_string(),
+            options,
+        )
+        .await;
+        if !error.is_empty() {
+            return Err(format!("RDF is not well-formed: {}", error));
+        }
+        ntriples::parse_ntriples(&response.result.content)
+    } else {
+        Vec::new()
+    };
+
+    let mut sparql_results: HashMap<String, Result<Vec<Term>, String>> = HashMap::new();
+    for association in associations.iter() {
+        if let NodeSelector::Sparql(query) = &association.node {
+            if !sparql_results.contains_key(query) {
+                let (response, error) = api::call_rdf_query_api(
+                    rdf.clone(),
+                    rdf_format.clone(),
+                    query.clone(),
+                    options,
+                )
+                .await;
+                let result = if error.is_empty() {
+                    let var = response
+                        .result
+                        .head
+                        .vars
+                        .first()
+                        .cloned()
+                        .unwrap_or_default();
+                    Ok(response
+                        .result
+                        .results
+                        .bindings
+                        .iter()
+                        .filter_map(|binding| binding.get(&var))
+                        .map(binding_to_term)
+                        .collect())
+                } else {
+                    Err(error)
+                };
+                sparql_results.insert(query.clone(), result);
+            }
+        }
+    }
+
+    Ok(resolve_associations(
+        &associations,
+        &triples,
+        &prefixes,
+        &sparql_results,
+    ))
+}
+
+/// Resuelve cada asociación con las tripletas y los resultados SPARQL ya disponibles.
+pub fn resolve_associations(
+    associations: &[ShapeMapAssociation],
+    triples: &[Triple],
+    prefixes: &[Prefix],
+    sparql_results: &HashMap<String, Result<Vec<Term>, String>>,
+) -> Vec<ResolvedAssociation> {
+    associations
+        .iter()
+        .map(|association| {
+            let nodes = match &association.node {
+                NodeSelector::Node(node) => node_terms(node, prefixes),
+                NodeSelector::TriplePattern {
+                    subject,
+                    predicate,
+                    object,
+                } => select_nodes(subject, predicate, object, triples, prefixes),
+                NodeSelector::Sparql(query) => sparql_results
+                    .get(query)
+                    .cloned()
+                    .unwrap_or_else(|| Err("Query was not executed".to_string())),
+            };
+            resolved_association(association, nodes)
+        })
+        .collect()
+}
+
+/// Convierte el nodo de una asociación fija en la lista con su término.
+fn node_terms(node: &str, prefixes: &[Prefix]) -> Result<Vec<Term>, String> {
+    pattern_term(node, prefixes).and_then(|term| {
+        term.map(|term| vec![term])
+            .ok_or_else(|| "'_' is only allowed inside triple patterns".to_string())
+    })
+}
+
+/// Construye la asociación resuelta con los nodos seleccionados o el motivo por el que no se han podido obtener.
+fn resolved_association(
+    association: &ShapeMapAssociation,
+    nodes: Result<Vec<Term>, String>,
+) -> ResolvedAssociation {
+    let (nodes, error) = match nodes {
+        Ok(nodes) => (nodes, None),
+        Err(error) => (Vec::new(), Some(error)),
+    };
+    ResolvedAssociation {
+        selector: shapemap::format_association(&association.node, &association.shape),
+        shape: association.shape.clone(),
+        nodes,
+        error,
+    }
+}
+
+/// Construye un ShapeMap fijo (solo nodos concretos) a partir de las asociaciones resueltas.
+///
+/// Las asociaciones que no se pudieron expandir o que seleccionan nodos en blanco (cuyas etiquetas
+/// no se conservan entre documentos) se mantienen tal cual para que las resuelva el servidor. Los nodos
+/// se escriben con su IRI completa para que el servidor no dependa de los prefijos con que se han resuelto.
+/// Los selectores que no seleccionan ningún nodo no aparecen en el resultado (ver `unmatched_selectors`).
+pub fn to_fixed_shapemap(resolved: &[ResolvedAssociation]) -> String {
+    let mut entries: Vec<String> = Vec::new();
+    for association in resolved {
+        let keep_selector = association.error.is_some()
+            || association
+                .nodes
+                .iter()
+                .any(|node| matches!(node, Term::BlankNode(_)));
+        if keep_selector {
+            entries.push(association.selector.clone());
+            continue;
+        }
+        for node in association.nodes.iter() {
+            let entry = shapemap::format_association(
+                &NodeSelector::Node(node.to_ntriples()),
+                &association.shape,
+            );
+            if !entries.contains(&entry) {
+                entries.push(entry);
+            }
+        }
+    }
+    entries.join(",\n")
+}
+
+/// Selectores que se han expandido sin errores pero no seleccionan ningún nodo de los datos.
+pub fn unmatched_selectors(resolved: &[ResolvedAssociation]) -> Vec<String> {
+    resolved
+        .iter()
+        .filter(|association| association.error.is_none() && association.nodes.is_empty())
+        .map(|association| association.selector.clone())
+        .collect()
+}
+
+/// Devuelve el término de forma legible, acortando las IRIs con los prefijos indicados.
+pub fn display_term(term: &Term, prefixes: &[Prefix]) -> String {
+    match term {
+        Term::Iri(iri) => shapemap::compact_iri(iri, prefixes),
+        _ => term.to_ntriples(),
+    }
+}
+
+/// Expande un nombre prefijado o una IRI entre `<` y `>` a la IRI completa.
+pub fn expand_iri(text: &str, prefixes: &[Prefix]) -> Result<String, String> {
+    if text == "a" {
+        return Ok(ntriples::RDF_TYPE.to_string());
+    }
+    if let Some(iri) = text.strip_prefix('<').and_then(|iri| iri.strip_suffix('>')) {
+        return Ok(iri.to_string());
+    }
+    let (prefix, local) = text
+        .split_once(':')
+        .ok_or_else(|| format!("'{}' is not an IRI", text))?;
+    prefixes
+        .iter()
+        .find(|candidate| candidate.prefix_name == prefix)
+        .map(|candidate| format!("{}{}", candidate.prefix_iri, local))
+        .ok_or_else(|| format!("Unknown prefix '{}:'", prefix))
+}
+
+/// Convierte un término escrito en el ShapeMap en un `Term`; `_` se devuelve como `None` (comodín).
+pub fn pattern_term(text: &str, prefixes: &[Prefix]) -> Result<Option<Term>, String> {
+    if text == "_" {
+        return Ok(None);
+    }
+    if let Some(label) = text.strip_prefix("_:") {
+        return Ok(Some(Term::BlankNode(label.to_string())));
+    }
+    if let Some(literal) = text.strip_prefix('"') {
+        let end = literal.rfind('"').unwrap_or(literal.len());
+        let value = literal[..end].to_string();
+        let rest = &literal[(end + 1).min(literal.len())..];
+        let (datatype, language) = if let Some(language) = rest.strip_prefix('@') {
+            (None, Some(language.to_string()))
+        } else if let Some(datatype) = rest.strip_prefix("^^") {
+            (Some(expand_iri(datatype, prefixes)?), None)
+        } else {
+            (None, None)
+        };
+        return Ok(Some(Term::Literal {
+            value,
+            datatype,
+            language,
+        }));
+    }
+    if text.starts_with(|c: char| c.is_ascii_digit() || c == '-' || c == '+') {
+        return Ok(Some(Term::Literal {
+            value: text.to_string(),
+            datatype: None,
+            language: None,
+        }));
+    }
+    expand_iri(text, prefixes).map(|iri| Some(Term::Iri(iri)))
+}
+
+/// Comprueba si un término de los datos encaja con el término del patrón.
+fn term_matches(pattern: &Option<Term>, term: &Term) -> bool {
+    match (pattern, term) {
+        (None, _) => true,
+        (
+            Some(Term::Literal {
+                value,
+                datatype: None,
+                language: None,
+            }),
+            Term::Literal {
+                value: other,
+                language: None,
+                ..
+            },
+        ) => value == other,
+        (Some(pattern), term) => pattern == term,
+    }
+}
+
+/// Selecciona los nodos foco de un patrón de tripleta sobre las tripletas de los datos.
+fn select_nodes(
+    subject: &str,
+    predicate: &str,
+    object: &str,
+    triples: &[Triple],
+    prefixes: &[Prefix],
+) -> Result<Vec<Term>, String> {
+    let predicate = if predicate == "_" {
+        None
+    } else {
+        Some(expand_iri(predicate, prefixes)?)
+    };
+    let focus_is_subject = subject == "FOCUS";
+    let other = pattern_term(if focus_is_subject { object } else { subject }, prefixes)?;
+
+    // Los nodos se devuelven en el orden de las tripletas; el conjunto solo sirve para descartar repetidos
+    let mut nodes: Vec<Term> = Vec::new();
+    let mut seen: HashSet<String> = HashSet::new();
+    for triple in triples {
+        if predicate
+            .as_ref()
+            .is_some_and(|predicate| predicate != &triple.predicate)
+        {
+            continue;
+        }
+        let (focus, other_term) = if focus_is_subject {
+            (&triple.subject, &triple.object)
+        } else {
+            (&triple.object, &triple.subject)
+        };
+        if term_matches(&other, other_term) && seen.insert(focus.to_ntriples()) {
+            nodes.push(focus.clone());
+        }
+    }
+    Ok(nodes)
+}
+
+/// Convierte un valor de los resultados SPARQL en un `Term`.
+fn binding_to_term(binding: &QueryBinding) -> Term {
+    match binding.binding_type.as_str() {
+        "uri" => Term::Iri(binding.value.clone()),
+        "bnode" => Term::BlankNode(binding.value.clone()),
+        _ => Term::Literal {
+            value: binding.value.clone(),
+            datatype: binding.datatype.clone(),
+            language: binding.lang.clone(),
+        },
+    }
+}
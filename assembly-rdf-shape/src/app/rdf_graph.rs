@@ -0,0 +1,345 @@
+use crate::app::api::{self, ShapeMapEntry};
+use crate::app::ntriples::{Term, Triple};
+
+/// Número máximo de nodos que se dibujan; el resto de tripletas se omite.
+pub const MAX_NODES: usize = 200;
+/// Ancho del área en la que se colocan los nodos.
+const WIDTH: f64 = 800.0;
+/// Alto del área en la que se colocan los nodos.
+const HEIGHT: f64 = 500.0;
+/// Iteraciones del algoritmo de colocación por fuerzas.
+const ITERATIONS: usize = 150;
+/// Radio de los nodos que representan recursos.
+const NODE_RADIUS: f64 = 16.0;
+
+/// Estado de validación de un nodo según el último resultado de validación.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NodeStatus {
+    /// El nodo no aparece en el resultado de validación.
+    Unchecked,
+    /// Todas las asociaciones del nodo son válidas.
+    Valid,
+    /// Alguna asociación del nodo no es válida.
+    Invalid,
+}
+
+impl NodeStatus {
+    /// Clase CSS con la que se colorea el nodo.
+    pub fn class(&self) -> &'static str {
+        match self {
+            NodeStatus::Unchecked => "status-unchecked",
+            NodeStatus::Valid => "status-valid",
+            NodeStatus::Invalid => "status-invalid",
+        }
+    }
+}
+
+/// Nodo del grafo de los datos RDF.
+///
+/// # Campos
+/// * `term` - Término RDF del nodo
+/// * `label` - Texto con el que se muestra el nodo
+/// * `status` - Estado de validación del nodo
+/// * `x` - Coordenada horizontal calculada por la colocación
+/// * `y` - Coordenada vertical calculada por la colocación
+#[derive(Clone, Debug, PartialEq)]
+pub struct GraphNode {
+    pub term: Term,
+    pub label: String,
+    pub status: NodeStatus,
+    pub x: f64,
+    pub y: f64,
+}
+
+/// Arista del grafo de los datos RDF, etiquetada con el predicado de la tripleta.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GraphEdge {
+    pub source: usize,
+    pub target: usize,
+    pub label: String,
+}
+
+/// Grafo de los datos RDF preparado para dibujarse.
+///
+/// # Campos
+/// * `nodes` - Sujetos, objetos y literales (cada literal es una hoja propia)
+/// * `edges` - Una arista por tripleta
+/// * `truncated` - Indica si se han omitido tripletas por superar `MAX_NODES`
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RdfGraph {
+    pub nodes: Vec<GraphNode>,
+    pub edges: Vec<GraphEdge>,
+    pub truncated: bool,
+}
+
+impl RdfGraph {
+    /// Construye el grafo a partir de las tripletas de los datos y colorea los nodos con el resultado de validación.
+    ///
+    /// # Parámetros
+    /// * `triples` - Tripletas de los datos RDF.
+    /// * `entries` - Entradas del último resultado de validación (`ApiResult.shape_map`).
+    pub fn new(triples: &[Triple], entries: &[ShapeMapEntry]) -> Self {
+        let mut graph = RdfGraph::default();
+        for triple in triples {
+            let new_nodes = usize::from(graph.position(&triple.subject).is_none())
+                + usize::from(
+                    triple.object.is_literal() || graph.position(&triple.object).is_none(),
+                );
+            if graph.nodes.len() + new_nodes > MAX_NODES {
+                graph.truncated = true;
+                break;
+            }
+            let source = graph.add_node(&triple.subject, entries);
+            // Los literales no se comparten: cada uno es una hoja de su sujeto
+            let target = if triple.object.is_literal() {
+                graph.push_node(&triple.object, entries)
+            } else {
+                graph.add_node(&triple.object, entries)
+            };
+            graph.edges.push(GraphEdge {
+                source,
+                target,
+                label: local_label(&triple.predicate),
+            });
+        }
+        graph.layout();
+        graph
+    }
+
+    /// Índices de los nodos cuya etiqueta o IRI contiene el texto buscado, sin distinguir mayúsculas.
+    pub fn search(&self, text: &str) -> Vec<usize> {
+        let text = text.trim().to_lowercase();
+        if text.is_empty() {
+            return Vec::new();
+        }
+        self.nodes
+            .iter()
+            .enumerate()
+            .filter(|(_, node)| {
+                node.label.to_lowercase().contains(&text)
+                    || node.term.to_ntriples().to_lowercase().contains(&text)
+            })
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// Número de nodos con el estado indicado.
+    pub fn count(&self, status: NodeStatus) -> usize {
+        self.nodes
+            .iter()
+            .filter(|node| node.status == status)
+            .count()
+    }
+
+    /// Genera el grafo como documento SVG, resaltando los nodos indicados.
+    pub fn to_svg(&self, highlighted: &[usize]) -> String {
+        let mut svg = format!(
+            concat!(
+                r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}" "#,
+                r#"font-family="sans-serif" font-size="11">"#,
+                r#"<defs><marker id="graph-arrow" markerWidth="8" markerHeight="6" refX="8" refY="3" orient="auto">"#,
+                r##"<path d="M0,0 L8,3 L0,6 z" fill="#888"/></marker></defs>"##
+            ),
+            w = WIDTH,
+            h = HEIGHT
+        );
+
+        for edge in self.edges.iter() {
+            let (source, target) = (&self.nodes[edge.source], &self.nodes[edge.target]);
+            let (dx, dy) = (target.x - source.x, target.y - source.y);
+            let distance = (dx * dx + dy * dy).sqrt().max(1.0);
+            // La flecha termina en el borde del nodo destino
+            let end_x = target.x - dx / distance * NODE_RADIUS;
+            let end_y = target.y - dy / distance * NODE_RADIUS;
+            svg.push_str(&format!(
+                r##"<g class="graph-edge"><line x1="{x1:.1}" y1="{y1:.1}" x2="{x2:.1}" y2="{y2:.1}" stroke="#888" marker-end="url(#graph-arrow)"/><text x="{tx:.1}" y="{ty:.1}" fill="#666" text-anchor="middle">{label}</text></g>"##,
+                x1 = source.x,
+                y1 = source.y,
+                x2 = end_x,
+                y2 = end_y,
+                tx = (source.x + target.x) / 2.0,
+                ty = (source.y + target.y) / 2.0 - 3.0,
+                label = escape(&edge.label)
+            ));
+        }
+
+        for (index, node) in self.nodes.iter().enumerate() {
+            let class = format!(
+                "graph-node {}{}",
+                node.status.class(),
+                if highlighted.contains(&index) {
+                    " graph-node-match"
+                } else {
+                    ""
+                }
+            );
+            let shape = if node.term.is_literal() {
+                let width = node.label.chars().count() as f64 * 6.0 + 10.0;
+                format!(
+                    r#"<rect x="{x:.1}" y="{y:.1}" width="{w:.1}" height="18" rx="3"/>"#,
+                    x = node.x - width / 2.0,
+                    y = node.y - 9.0,
+                    w = width
+                )
+            } else {
+                format!(
+                    r#"<circle cx="{x:.1}" cy="{y:.1}" r="{r}"/>"#,
+                    x = node.x,
+                    y = node.y,
+                    r = NODE_RADIUS
+                )
+            };
+            svg.push_str(&format!(
+                r#"<g class="{class}" data-label="{label}"><title>{title}</title>{shape}<text x="{x:.1}" y="{y:.1}" text-anchor="middle">{label}</text></g>"#,
+                class = class,
+                label = escape(&node.label),
+                title = escape(&node.term.to_ntriples()),
+                shape = shape,
+                x = node.x,
+                y = if node.term.is_literal() {
+                    node.y + 4.0
+                } else {
+                    node.y + NODE_RADIUS + 12.0
+                }
+            ));
+        }
+
+        svg.push_str("</svg>");
+        svg
+    }
+
+    /// Dimensiones del área del grafo.
+    pub fn size() -> (f64, f64) {
+        (WIDTH, HEIGHT)
+    }
+
+    /// Posición del nodo con el término indicado.
+    fn position(&self, term: &Term) -> Option<usize> {
+        self.nodes.iter().position(|node| &node.term == term)
+    }
+
+    /// Añade el nodo si no existe y devuelve su posición.
+    fn add_node(&mut self, term: &Term, entries: &[ShapeMapEntry]) -> usize {
+        match self.position(term) {
+            Some(index) => index,
+            None => self.push_node(term, entries),
+        }
+    }
+
+    /// Añade un nodo nuevo y devuelve su posición.
+    fn push_node(&mut self, term: &Term, entries: &[ShapeMapEntry]) -> usize {
+        self.nodes.push(GraphNode {
+            term: term.clone(),
+            label: term_label(term),
+            status: node_status(term, entries),
+            x: 0.0,
+            y: 0.0,
+        });
+        self.nodes.len() - 1
+    }
+
+    /// Coloca los nodos con un algoritmo de fuerzas (Fruchterman-Reingold) partiendo de un círculo,
+    /// de modo que la colocación es siempre la misma para los mismos datos.
+    fn layout(&mut self) {
+        let count = self.nodes.len();
+        if count == 0 {
+            return;
+        }
+        let (center_x, center_y) = (WIDTH / 2.0, HEIGHT / 2.0);
+        for (index, node) in self.nodes.iter_mut().enumerate() {
+            let angle = index as f64 / count as f64 * std::f64::consts::TAU;
+            node.x = center_x + angle.cos() * WIDTH / 3.0;
+            node.y = center_y + angle.sin() * HEIGHT / 3.0;
+        }
+
+        let k = (WIDTH * HEIGHT / count as f64).sqrt() * 0.8;
+        let mut temperature = WIDTH / 10.0;
+        for _ in 0..ITERATIONS {
+            let mut displacement = vec![(0.0, 0.0); count];
+            for i in 0..count {
+                for j in (i + 1)..count {
+                    let (dx, dy) = (
+                        self.nodes[i].x - self.nodes[j].x,
+                        self.nodes[i].y - self.nodes[j].y,
+                    );
+                    let distance = (dx * dx + dy * dy).sqrt().max(0.01);
+                    let force = k * k / distance;
+                    displacement[i].0 += dx / distance * force;
+                    displacement[i].1 += dy / distance * force;
+                    displacement[j].0 -= dx / distance * force;
+                    displacement[j].1 -= dy / distance * force;
+                }
+            }
+            for edge in self.edges.iter() {
+                let (dx, dy) = (
+                    self.nodes[edge.source].x - self.nodes[edge.target].x,
+                    self.nodes[edge.source].y - self.nodes[edge.target].y,
+                );
+                let distance = (dx * dx + dy * dy).sqrt().max(0.01);
+                let force = distance * distance / k;
+                displacement[edge.source].0 -= dx / distance * force;
+                displacement[edge.source].1 -= dy / distance * force;
+                displacement[edge.target].0 += dx / distance * force;
+                displacement[edge.target].1 += dy / distance * force;
+            }
+            for (node, (dx, dy)) in self.nodes.iter_mut().zip(displacement) {
+                let length = (dx * dx + dy * dy).sqrt().max(0.01);
+                node.x = (node.x + dx / length * length.min(temperature))
+                    .clamp(NODE_RADIUS * 2.0, WIDTH - NODE_RADIUS * 2.0);
+                node.y = (node.y + dy / length * length.min(temperature))
+                    .clamp(NODE_RADIUS * 2.0, HEIGHT - NODE_RADIUS * 2.0);
+            }
+            temperature *= 0.97;
+        }
+    }
+}
+
+/// Estado de validación de un término según las entradas del resultado de validación.
+///
+/// Los nodos del resultado están abreviados como `:<último segmento>` (ver `api::format_shape_maps`),
+/// así que se comparan con el término abreviado del mismo modo.
+fn node_status(term: &Term, entries: &[ShapeMapEntry]) -> NodeStatus {
+    if term.is_literal() {
+        return NodeStatus::Unchecked;
+    }
+    let node = format!(":{}", api::extract_last_segment(&term.to_ntriples()));
+    let statuses: Vec<&String> = entries
+        .iter()
+        .filter(|entry| entry.node == node)
+        .map(|entry| &entry.status)
+        .collect();
+    if statuses.is_empty() {
+        NodeStatus::Unchecked
+    } else if statuses.iter().all(|status| *status == "Valid") {
+        NodeStatus::Valid
+    } else {
+        NodeStatus::Invalid
+    }
+}
+
+/// Texto con el que se muestra un término en el grafo.
+fn term_label(term: &Term) -> String {
+    match term {
+        Term::Iri(iri) => local_label(iri),
+        Term::BlankNode(_) => term.to_ntriples(),
+        Term::Literal { value, .. } => value.clone(),
+    }
+}
+
+/// Nombre local de una IRI (lo que sigue al último `#` o `/`).
+fn local_label(iri: &str) -> String {
+    let name = iri.rsplit(['#', '/']).next().unwrap_or(iri);
+    if name.is_empty() {
+        iri.to_string()
+    } else {
+        name.to_string()
+    }
+}
+
+/// Escapa los caracteres especiales del texto incluido en el SVG.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
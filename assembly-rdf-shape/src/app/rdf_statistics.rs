@@ -0,0 +1,211 @@
+use crate::app::api::Prefix;
+use crate::app::ntriples::{self, Term, Triple};
+use crate::app::shapemap;
+use serde::Serialize;
+use std::collections::HashSet;
+
+/// Tipo de dato de los literales sin tipo ni idioma (RDF 1.1).
+const XSD_STRING: &str = "http://www.w3.org/2001/XMLSchema#string";
+/// Tipo de dato de los literales con etiqueta de idioma (RDF 1.1).
+const RDF_LANG_STRING: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#langString";
+
+/// Estadísticas detalladas de los datos RDF para el modal de análisis.
+///
+/// # Campos
+/// * `triples` - Número de tripletas
+/// * `subjects` - Número de sujetos distintos
+/// * `predicates` - Número de predicados distintos
+/// * `objects` - Número de objetos distintos
+/// * `blank_nodes` - Número de nodos en blanco distintos
+/// * `classes` - Clases (`rdf:type`) con su número de instancias
+/// * `datatypes` - Tipos de dato de los literales con su número de apariciones
+/// * `languages` - Etiquetas de idioma con su número de apariciones
+/// * `declared_prefixes` - Prefijos declarados en el documento
+/// * `unused_prefixes` - Prefijos declarados que no usa ninguna IRI de los datos
+#[derive(Serialize, Clone, Debug, Default, PartialEq)]
+pub struct RdfStatistics {
+    pub triples: usize,
+    pub subjects: usize,
+    pub predicates: usize,
+    pub objects: usize,
+    pub blank_nodes: usize,
+    pub classes: Vec<(String, usize)>,
+    pub datatypes: Vec<(String, usize)>,
+    pub languages: Vec<(String, usize)>,
+    pub declared_prefixes: Vec<Prefix>,
+    pub unused_prefixes: Vec<String>,
+}
+
+impl RdfStatistics {
+    /// Calcula las estadísticas de los datos.
+    ///
+    /// # Parámetros
+    /// * `triples` - Tripletas de los datos (obtenidas al convertirlos a N-Triples).
+    /// * `source` - Documento original, del que se leen las declaraciones de prefijos.
+    pub fn new(triples: &[Triple], source: &str) -> Self {
+        let declared_prefixes = declared_prefixes(source);
+
+        let mut subjects: HashSet<&Term> = HashSet::new();
+        let mut predicates: HashSet<&String> = HashSet::new();
+        let mut objects: HashSet<&Term> = HashSet::new();
+        let mut blank_nodes: HashSet<&Term> = HashSet::new();
+        let mut instances: HashSet<(&Term, &Term)> = HashSet::new();
+        let mut datatypes: Vec<(String, usize)> = Vec::new();
+        let mut languages: Vec<(String, usize)> = Vec::new();
+        let mut iris: Vec<&str> = Vec::new();
+
+        for triple in triples {
+            subjects.insert(&triple.subject);
+            predicates.insert(&triple.predicate);
+            objects.insert(&triple.object);
+            iris.push(&triple.predicate);
+            for term in [&triple.subject, &triple.object] {
+                match term {
+                    Term::BlankNode(_) => {
+                        blank_nodes.insert(term);
+                    }
+                    Term::Iri(iri) => iris.push(iri),
+                    Term::Literal {
+                        datatype, language, ..
+                    } => {
+                        let datatype = match (datatype, language) {
+                            (_, Some(language)) => {
+                                increment(&mut languages, language.clone());
+                                RDF_LANG_STRING
+                            }
+                            (Some(datatype), None) => {
+                                iris.push(datatype);
+                                datatype
+                            }
+                            (None, None) => XSD_STRING,
+                        };
+                        increment(
+                            &mut datatypes,
+                            shapemap::compact_iri(datatype, &declared_prefixes),
+                        );
+                    }
+                }
+            }
+            if triple.predicate == ntriples::RDF_TYPE {
+                instances.insert((&triple.subject, &triple.object));
+            }
+        }
+
+        let mut classes: Vec<(String, usize)> = Vec::new();
+        for (_, class) in instances {
+            let class = match class {
+                Term::Iri(iri) => shapemap::compact_iri(iri, &declared_prefixes),
+                _ => class.to_ntriples(),
+            };
+            increment(&mut classes, class);
+        }
+
+        let unused_prefixes = declared_prefixes
+            .iter()
+            .filter(|prefix| !iris.iter().any(|iri| iri.starts_with(&prefix.prefix_iri)))
+            .map(|prefix| format!("{}:", prefix.prefix_name))
+            .collect();
+
+        RdfStatistics {
+            triples: triples.len(),
+            subjects: subjects.len(),
+            predicates: predicates.len(),
+            objects: objects.len(),
+            blank_nodes: blank_nodes.len(),
+            classes: sorted(classes),
+            datatypes: sorted(datatypes),
+            languages: sorted(languages),
+            declared_prefixes,
+            unused_prefixes,
+        }
+    }
+
+    /// Devuelve las estadísticas en formato CSV con las columnas `section,name,value`.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("section,name,value\n");
+        let mut row = |section: &str, name: &str, value: &str| {
+            csv.push_str(&format!(
+                "{},{},{}\n",
+                section,
+                csv_field(name),
+                csv_field(value)
+            ));
+        };
+        row("summary", "triples", &self.triples.to_string());
+        row("summary", "subjects", &self.subjects.to_string());
+        row("summary", "predicates", &self.predicates.to_string());
+        row("summary", "objects", &self.objects.to_string());
+        row("summary", "blank nodes", &self.blank_nodes.to_string());
+        for (class, count) in self.classes.iter() {
+            row("class", class, &count.to_string());
+        }
+        for (datatype, count) in self.datatypes.iter() {
+            row("datatype", datatype, &count.to_string());
+        }
+        for (language, count) in self.languages.iter() {
+            row("language", language, &count.to_string());
+        }
+        for prefix in self.declared_prefixes.iter() {
+            row("prefix", &prefix.prefix_name, &prefix.prefix_iri);
+        }
+        for prefix in self.unused_prefixes.iter() {
+            row("unused prefix", prefix, "");
+        }
+        csv
+    }
+}
+
+/// Lee las declaraciones `@prefix` y `PREFIX` de un documento Turtle, TriG, N3 o N-Quads.
+///
+/// En el resto de formatos (RDF/XML, JSON-LD...) no se obtiene ningún prefijo.
+pub fn declared_prefixes(source: &str) -> Vec<Prefix> {
+    let mut prefixes: Vec<Prefix> = Vec::new();
+    for line in source.lines() {
+        let line = line.trim();
+        let rest = match line.get(..7) {
+            Some(keyword) if keyword.eq_ignore_ascii_case("@prefix") => &line[7..],
+            _ => match line.get(..6) {
+                Some(keyword) if keyword.eq_ignore_ascii_case("prefix") => &line[6..],
+                _ => continue,
+            },
+        };
+        let (name, iri) = match rest.split_once(':') {
+            Some((name, iri)) => (name.trim(), iri.trim()),
+            None => continue,
+        };
+        let iri = match (iri.find('<'), iri.find('>')) {
+            (Some(start), Some(end)) if start < end => &iri[start + 1..end],
+            _ => continue,
+        };
+        if !prefixes.iter().any(|prefix| prefix.prefix_name == name) {
+            prefixes.push(Prefix {
+                prefix_name: name.to_string(),
+                prefix_iri: iri.to_string(),
+            });
+        }
+    }
+    prefixes
+}
+
+/// Incrementa el contador de la clave indicada.
+fn increment(counts: &mut Vec<(String, usize)>, key: String) {
+    match counts.iter_mut().find(|(existing, _)| *existing == key) {
+        Some((_, count)) => *count += 1,
+        None => counts.push((key, 1)),
+    }
+}
+
+/// Ordena los contadores de mayor a menor y, a igualdad, alfabéticamente.
+fn sorted(mut counts: Vec<(String, usize)>) -> Vec<(String, usize)> {
+    counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    counts
+}
+
+/// Escapa un campo CSV si contiene comas o comillas.
+fn csv_field(value: &str) -> String {
+    if value.contains(',') || value.contains('"') {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
@@ -0,0 +1,261 @@
+use crate::app::user_examples::{getStorageItem, setStorageItem};
+use gloo_timers::future::TimeoutFuture;
+use reqwasm::http::Request;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+use web_sys::{AbortController, AbortSignal};
+use yew::Callback;
+
+/// Clave del almacenamiento local del navegador en la que se guarda la configuración de las solicitudes.
+const STORAGE_KEY: &str = "rdf-validator-request-policy";
+
+/// Códigos de estado HTTP de errores transitorios del servidor, tras los que se repite la solicitud.
+const TRANSIENT_STATUSES: [u16; 3] = [502, 503, 504];
+
+/// Controlador que cancela la solicitud al pasar el tiempo máximo o al cancelarse la señal del usuario,
+/// y liberación de su temporizador y de su escucha sobre la señal del usuario cuando la respuesta ya se ha leído
+#[wasm_bindgen(inline_js = "
+    export function timeoutController(signal, ms) {
+        const controller = new AbortController();
+        controller.timer = setTimeout(() => controller.abort(), ms);
+        controller.signal.addEventListener('abort', () => clearTimeout(controller.timer), { once: true });
+        if (signal) {
+            if (signal.aborted) {
+                controller.abort();
+            } else {
+                controller.parent = signal;
+                controller.handler = () => controller.abort();
+                signal.addEventListener('abort', controller.handler, { once: true });
+            }
+        }
+        return controller;
+    }
+
+    export function clearTimeoutController(controller) {
+        clearTimeout(controller.timer);
+        if (controller.parent) {
+            controller.parent.removeEventListener('abort', controller.handler);
+        }
+    }
+    ")]
+extern "C" {
+    fn timeoutController(signal: Option<AbortSignal>, ms: u32) -> AbortController;
+    fn clearTimeoutController(controller: &AbortController);
+}
+
+/// Tiempo máximo, reintentos y caché de las solicitudes a la API de RDFShape.
+///
+/// # Campos
+/// * `timeout_secs` - Segundos que se espera la respuesta de cada intento
+/// * `max_attempts` - Número máximo de intentos, contando el primero
+/// * `initial_backoff_ms` - Espera antes del primer reintento; se duplica en cada uno de los siguientes
+/// * `use_cache` - Indica si se reutilizan las respuestas de análisis y validación de contenidos ya enviados
+/// * `persistent_cache` - Indica si las respuestas se guardan también en IndexedDB para las siguientes sesiones
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+#[serde(default)]
+pub struct RequestPolicy {
+    pub timeout_secs: u32,
+    pub max_attempts: u32,
+    pub initial_backoff_ms: u32,
+    pub use_cache: bool,
+    pub persistent_cache: bool,
+}
+
+impl Default for RequestPolicy {
+    fn default() -> Self {
+        RequestPolicy {
+            timeout_secs: 60,
+            max_attempts: 3,
+            initial_backoff_ms: 500,
+            use_cache: true,
+            persistent_cache: false,
+        }
+    }
+}
+
+impl RequestPolicy {
+    /// Lee la configuración guardada en el navegador o, si no hay ninguna, la configuración por defecto.
+    pub fn load() -> RequestPolicy {
+        getStorageItem(STORAGE_KEY)
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default()
+    }
+
+    /// Guarda la configuración en el navegador.
+    pub fn save(&self) -> Result<(), String> {
+        let json = serde_json::to_string(self).unwrap_or_default();
+        if setStorageItem(STORAGE_KEY, &json) {
+            Ok(())
+        } else {
+            Err("The request settings could not be saved in the browser storage.".to_string())
+        }
+    }
+
+    /// Espera antes del reintento indicado (1 para el primer reintento), en milisegundos.
+    pub fn backoff(&self, retry: u32) -> u32 {
+        self.initial_backoff_ms
+            .saturating_mul(2u32.saturating_pow(retry.saturating_sub(1)))
+    }
+}
+
+/// Reintento de una solicitud a la API.
+///
+/// # Campos
+/// * `attempt` - Intento que se va a realizar (2 para el primer reintento)
+/// * `max_attempts` - Número máximo de intentos
+/// * `reason` - Error por el que se repite la solicitud
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RetryStatus {
+    pub attempt: u32,
+    pub max_attempts: u32,
+    pub reason: String,
+}
+
+/// Opciones de una solicitud a la API.
+///
+/// # Campos
+/// * `policy` - Tiempo máximo, reintentos y caché que se aplican a la solicitud
+/// * `abort_signal` - Señal con la que el usuario cancela la solicitud
+/// * `on_retry` - `Callback` que se avisa antes de cada reintento
+/// * `bypass_cache` - Indica si la solicitud se envía al servidor aunque su respuesta esté en la caché
+/// * `retry_timeouts` - Indica si la solicitud se repite cuando se agota el tiempo de espera; las validaciones
+///   no lo hacen, porque repetir una validación que el servidor no termina a tiempo solo multiplica su carga
+#[derive(Clone)]
+pub struct RequestOptions {
+    pub policy: RequestPolicy,
+    pub abort_signal: Option<AbortSignal>,
+    pub on_retry: Option<Callback<RetryStatus>>,
+    pub bypass_cache: bool,
+    pub retry_timeouts: bool,
+}
+
+impl Default for RequestOptions {
+    fn default() -> Self {
+        RequestOptions {
+            policy: RequestPolicy::default(),
+            abort_signal: None,
+            on_retry: None,
+            bypass_cache: false,
+            retry_timeouts: true,
+        }
+    }
+}
+
+impl RequestOptions {
+    /// Opciones de una solicitud con la configuración indicada.
+    pub fn with_policy(policy: RequestPolicy) -> Self {
+        RequestOptions {
+            policy,
+            ..RequestOptions::default()
+        }
+    }
+}
+
+/// Respuesta de la API con su cuerpo ya leído.
+///
+/// # Campos
+/// * `status` - Código de estado HTTP
+/// * `body` - Cuerpo de la respuesta
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ApiResponse {
+    pub status: u16,
+    pub body: String,
+}
+
+impl ApiResponse {
+    /// Indica si el código de estado es de éxito (2xx).
+    pub fn ok(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    /// Interpreta el cuerpo de la respuesta como JSON.
+    pub fn json<T: DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        serde_json::from_str(&self.body)
+    }
+}
+
+/// Envía una solicitud POST a la API aplicando el tiempo máximo y los reintentos de `options.policy`.
+///
+/// Los endpoints de RDFShape solo analizan el contenido enviado y no modifican nada en el servidor, así que
+/// la solicitud se repite sin riesgo tras un error de red, un error transitorio (502, 503 o 504) o, si
+/// `options.retry_timeouts` lo permite, un tiempo de espera agotado, esperando cada vez el doble que la
+/// anterior. No se repite si el usuario la cancela. El tiempo máximo incluye la lectura del cuerpo.
+///
+/// # Parámetros
+/// * `endpoint` - URL del endpoint
+/// * `body` - Cuerpo JSON de la solicitud
+/// * `options` - Configuración, señal de cancelación y aviso de los reintentos
+///
+/// # Retorna
+/// Devuelve la respuesta del servidor, aunque sea un error transitorio tras agotar los intentos, o el mensaje
+/// del error que ha impedido recibirla.
+pub async fn post(
+    endpoint: &str,
+    body: String,
+    options: &RequestOptions,
+) -> Result<ApiResponse, String> {
+    let policy = options.policy;
+    let mut attempt = 1;
+    loop {
+        let controller = timeoutController(
+            options.abort_signal.clone(),
+            policy.timeout_secs.saturating_mul(1000),
+        );
+        let signal = controller.signal();
+        let result = send(endpoint, &body, &signal).await;
+        clearTimeoutController(&controller);
+        if is_cancelled(options) {
+            return Err("The request was cancelled.".to_string());
+        }
+        let reason = match &result {
+            Ok(response) if TRANSIENT_STATUSES.contains(&response.status) => {
+                format!("HTTP {}", response.status)
+            }
+            Ok(_) => return result,
+            Err(_) if signal.aborted() => {
+                let reason = format!("The request timed out after {} s.", policy.timeout_secs);
+                if !options.retry_timeouts {
+                    return Err(reason);
+                }
+                reason
+            }
+            Err(error) => error.clone(),
+        };
+        if attempt >= policy.max_attempts {
+            return result.map_err(|_| reason);
+        }
+        attempt += 1;
+        if let Some(on_retry) = &options.on_retry {
+            on_retry.emit(RetryStatus {
+                attempt,
+                max_attempts: policy.max_attempts,
+                reason,
+            });
+        }
+        TimeoutFuture::new(policy.backoff(attempt - 1)).await;
+        if is_cancelled(options) {
+            return Err("The request was cancelled.".to_string());
+        }
+    }
+}
+
+/// Envía la solicitud y lee el cuerpo de la respuesta con la señal indicada.
+async fn send(endpoint: &str, body: &str, signal: &AbortSignal) -> Result<ApiResponse, String> {
+    let response = Request::post(endpoint)
+        .body(body)
+        .abort_signal(Some(signal))
+        .send()
+        .await
+        .map_err(|error| error.to_string())?;
+    let body = response.text().await.map_err(|error| error.to_string())?;
+    Ok(ApiResponse {
+        status: response.status(),
+        body,
+    })
+}
+
+/// Indica si el usuario ha cancelado la solicitud.
+fn is_cancelled(options: &RequestOptions) -> bool {
+    matches!(&options.abort_signal, Some(signal) if signal.aborted())
+}
@@ -0,0 +1,145 @@
+use crate::app::request_policy::RequestPolicy;
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+use wasm_bindgen::prelude::*;
+
+/// Número máximo de respuestas que se guardan en memoria; al superarlo se descarta la más antigua.
+const MAX_MEMORY_ENTRIES: usize = 50;
+
+/// Almacén de respuestas en IndexedDB, que se conserva entre sesiones
+#[wasm_bindgen(inline_js = "
+    function openCache() {
+        return new Promise((resolve, reject) => {
+            const request = window.indexedDB.open('rdf-validator-cache', 1);
+            request.onupgradeneeded = () => request.result.createObjectStore('responses');
+            request.onsuccess = () => resolve(request.result);
+            request.onerror = () => reject(request.error);
+        });
+    }
+
+    function runRequest(mode, action) {
+        return openCache()
+            .then((db) => new Promise((resolve) => {
+                const request = action(db.transaction('responses', mode).objectStore('responses'));
+                request.onsuccess = () => resolve(request.result === undefined ? null : request.result);
+                request.onerror = () => resolve(null);
+            }))
+            .catch(() => null);
+    }
+
+    export function readStoredResponse(key) {
+        return runRequest('readonly', (store) => store.get(key));
+    }
+
+    export function writeStoredResponse(key, value) {
+        return runRequest('readwrite', (store) => store.put(value, key));
+    }
+
+    export function clearStoredResponses() {
+        return runRequest('readwrite', (store) => store.clear());
+    }
+    ")]
+extern "C" {
+    async fn readStoredResponse(key: &str) -> JsValue;
+    async fn writeStoredResponse(key: &str, value: &str) -> JsValue;
+    async fn clearStoredResponses() -> JsValue;
+}
+
+/// Respuesta guardada junto con el cuerpo de la solicitud que la ha producido.
+///
+/// La clave es solo un hash del cuerpo, así que al buscar una respuesta se comprueba que el cuerpo guardado
+/// coincide con el de la solicitud para no devolver la de otro contenido con el mismo hash.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+struct CachedResponse {
+    request: String,
+    response: String,
+}
+
+/// Respuestas guardadas en memoria y orden en el que se han guardado.
+#[derive(Default)]
+struct MemoryCache {
+    responses: HashMap<String, CachedResponse>,
+    order: VecDeque<String>,
+}
+
+thread_local! {
+    static MEMORY_CACHE: RefCell<MemoryCache> = RefCell::new(MemoryCache::default());
+}
+
+/// Clave de una solicitud: el endpoint y el hash FNV-1a del cuerpo enviado, que incluye el contenido y sus formatos.
+pub fn cache_key(endpoint: &str, body: &str) -> String {
+    let hash = body.bytes().fold(0xcbf2_9ce4_8422_2325u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    });
+    format!("{}#{:016x}-{}", endpoint, hash, body.len())
+}
+
+/// Busca la respuesta guardada de una solicitud, primero en memoria y después en IndexedDB si está activado.
+///
+/// # Parámetros
+/// * `key` - Clave de la solicitud (ver `cache_key`)
+/// * `request` - Cuerpo de la solicitud, que debe coincidir con el de la respuesta guardada
+/// * `policy` - Configuración de la caché
+///
+/// # Retorna
+/// Devuelve el cuerpo de la respuesta, o `None` si no está guardada o la caché está desactivada.
+pub async fn lookup(key: &str, request: &str, policy: &RequestPolicy) -> Option<String> {
+    if !policy.use_cache {
+        return None;
+    }
+    let cached = MEMORY_CACHE.with(|cache| cache.borrow().responses.get(key).cloned());
+    let cached = match cached {
+        Some(cached) => Some(cached),
+        None if policy.persistent_cache => {
+            let stored = readStoredResponse(key).await.as_string()?;
+            let stored: CachedResponse = serde_json::from_str(&stored).ok()?;
+            remember(key, stored.clone());
+            Some(stored)
+        }
+        None => None,
+    }?;
+    if cached.request == request {
+        Some(cached.response)
+    } else {
+        None
+    }
+}
+
+/// Guarda la respuesta de una solicitud, junto con su cuerpo, en memoria y, si está activado, en IndexedDB.
+pub async fn store(key: &str, request: &str, response: &str, policy: &RequestPolicy) {
+    if !policy.use_cache {
+        return;
+    }
+    let cached = CachedResponse {
+        request: request.to_string(),
+        response: response.to_string(),
+    };
+    if policy.persistent_cache {
+        if let Ok(json) = serde_json::to_string(&cached) {
+            writeStoredResponse(key, &json).await;
+        }
+    }
+    remember(key, cached);
+}
+
+/// Elimina todas las respuestas guardadas, en memoria y en IndexedDB.
+pub async fn clear() {
+    MEMORY_CACHE.with(|cache| *cache.borrow_mut() = MemoryCache::default());
+    clearStoredResponses().await;
+}
+
+/// Guarda una respuesta en memoria descartando la más antigua si se supera `MAX_MEMORY_ENTRIES`.
+fn remember(key: &str, response: CachedResponse) {
+    MEMORY_CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        if cache.responses.insert(key.to_string(), response).is_none() {
+            cache.order.push_back(key.to_string());
+        }
+        while cache.order.len() > MAX_MEMORY_ENTRIES {
+            if let Some(oldest) = cache.order.pop_front() {
+                cache.responses.remove(&oldest);
+            }
+        }
+    });
+}
@@ -0,0 +1,208 @@
+use crate::app::api::{ShapeMapEntry, ValidationParameters};
+use crate::app::batch_validation::MAX_CONCURRENT_VALIDATIONS;
+use crate::app::document_tabs::DocumentTab;
+use crate::app::format_detection;
+use std::collections::VecDeque;
+
+/// Fila de la matriz de comparación: un nodo y una forma con su estado en cada versión del esquema.
+///
+/// # Campos
+/// * `node` - Nodo validado
+/// * `shape` - Forma con la que se ha validado
+/// * `node_iri`, `shape_iri` - Nodo y forma completos, que identifican la fila
+/// * `statuses` - Estado (`Valid`, `Invalid`) en cada versión; vacío si la versión no tiene resultado para el nodo
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ComparisonRow {
+    pub node: String,
+    pub shape: String,
+    pub node_iri: String,
+    pub shape_iri: String,
+    pub statuses: Vec<String>,
+}
+
+impl ComparisonRow {
+    /// Indica si el estado del nodo cambia entre versiones del esquema.
+    pub fn differs(&self) -> bool {
+        self.statuses
+            .iter()
+            .any(|status| *status != self.statuses[0])
+    }
+}
+
+/// Validación de los mismos datos con varias versiones del esquema.
+///
+/// # Campos
+/// * `versions` - Nombres de las versiones del esquema (las pestañas del panel ShEx)
+/// * `results` - Entradas del resultado o error de cada versión; `None` mientras no llega la respuesta
+/// * `rdf`, `parameters` - Datos y parámetros comunes a todas las versiones; el formato de cada versión se
+///   detecta a partir de su contenido y solo se toma de `parameters` si no se puede detectar
+#[derive(Clone, Default, PartialEq)]
+pub struct SchemaComparison {
+    pub versions: Vec<String>,
+    pub results: Vec<Option<Result<Vec<ShapeMapEntry>, String>>>,
+    pub rdf: String,
+    pub parameters: ValidationParameters,
+    queue: VecDeque<(usize, String, String)>,
+    in_flight: usize,
+}
+
+impl SchemaComparison {
+    /// Crea una comparación sin resultados para las versiones del esquema indicadas.
+    pub fn new(versions: Vec<DocumentTab>, rdf: String, parameters: ValidationParameters) -> Self {
+        SchemaComparison {
+            results: vec![None; versions.len()],
+            versions: versions
+                .iter()
+                .map(|version| version.name.clone())
+                .collect(),
+            queue: versions
+                .into_iter()
+                .enumerate()
+                .map(|(index, version)| {
+                    let format = version_format(&version.content, &parameters);
+                    (index, version.content, format)
+                })
+                .collect(),
+            rdf,
+            parameters,
+            in_flight: 0,
+        }
+    }
+
+    /// Saca de la cola las versiones que se pueden validar sin superar `MAX_CONCURRENT_VALIDATIONS`.
+    ///
+    /// # Retorna
+    /// Devuelve la posición de cada versión y los parámetros con los que se valida.
+    pub fn next_versions(&mut self) -> Vec<(usize, ValidationParameters)> {
+        let mut versions = Vec::new();
+        while self.in_flight < MAX_CONCURRENT_VALIDATIONS {
+            match self.queue.pop_front() {
+                Some((index, shex, shex_format)) => {
+                    self.in_flight += 1;
+                    versions.push((
+                        index,
+                        ValidationParameters {
+                            shex,
+                            shex_format,
+                            ..self.parameters.clone()
+                        },
+                    ));
+                }
+                None => break,
+            }
+        }
+        versions
+    }
+
+    /// Registra el resultado de la validación con la versión en la posición indicada.
+    pub fn record(&mut self, index: usize, entries: Vec<ShapeMapEntry>, error: String) {
+        self.in_flight = self.in_flight.saturating_sub(1);
+        if let Some(result) = self.results.get_mut(index) {
+            *result = Some(if error.is_empty() {
+                Ok(entries)
+            } else {
+                Err(error)
+            });
+        }
+    }
+
+    /// Número de versiones cuya validación ha terminado.
+    pub fn completed(&self) -> usize {
+        self.results
+            .iter()
+            .filter(|result| result.is_some())
+            .count()
+    }
+
+    /// Indica si ya se han recibido los resultados de todas las versiones.
+    pub fn is_finished(&self) -> bool {
+        self.completed() == self.versions.len()
+    }
+
+    /// Errores de las versiones que no se han podido validar, con el nombre de la versión.
+    pub fn errors(&self) -> Vec<String> {
+        self.versions
+            .iter()
+            .zip(self.results.iter())
+            .filter_map(|(version, result)| match result {
+                Some(Err(error)) => Some(format!("{}: {}", version, error)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Construye la matriz nodo × versión en el orden en el que aparecen los nodos en los resultados.
+    pub fn rows(&self) -> Vec<ComparisonRow> {
+        let mut rows: Vec<ComparisonRow> = Vec::new();
+        for (index, result) in self.results.iter().enumerate() {
+            let entries = match result {
+                Some(Ok(entries)) => entries,
+                _ => continue,
+            };
+            for entry in entries {
+                let (node_iri, shape_iri) = entry.key();
+                let position = match rows
+                    .iter()
+                    .position(|row| row.node_iri == node_iri && row.shape_iri == shape_iri)
+                {
+                    Some(position) => position,
+                    None => {
+                        rows.push(ComparisonRow {
+                            node: entry.node.clone(),
+                            shape: entry.shape.clone(),
+                            node_iri: node_iri.to_string(),
+                            shape_iri: shape_iri.to_string(),
+                            statuses: vec![String::new(); self.versions.len()],
+                        });
+                        rows.len() - 1
+                    }
+                };
+                rows[position].statuses[index] = entry.status.clone();
+            }
+        }
+        rows
+    }
+
+    /// Exporta la matriz como CSV separado por `;`, con el nodo y la forma completos.
+    pub fn to_csv(&self) -> String {
+        let header = csv_line(
+            ["Node", "Shape"]
+                .iter()
+                .map(|field| field.to_string())
+                .chain(self.versions.iter().cloned()),
+        );
+        self.rows().iter().fold(header, |acc, row| {
+            acc + &csv_line(
+                vec![row.node_iri.clone(), row.shape_iri.clone()]
+                    .into_iter()
+                    .chain(row.statuses.iter().cloned()),
+            )
+        })
+    }
+}
+
+/// Une los campos indicados en una línea CSV separada por `;`.
+fn csv_line(fields: impl Iterator<Item = String>) -> String {
+    let fields: Vec<String> = fields.map(|field| csv_field(&field)).collect();
+    format!("{}\n", fields.join(";"))
+}
+
+/// Entrecomilla un campo CSV si contiene el separador, comillas o saltos de línea, duplicando sus comillas.
+fn csv_field(field: &str) -> String {
+    if field.contains([';', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Detecta el formato de una versión del esquema: un formato ShEx con el motor ShEx o el formato RDF del
+/// grafo de formas con SHACL. Si no se puede detectar, se usa el formato de los parámetros de la comparación.
+fn version_format(content: &str, parameters: &ValidationParameters) -> String {
+    let detected = if parameters.engine == "ShEx" {
+        format_detection::detect_shex_format(content)
+    } else {
+        format_detection::detect_rdf_format(content)
+    };
+    detected.unwrap_or_else(|| parameters.shex_format.clone())
+}
@@ -0,0 +1,458 @@
+use crate::app::api::Prefix;
+use crate::app::shapemap;
+use crate::app::shexj;
+use serde_json::Value;
+
+/// Ancho aproximado en píxeles de cada carácter del texto del diagrama.
+const CHAR_WIDTH: usize = 7;
+/// Alto de la cabecera de cada forma.
+const HEADER_HEIGHT: usize = 26;
+/// Alto de cada fila de restricciones.
+const ROW_HEIGHT: usize = 18;
+/// Separación entre formas y márgenes del diagrama.
+const GAP: usize = 80;
+/// Número máximo de formas por fila.
+const COLUMNS: usize = 3;
+
+/// Forma del esquema tal y como se dibuja en el diagrama.
+///
+/// # Campos
+/// * `label` - Etiqueta de la forma, acortada con los prefijos del esquema
+/// * `constraints` - Restricciones de tripleta de la forma
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DiagramShape {
+    pub label: String,
+    pub constraints: Vec<DiagramConstraint>,
+}
+
+/// Restricción de tripleta de una forma del diagrama.
+///
+/// # Campos
+/// * `predicate` - Predicado (precedido de `^` si es inverso)
+/// * `value` - Tipo de valor (tipo de dato, tipo de nodo, conjunto de valores o `@forma`)
+/// * `cardinality` - Cardinalidad (`?`, `*`, `+`, `{m,n}` o vacía si es exactamente uno)
+/// * `reference` - Forma referenciada, si el valor es una referencia `@forma`
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DiagramConstraint {
+    pub predicate: String,
+    pub value: String,
+    pub cardinality: String,
+    pub reference: Option<String>,
+}
+
+impl DiagramConstraint {
+    /// Devuelve el texto de la restricción dentro de la caja de la forma.
+    pub fn describe(&self) -> String {
+        format!("{} : {} {}", self.predicate, self.value, self.cardinality)
+            .trim_end()
+            .to_string()
+    }
+}
+
+/// Obtiene las formas del diagrama a partir de un esquema ShExJ.
+///
+/// # Parámetros
+/// * `shexj` - Esquema en formato ShExJ.
+/// * `prefixes` - Prefijos con los que se acortan las IRIs del esquema.
+///
+/// # Retorna
+/// Devuelve las formas en orden de declaración, vacío si el esquema no es un JSON válido.
+pub fn diagram_shapes(shexj: &str, prefixes: &[Prefix]) -> Vec<DiagramShape> {
+    let schema: Value = match serde_json::from_str(shexj) {
+        Ok(schema) => schema,
+        Err(_) => return Vec::new(),
+    };
+    shexj::shape_declarations(&schema)
+        .into_iter()
+        .map(|(id, expression)| {
+            let mut constraints = Vec::new();
+            collect_constraints(expression, prefixes, &mut constraints);
+            DiagramShape {
+                label: shapemap::compact_iri(&id, prefixes),
+                constraints,
+            }
+        })
+        .collect()
+}
+
+/// Genera el diagrama de las formas como documento SVG.
+///
+/// Cada forma se dibuja como una caja con su etiqueta y sus restricciones, y cada referencia
+/// entre formas como una flecha etiquetada con el predicado y la cardinalidad.
+pub fn to_svg(shapes: &[DiagramShape]) -> String {
+    let boxes = layout(shapes);
+    let width = boxes.iter().map(|b| b.x + b.width).max().unwrap_or(0) + GAP;
+    let height = boxes.iter().map(|b| b.y + b.height).max().unwrap_or(0) + GAP;
+
+    let mut svg = format!(
+        concat!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}" "#,
+            r#"font-family="monospace" font-size="12">"#,
+            r#"<defs><marker id="diagram-arrow" markerWidth="10" markerHeight="8" refX="10" refY="4" orient="auto">"#,
+            r##"<path d="M0,0 L10,4 L0,8 z" fill="#555"/></marker></defs>"##,
+            r##"<rect width="100%" height="100%" fill="#fff"/>"##
+        ),
+        w = width,
+        h = height
+    );
+
+    for (shape, source) in shapes.iter().zip(boxes.iter()) {
+        for (row, constraint) in shape.constraints.iter().enumerate() {
+            let target = match constraint.reference.as_ref().and_then(|reference| {
+                shapes
+                    .iter()
+                    .position(|candidate| &candidate.label == reference)
+            }) {
+                Some(index) => &boxes[index],
+                None => continue,
+            };
+            let start_y = source.y + HEADER_HEIGHT + row * ROW_HEIGHT + ROW_HEIGHT / 2 + 4;
+            let label =
+                escape(format!("{} {}", constraint.predicate, constraint.cardinality).trim_end());
+            if std::ptr::eq(source, target) {
+                // Referencia a sí misma: bucle por la derecha de la caja
+                let x = source.x + source.width;
+                svg.push_str(&format!(
+                    r##"<path class="diagram-reference" d="M{x},{y} C{c},{y} {c},{t} {x},{t}" fill="none" stroke="#555" marker-end="url(#diagram-arrow)"/><text x="{tx}" y="{ty}" fill="#555">{label}</text>"##,
+                    x = x,
+                    y = start_y,
+                    c = x + 50,
+                    t = source.y + HEADER_HEIGHT / 2,
+                    tx = x + 54,
+                    ty = (start_y + source.y) / 2 + 8,
+                    label = label
+                ));
+                continue;
+            }
+            let start_x = if target.center_x() >= source.center_x() {
+                source.x + source.width
+            } else {
+                source.x
+            };
+            let (end_x, end_y) = target.border_point(start_x as f64, start_y as f64);
+            svg.push_str(&format!(
+                r##"<line class="diagram-reference" x1="{x1}" y1="{y1}" x2="{x2:.0}" y2="{y2:.0}" stroke="#555" marker-end="url(#diagram-arrow)"/><text x="{tx:.0}" y="{ty:.0}" fill="#555" text-anchor="middle">{label}</text>"##,
+                x1 = start_x,
+                y1 = start_y,
+                x2 = end_x,
+                y2 = end_y,
+                tx = (start_x as f64 + end_x) / 2.0,
+                ty = (start_y as f64 + end_y) / 2.0 - 4.0,
+                label = label
+            ));
+        }
+    }
+
+    for (shape, b) in shapes.iter().zip(boxes.iter()) {
+        svg.push_str(&format!(
+            r##"<g class="diagram-shape"><rect x="{x}" y="{y}" width="{w}" height="{h}" fill="#f7fbff" stroke="#1f5f99" rx="4"/><rect x="{x}" y="{y}" width="{w}" height="{hh}" fill="#1f5f99" rx="4"/><text x="{tx}" y="{ty}" fill="#fff" font-weight="bold" text-anchor="middle">{label}</text>"##,
+            x = b.x,
+            y = b.y,
+            w = b.width,
+            h = b.height,
+            hh = HEADER_HEIGHT,
+            tx = b.center_x(),
+            ty = b.y + HEADER_HEIGHT / 2 + 4,
+            label = escape(&shape.label)
+        ));
+        for (row, constraint) in shape.constraints.iter().enumerate() {
+            svg.push_str(&format!(
+                r##"<text x="{x}" y="{y}" fill="#222">{text}</text>"##,
+                x = b.x + 8,
+                y = b.y + HEADER_HEIGHT + row * ROW_HEIGHT + ROW_HEIGHT / 2 + 8,
+                text = escape(&constraint.describe())
+            ));
+        }
+        svg.push_str("</g>");
+    }
+
+    svg.push_str("</svg>");
+    svg
+}
+
+/// Genera el diagrama de las formas como texto PlantUML (diagrama de clases).
+pub fn to_plantuml(shapes: &[DiagramShape]) -> String {
+    let alias = |label: &str| {
+        shapes
+            .iter()
+            .position(|shape| shape.label == label)
+            .map(|index| format!("S{}", index))
+    };
+
+    let mut uml = String::from("@startuml\nhide empty methods\n");
+    for (index, shape) in shapes.iter().enumerate() {
+        uml.push_str(&format!("class \"{}\" as S{} {{\n", shape.label, index));
+        for constraint in shape.constraints.iter() {
+            uml.push_str(&format!("  {}\n", constraint.describe()));
+        }
+        uml.push_str("}\n");
+    }
+    for (index, shape) in shapes.iter().enumerate() {
+        for constraint in shape.constraints.iter() {
+            if let Some(target) = constraint.reference.as_deref().and_then(alias) {
+                let cardinality = if constraint.cardinality.is_empty() {
+                    "1".to_string()
+                } else {
+                    constraint.cardinality.clone()
+                };
+                uml.push_str(&format!(
+                    "S{} --> \"{}\" {} : {}\n",
+                    index, cardinality, target, constraint.predicate
+                ));
+            }
+        }
+    }
+    uml.push_str("@enduml\n");
+    uml
+}
+
+/// Posición y tamaño de la caja de una forma en el diagrama.
+struct ShapeBox {
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+}
+
+impl ShapeBox {
+    fn center_x(&self) -> usize {
+        self.x + self.width / 2
+    }
+
+    /// Punto del borde de la caja en la dirección que une el punto indicado con su centro.
+    fn border_point(&self, from_x: f64, from_y: f64) -> (f64, f64) {
+        let cx = self.x as f64 + self.width as f64 / 2.0;
+        let cy = self.y as f64 + self.height as f64 / 2.0;
+        let (dx, dy) = (from_x - cx, from_y - cy);
+        if dx == 0.0 && dy == 0.0 {
+            return (cx, cy);
+        }
+        let scale_x = if dx != 0.0 {
+            (self.width as f64 / 2.0) / dx.abs()
+        } else {
+            f64::INFINITY
+        };
+        let scale_y = if dy != 0.0 {
+            (self.height as f64 / 2.0) / dy.abs()
+        } else {
+            f64::INFINITY
+        };
+        let scale = scale_x.min(scale_y);
+        (cx + dx * scale, cy + dy * scale)
+    }
+}
+
+/// Coloca las formas en una rejilla de `COLUMNS` columnas.
+fn layout(shapes: &[DiagramShape]) -> Vec<ShapeBox> {
+    let sizes: Vec<(usize, usize)> = shapes
+        .iter()
+        .map(|shape| {
+            let longest = shape
+                .constraints
+                .iter()
+                .map(|constraint| constraint.describe().chars().count())
+                .chain(std::iter::once(shape.label.chars().count()))
+                .max()
+                .unwrap_or(0);
+            (
+                longest * CHAR_WIDTH + 16,
+                HEADER_HEIGHT + shape.constraints.len().max(1) * ROW_HEIGHT + 6,
+            )
+        })
+        .collect();
+
+    let column_widths: Vec<usize> = (0..COLUMNS)
+        .map(|column| {
+            sizes
+                .iter()
+                .skip(column)
+                .step_by(COLUMNS)
+                .map(|(width, _)| *width)
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    let mut boxes = Vec::new();
+    let mut y = GAP / 2;
+    for row in sizes.chunks(COLUMNS) {
+        let mut x = GAP / 2;
+        for (column, (width, height)) in row.iter().enumerate() {
+            boxes.push(ShapeBox {
+                x,
+                y,
+                width: *width,
+                height: *height,
+            });
+            x += column_widths[column] + GAP * 2;
+        }
+        y += row.iter().map(|(_, height)| *height).max().unwrap_or(0) + GAP;
+    }
+    boxes
+}
+
+/// Recorre una expresión de forma ShExJ acumulando las restricciones de tripleta.
+fn collect_constraints(
+    expression: &Value,
+    prefixes: &[Prefix],
+    constraints: &mut Vec<DiagramConstraint>,
+) {
+    match expression.get("type").and_then(Value::as_str) {
+        Some("TripleConstraint") => {
+            let predicate = expression
+                .get("predicate")
+                .and_then(Value::as_str)
+                .map(|predicate| shapemap::compact_iri(predicate, prefixes))
+                .unwrap_or_default();
+            let inverse = expression
+                .get("inverse")
+                .and_then(Value::as_bool)
+                .unwrap_or(false);
+            let value_expr = expression.get("valueExpr");
+            constraints.push(DiagramConstraint {
+                predicate: if inverse {
+                    format!("^{}", predicate)
+                } else {
+                    predicate
+                },
+                value: value_expr
+                    .map(|value| describe_value(value, prefixes))
+                    .unwrap_or_else(|| ".".to_string()),
+                cardinality: cardinality(expression),
+                reference: value_expr
+                    .and_then(Value::as_str)
+                    .map(|label| shapemap::compact_iri(label, prefixes)),
+            });
+        }
+        Some("Shape") => {
+            if let Some(triple_expression) = expression.get("expression") {
+                collect_constraints(triple_expression, prefixes, constraints);
+            }
+        }
+        Some("EachOf") | Some("OneOf") => {
+            for item in expression
+                .get("expressions")
+                .and_then(Value::as_array)
+                .into_iter()
+                .flatten()
+            {
+                collect_constraints(item, prefixes, constraints);
+            }
+        }
+        Some("ShapeAnd") | Some("ShapeOr") => {
+            for item in expression
+                .get("shapeExprs")
+                .and_then(Value::as_array)
+                .into_iter()
+                .flatten()
+            {
+                match item.as_str() {
+                    // Las formas combinadas con AND/OR se muestran como una referencia sin predicado
+                    Some(label) => constraints.push(DiagramConstraint {
+                        predicate: if expression["type"] == "ShapeAnd" {
+                            "AND".to_string()
+                        } else {
+                            "OR".to_string()
+                        },
+                        value: format!("@{}", shapemap::compact_iri(label, prefixes)),
+                        cardinality: String::new(),
+                        reference: Some(shapemap::compact_iri(label, prefixes)),
+                    }),
+                    None => collect_constraints(item, prefixes, constraints),
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Describe la expresión de valor de una restricción de tripleta.
+fn describe_value(value: &Value, prefixes: &[Prefix]) -> String {
+    if let Some(label) = value.as_str() {
+        return format!("@{}", shapemap::compact_iri(label, prefixes));
+    }
+    match value.get("type").and_then(Value::as_str) {
+        Some("NodeConstraint") => {
+            let mut parts: Vec<String> = Vec::new();
+            if let Some(kind) = value.get("nodeKind").and_then(Value::as_str) {
+                parts.push(
+                    match kind {
+                        "iri" => "IRI",
+                        "bnode" => "BNode",
+                        "nonliteral" => "NonLiteral",
+                        _ => "Literal",
+                    }
+                    .to_string(),
+                );
+            }
+            if let Some(datatype) = value.get("datatype").and_then(Value::as_str) {
+                parts.push(shapemap::compact_iri(datatype, prefixes));
+            }
+            if let Some(values) = value.get("values").and_then(Value::as_array) {
+                let values: Vec<String> = values
+                    .iter()
+                    .map(|value| match value {
+                        Value::String(iri) => shapemap::compact_iri(iri, prefixes),
+                        _ => match value.get("value").and_then(Value::as_str) {
+                            Some(literal) => format!("\"{}\"", literal),
+                            None => "~".to_string(),
+                        },
+                    })
+                    .collect();
+                parts.push(format!("[{}]", values.join(" ")));
+            }
+            if parts.is_empty() {
+                ".".to_string()
+            } else {
+                parts.join(" ")
+            }
+        }
+        Some("ShapeAnd") | Some("ShapeOr") => {
+            let separator = if value["type"] == "ShapeAnd" {
+                " AND "
+            } else {
+                " OR "
+            };
+            value
+                .get("shapeExprs")
+                .and_then(Value::as_array)
+                .map(|items| {
+                    items
+                        .iter()
+                        .map(|item| describe_value(item, prefixes))
+                        .collect::<Vec<_>>()
+                        .join(separator)
+                })
+                .unwrap_or_default()
+        }
+        Some("ShapeNot") => value
+            .get("shapeExpr")
+            .map(|item| format!("NOT {}", describe_value(item, prefixes)))
+            .unwrap_or_default(),
+        Some("Shape") => "{ … }".to_string(),
+        _ => ".".to_string(),
+    }
+}
+
+/// Devuelve la cardinalidad de una restricción de tripleta en sintaxis ShExC.
+fn cardinality(expression: &Value) -> String {
+    let min = expression.get("min").and_then(Value::as_i64).unwrap_or(1);
+    let max = expression.get("max").and_then(Value::as_i64).unwrap_or(1);
+    match (min, max) {
+        (1, 1) => String::new(),
+        (0, 1) => "?".to_string(),
+        (0, -1) => "*".to_string(),
+        (1, -1) => "+".to_string(),
+        (min, -1) => format!("{{{},}}", min),
+        (min, max) if min == max => format!("{{{}}}", min),
+        (min, max) => format!("{{{},{}}}", min, max),
+    }
+}
+
+/// Escapa los caracteres especiales del texto incluido en el SVG.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
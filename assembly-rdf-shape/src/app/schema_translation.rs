@@ -0,0 +1,217 @@
+use crate::app::api;
+use crate::app::ntriples::{self, Term, Triple};
+use crate::app::request_policy::RequestOptions;
+use crate::app::shacl::SH;
+use crate::app::shexj;
+use serde_json::Value;
+
+/// Resultado de traducir un esquema entre ShEx y SHACL.
+///
+/// # Campos
+/// * `content` - Esquema traducido
+/// * `format` - Formato del esquema traducido
+/// * `engine` - Motor del esquema traducido
+/// * `warnings` - Construcciones del esquema original que no se han podido expresar en el destino
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SchemaTranslation {
+    pub content: String,
+    pub format: String,
+    pub engine: String,
+    pub warnings: Vec<String>,
+}
+
+/// Traduce un esquema ShEx a un grafo de formas SHACL o un grafo de formas SHACL a ShEx.
+///
+/// Las advertencias se calculan sobre el esquema original (en ShExJ o en N-Triples) antes de traducirlo.
+///
+/// # Parámetros
+/// * `schema` - Contenido del esquema.
+/// * `format` - Formato del esquema.
+/// * `engine` - Motor del esquema (`ShEx` o un motor SHACL).
+/// * `target_engine` - Motor de destino.
+/// * `options` - Configuración y señal de cancelación de las solicitudes a la API.
+///
+/// # Retorna
+/// Devuelve la traducción con sus advertencias o el mensaje de error de la API.
+pub async fn translate_schema(
+    schema: String,
+    format: String,
+    engine: String,
+    target_engine: String,
+    options: &RequestOptions,
+) -> Result<SchemaTranslation, String> {
+    let to_shacl = engine == "ShEx";
+    let warnings = if to_shacl {
+        let (response, error) = api::call_shex_convert_api(
+            schema.clone(),
+            format.clone(),
+            "ShExJ".to_string(),
+            options,
+        )
+        .await;
+        if !error.is_empty() {
+            return Err(error);
+        }
+        shex_translation_warnings(&response.result.content)
+    } else {
+        let (response, error) = api::call_rdf_convert_api(
+            schema.clone(),
+            format.clone(),
+            "N-Triples".to_string(),
+            options,
+        )
+        .await;
+        if !error.is_empty() {
+            return Err(error);
+        }
+        shacl_translation_warnings(&ntriples::parse_ntriples(&response.result.content))
+    };
+
+    let target_format = if to_shacl { "Turtle" } else { "ShExC" };
+    let (response, error) = api::call_schema_translation_api(
+        schema,
+        format,
+        engine,
+        target_format.to_string(),
+        target_engine.clone(),
+        options,
+    )
+    .await;
+    if !error.is_empty() {
+        return Err(error);
+    }
+    if response.result.content.is_empty() {
+        return Err("The API returned an empty schema".to_string());
+    }
+
+    Ok(SchemaTranslation {
+        content: response.result.content,
+        format: target_format.to_string(),
+        engine: target_engine,
+        warnings,
+    })
+}
+
+/// Advertencias de las construcciones de un esquema ShExJ que no tienen equivalente en SHACL.
+///
+/// # Parámetros
+/// * `shexj` - Esquema en formato ShExJ.
+///
+/// # Retorna
+/// Devuelve una advertencia por cada tipo de construcción no soportada, sin repetir.
+pub fn shex_translation_warnings(shexj: &str) -> Vec<String> {
+    let schema: Value = match serde_json::from_str(shexj) {
+        Ok(schema) => schema,
+        Err(_) => return Vec::new(),
+    };
+
+    let mut warnings: Vec<String> = Vec::new();
+    if schema.get("start").is_some() {
+        push_warning(
+            &mut warnings,
+            "The start declaration has no SHACL equivalent and is dropped.",
+        );
+    }
+    if schema.get("imports").is_some() {
+        push_warning(&mut warnings, "Imported schemas are not translated.");
+    }
+    collect_shex_warnings(&schema, &mut warnings);
+
+    let recursive = shexj::recursive_shapes(&shexj::shape_references(shexj));
+    if !recursive.is_empty() {
+        push_warning(
+            &mut warnings,
+            &format!(
+                "Recursive shapes ({}) have no standard semantics in SHACL.",
+                recursive.join(", ")
+            ),
+        );
+    }
+    warnings
+}
+
+/// Advertencias de las construcciones de un grafo de formas SHACL que no tienen equivalente en ShEx.
+///
+/// # Parámetros
+/// * `triples` - Tripletas del grafo de formas.
+///
+/// # Retorna
+/// Devuelve una advertencia por cada tipo de construcción no soportada, sin repetir.
+pub fn shacl_translation_warnings(triples: &[Triple]) -> Vec<String> {
+    let mut warnings: Vec<String> = Vec::new();
+    for triple in triples {
+        let property = match triple.predicate.strip_prefix(SH) {
+            Some(property) => property,
+            None => continue,
+        };
+        let warning = match property {
+            "targetClass" | "targetNode" | "targetSubjectsOf" | "targetObjectsOf" => {
+                "Target declarations are not part of ShEx; use a ShapeMap to select the focus nodes."
+            }
+            "sparql" | "select" | "ask" => "SPARQL-based constraints cannot be translated.",
+            "severity" | "message" => "Severities and messages are dropped.",
+            "deactivated" => "sh:deactivated is ignored.",
+            "qualifiedValueShape" => "Qualified value shapes are only approximated.",
+            "equals" | "disjoint" | "lessThan" | "lessThanOrEquals" => {
+                "Property pair constraints (sh:equals, sh:disjoint, sh:lessThan) cannot be translated."
+            }
+            "uniqueLang" => "sh:uniqueLang cannot be translated.",
+            "alternativePath" | "zeroOrMorePath" | "oneOrMorePath" | "zeroOrOnePath" => {
+                "Complex property paths cannot be translated."
+            }
+            "path" if matches!(triple.object, Term::BlankNode(_)) => {
+                "Complex property paths cannot be translated."
+            }
+            _ => continue,
+        };
+        push_warning(&mut warnings, warning);
+    }
+    warnings
+}
+
+/// Recorre una expresión ShExJ acumulando las advertencias de sus construcciones.
+fn collect_shex_warnings(expression: &Value, warnings: &mut Vec<String>) {
+    match expression {
+        Value::Object(object) => {
+            if object.contains_key("semActs") {
+                push_warning(warnings, "Semantic actions are ignored.");
+            }
+            if object.contains_key("annotations") {
+                push_warning(warnings, "Annotations are ignored.");
+            }
+            if object.contains_key("extra") {
+                push_warning(warnings, "EXTRA predicates cannot be expressed in SHACL.");
+            }
+            match object.get("type").and_then(Value::as_str) {
+                Some("OneOf") => push_warning(
+                    warnings,
+                    "One-of triple expressions (|) have no direct SHACL equivalent.",
+                ),
+                Some("ShapeExternal") => {
+                    push_warning(warnings, "EXTERNAL shapes cannot be translated.")
+                }
+                Some(kind) if kind.contains("Stem") => push_warning(
+                    warnings,
+                    "Value set stems (~) are only approximated or dropped.",
+                ),
+                _ => {}
+            }
+            for value in object.values() {
+                collect_shex_warnings(value, warnings);
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                collect_shex_warnings(item, warnings);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Añade la advertencia a la lista si no estaba ya.
+fn push_warning(warnings: &mut Vec<String>, warning: &str) {
+    if !warnings.iter().any(|existing| existing == warning) {
+        warnings.push(warning.to_string());
+    }
+}
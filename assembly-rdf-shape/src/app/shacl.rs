@@ -0,0 +1,228 @@
+use crate::app::api::{self, ShapeMapEntry};
+use crate::app::ntriples::{self, Term, Triple};
+use crate::app::request_policy::RequestOptions;
+use serde_json::Value;
+
+/// Espacio de nombres de SHACL.
+pub const SH: &str = "http://www.w3.org/ns/shacl#";
+
+/// Resumen de un grafo de formas SHACL para el modal de análisis.
+///
+/// # Campos
+/// * `node_shapes` - Formas de nodo declaradas
+/// * `property_shapes` - Número de formas de propiedad
+/// * `targets` - Declaraciones de objetivos, por ejemplo `:UserShape → targetClass :Person`
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ShapesGraphInfo {
+    pub node_shapes: Vec<String>,
+    pub property_shapes: usize,
+    pub targets: Vec<String>,
+}
+
+/// Analiza las tripletas de un grafo de formas SHACL.
+///
+/// # Parámetros
+/// * `triples` - Tripletas del grafo de formas.
+///
+/// # Retorna
+/// Devuelve las formas de nodo, el número de formas de propiedad y los objetivos declarados.
+pub fn shapes_graph_info(triples: &[Triple]) -> ShapesGraphInfo {
+    let node_shape = Term::Iri(format!("{}NodeShape", SH));
+    let property_shape = Term::Iri(format!("{}PropertyShape", SH));
+    let target_predicates = [
+        "targetClass",
+        "targetNode",
+        "targetSubjectsOf",
+        "targetObjectsOf",
+    ];
+
+    let mut node_shapes: Vec<&Term> = Vec::new();
+    let mut property_shapes: Vec<&Term> = Vec::new();
+    let mut targets: Vec<String> = Vec::new();
+    for triple in triples {
+        let is_target = target_predicates
+            .iter()
+            .any(|target| triple.predicate == format!("{}{}", SH, target));
+        let declares_shape = (triple.predicate == ntriples::RDF_TYPE
+            && triple.object == node_shape)
+            || is_target
+            || triple.predicate == format!("{}property", SH);
+        if declares_shape && !node_shapes.contains(&&triple.subject) {
+            node_shapes.push(&triple.subject);
+        }
+        if triple.predicate == ntriples::RDF_TYPE && triple.object == property_shape {
+            if !property_shapes.contains(&&triple.subject) {
+                property_shapes.push(&triple.subject);
+            }
+        } else if triple.predicate == format!("{}property", SH)
+            && !property_shapes.contains(&&triple.object)
+        {
+            property_shapes.push(&triple.object);
+        }
+        if is_target {
+            targets.push(format!(
+                "{} → {} {}",
+                display_term(&triple.subject),
+                triple.predicate.trim_start_matches(SH),
+                display_term(&triple.object)
+            ));
+        }
+    }
+
+    ShapesGraphInfo {
+        node_shapes: node_shapes
+            .into_iter()
+            .filter(|shape| !property_shapes.contains(shape))
+            .map(display_term)
+            .collect(),
+        property_shapes: property_shapes.len(),
+        targets,
+    }
+}
+
+/// Convierte los resultados de un informe de validación SHACL en filas de la tabla de resultados.
+///
+/// # Parámetros
+/// * `triples` - Tripletas del informe de validación.
+///
+/// # Retorna
+/// Devuelve una entrada por cada `sh:ValidationResult`, ordenadas por nodo foco.
+pub fn report_entries(triples: &[Triple]) -> Vec<ShapeMapEntry> {
+    let validation_result = Term::Iri(format!("{}ValidationResult", SH));
+    let mut results: Vec<&Term> = Vec::new();
+    for triple in triples {
+        let result = if triple.predicate == ntriples::RDF_TYPE && triple.object == validation_result
+        {
+            &triple.subject
+        } else if triple.predicate == format!("{}result", SH) {
+            &triple.object
+        } else {
+            continue;
+        };
+        if !results.contains(&result) {
+            results.push(result);
+        }
+    }
+
+    let mut entries: Vec<ShapeMapEntry> = results
+        .into_iter()
+        .map(|result| {
+            let values = |property: &str| -> Vec<&Term> {
+                triples
+                    .iter()
+                    .filter(|triple| {
+                        &triple.subject == result
+                            && triple.predicate == format!("{}{}", SH, property)
+                    })
+                    .map(|triple| &triple.object)
+                    .collect()
+            };
+            let first = |property: &str| {
+                values(property)
+                    .first()
+                    .map(|term| display_term(term))
+                    .unwrap_or_default()
+            };
+            let full = |property: &str| {
+                values(property)
+                    .first()
+                    .map(|term| term.to_ntriples())
+                    .unwrap_or_default()
+            };
+
+            let severity = match values("resultSeverity").first() {
+                Some(Term::Iri(iri)) => iri.trim_start_matches(SH).to_string(),
+                _ => "Violation".to_string(),
+            };
+            let messages: Vec<String> = values("resultMessage")
+                .into_iter()
+                .map(display_term)
+                .collect();
+            let reason = match values("sourceConstraintComponent").first() {
+                _ if !messages.is_empty() => messages.join("; "),
+                Some(Term::Iri(iri)) => iri.trim_start_matches(SH).to_string(),
+                _ => String::new(),
+            };
+
+            ShapeMapEntry {
+                node: first("focusNode"),
+                shape: first("sourceShape"),
+                status: "Invalid".to_string(),
+                reason,
+                severity,
+                path: first("resultPath"),
+                value: first("value"),
+                node_iri: full("focusNode"),
+                shape_iri: full("sourceShape"),
+                ..ShapeMapEntry::default()
+            }
+        })
+        .collect();
+    entries.sort_by(|a, b| a.node.cmp(&b.node));
+    entries
+}
+
+/// Obtiene las filas de la tabla de resultados a partir del informe SHACL devuelto por la API.
+///
+/// El informe puede venir como texto Turtle, como objeto con `content` y `format` o como JSON-LD;
+/// en todos los casos se convierte a N-Triples con la API antes de analizarlo.
+///
+/// # Parámetros
+/// * `report` - Informe de validación de la respuesta (`ApiResult.report`).
+/// * `options` - Configuración y señal de cancelación de las solicitudes a la API.
+///
+/// # Retorna
+/// Devuelve las filas del informe o un mensaje de error si no se ha podido leer.
+pub async fn load_report_entries(
+    report: Value,
+    options: &RequestOptions,
+) -> Result<Vec<ShapeMapEntry>, String> {
+    let (content, format) = match &report {
+        Value::String(content) => (content.clone(), "Turtle".to_string()),
+        Value::Object(object) => match object.get("content").and_then(Value::as_str) {
+            Some(content) => (
+                content.to_string(),
+                object
+                    .get("format")
+                    .and_then(Value::as_str)
+                    .map(normalize_format)
+                    .unwrap_or_else(|| "Turtle".to_string()),
+            ),
+            None => (report.to_string(), "JSON-LD".to_string()),
+        },
+        _ => (report.to_string(), "JSON-LD".to_string()),
+    };
+
+    let (response, error) =
+        api::call_rdf_convert_api(content, format, "N-Triples".to_string(), options).await;
+    if !error.is_empty() {
+        return Err(error);
+    }
+    Ok(report_entries(&ntriples::parse_ntriples(
+        &response.result.content,
+    )))
+}
+
+/// Traduce los nombres de formato del informe (`TURTLE`, `JSON-LD`...) a los que admite la API.
+fn normalize_format(format: &str) -> String {
+    match format.to_uppercase().as_str() {
+        "JSON-LD" | "JSONLD" => "JSON-LD".to_string(),
+        "N-TRIPLES" | "NTRIPLES" => "N-Triples".to_string(),
+        "RDF/XML" | "RDFXML" => "RDF/XML".to_string(),
+        _ => "Turtle".to_string(),
+    }
+}
+
+/// Devuelve un término del informe tal y como se muestra en la tabla de resultados.
+fn display_term(term: &Term) -> String {
+    match term {
+        Term::Iri(_) => format!(":{}", api::extract_last_segment(&term.to_ntriples())),
+        Term::BlankNode(_) => term.to_ntriples(),
+        Term::Literal {
+            value, language, ..
+        } => match language {
+            Some(language) => format!("{}@{}", value, language),
+            None => value.clone(),
+        },
+    }
+}
@@ -0,0 +1,637 @@
+use crate::app::api::Prefix;
+use serde::{Deserialize, Serialize};
+
+/// Selector de nodos de una asociación del ShapeMap.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub enum NodeSelector {
+    /// Nodo concreto tal y como aparece en el ShapeMap (IRI, nombre prefijado, literal o nodo en blanco).
+    Node(String),
+    /// Patrón de tripleta con `FOCUS` en la posición de sujeto u objeto y `_` como comodín.
+    TriplePattern {
+        subject: String,
+        predicate: String,
+        object: String,
+    },
+    /// Consulta SPARQL cuyos resultados son los nodos foco.
+    Sparql(String),
+}
+
+/// Forma de una asociación del ShapeMap.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub enum ShapeLabel {
+    /// Forma identificada por su etiqueta (IRI o nombre prefijado).
+    Label(String),
+    /// Forma declarada como `start` en el esquema.
+    Start,
+}
+
+/// Asociación `nodo@forma` del ShapeMap junto con su posición en el texto.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ShapeMapAssociation {
+    pub node: NodeSelector,
+    pub shape: ShapeLabel,
+    pub line: usize,
+    pub column: usize,
+}
+
+/// Error de sintaxis encontrado al analizar un ShapeMap.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ShapeMapError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+/// Categoría de un fragmento de texto del ShapeMap, usada para resaltar la sintaxis.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TokenKind {
+    Iri,
+    PrefixedName,
+    Keyword,
+    Literal,
+    At,
+    Punctuation,
+    Comment,
+    Text,
+}
+
+impl TokenKind {
+    /// Clase CSS con la que se muestra el fragmento.
+    pub fn css_class(&self) -> &'static str {
+        match self {
+            TokenKind::Iri => "sm-iri",
+            TokenKind::PrefixedName => "sm-pname",
+            TokenKind::Keyword => "sm-keyword",
+            TokenKind::Literal => "sm-literal",
+            TokenKind::At => "sm-at",
+            TokenKind::Punctuation => "sm-punct",
+            TokenKind::Comment => "sm-comment",
+            TokenKind::Text => "sm-text",
+        }
+    }
+}
+
+/// Divide el texto de un ShapeMap compacto en fragmentos para resaltar su sintaxis.
+///
+/// A diferencia de `parse_shapemap`, nunca falla: el texto que no se reconoce se devuelve como `Text`,
+/// de modo que la concatenación de los fragmentos es siempre igual al contenido original.
+///
+/// # Parámetros
+/// * `content` - Contenido del ShapeMap.
+///
+/// # Retorna
+/// Devuelve la lista de fragmentos con su categoría.
+pub fn tokenize(content: &str) -> Vec<(TokenKind, String)> {
+    let chars: Vec<char> = content.chars().collect();
+    let mut tokens: Vec<(TokenKind, String)> = Vec::new();
+    let mut pos = 0;
+
+    while pos < chars.len() {
+        let start = pos;
+        let c = chars[pos];
+        let kind = match c {
+            '<' => {
+                while pos < chars.len() && chars[pos] != '>' && !chars[pos].is_whitespace() {
+                    pos += 1;
+                }
+                if pos < chars.len() && chars[pos] == '>' {
+                    pos += 1;
+                }
+                TokenKind::Iri
+            }
+            '"' | '\'' => {
+                pos += 1;
+                while pos < chars.len() && chars[pos] != c {
+                    if chars[pos] == '\\' {
+                        pos += 1;
+                    }
+                    pos += 1;
+                }
+                pos = (pos + 1).min(chars.len());
+                TokenKind::Literal
+            }
+            '#' => {
+                while pos < chars.len() && chars[pos] != '\n' {
+                    pos += 1;
+                }
+                TokenKind::Comment
+            }
+            '@' => {
+                pos += 1;
+                TokenKind::At
+            }
+            '{' | '}' | ',' => {
+                pos += 1;
+                TokenKind::Punctuation
+            }
+            c if is_name_char(c) || c == ':' => {
+                while pos < chars.len()
+                    && (is_name_char(chars[pos]) || chars[pos] == ':' || chars[pos] == '.')
+                {
+                    pos += 1;
+                }
+                let word: String = chars[start..pos].iter().collect();
+                if ["FOCUS", "START", "SPARQL"].contains(&word.to_uppercase().as_str())
+                    || word == "a"
+                    || word == "_"
+                {
+                    TokenKind::Keyword
+                } else if word.contains(':') {
+                    TokenKind::PrefixedName
+                } else {
+                    TokenKind::Text
+                }
+            }
+            _ => {
+                pos += 1;
+                TokenKind::Text
+            }
+        };
+        let text: String = chars[start..pos].iter().collect();
+        match tokens.last_mut() {
+            Some((last_kind, last_text)) if *last_kind == kind && kind == TokenKind::Text => {
+                last_text.push_str(&text)
+            }
+            _ => tokens.push((kind, text)),
+        }
+    }
+
+    tokens
+}
+
+/// Acorta una IRI usando el primer prefijo que la contenga.
+///
+/// # Parámetros
+/// * `iri` - IRI completa, con o sin los delimitadores `<` y `>`.
+/// * `prefixes` - Prefijos disponibles (normalmente los del esquema).
+///
+/// # Retorna
+/// Devuelve el nombre prefijado (`:alice`) o la IRI entre `<` y `>` si ningún prefijo encaja.
+pub fn compact_iri(iri: &str, prefixes: &[Prefix]) -> String {
+    let iri = iri.trim_start_matches('<').trim_end_matches('>');
+    prefixes
+        .iter()
+        .filter(|prefix| !prefix.prefix_iri.is_empty() && iri.starts_with(&prefix.prefix_iri))
+        .map(|prefix| (prefix, &iri[prefix.prefix_iri.len()..]))
+        .find(|(_, local)| {
+            local
+                .chars()
+                .all(|c| is_name_char(c) || c == '.' || c == '%')
+                && !local.ends_with('.')
+        })
+        .map(|(prefix, local)| format!("{}:{}", prefix.prefix_name, local))
+        .unwrap_or_else(|| format!("<{}>", iri))
+}
+
+/// Construye el texto de una asociación del ShapeMap.
+///
+/// # Parámetros
+/// * `node` - Selector de nodos de la asociación.
+/// * `shape` - Forma de la asociación.
+///
+/// # Retorna
+/// Devuelve la asociación en formato compacto, por ejemplo `:alice@:User` o `{FOCUS a :Person}@:User`.
+pub fn format_association(node: &NodeSelector, shape: &ShapeLabel) -> String {
+    let node = match node {
+        NodeSelector::Node(node) => node.clone(),
+        NodeSelector::TriplePattern {
+            subject,
+            predicate,
+            object,
+        } => format!("{{{} {} {}}}", subject, predicate, object),
+        NodeSelector::Sparql(query) => format!("SPARQL \"\"\"{}\"\"\"", escape_string(query)),
+    };
+    let shape = match shape {
+        ShapeLabel::Label(label) => label.clone(),
+        ShapeLabel::Start => "START".to_string(),
+    };
+    format!("{}@{}", node, shape)
+}
+
+/// Añade una asociación al final de un ShapeMap compacto.
+///
+/// # Parámetros
+/// * `shapemap` - Contenido actual del ShapeMap.
+/// * `association` - Asociación a añadir, ya en formato compacto.
+///
+/// # Retorna
+/// Devuelve el nuevo contenido del ShapeMap.
+pub fn append_association(shapemap: &str, association: &str) -> String {
+    let current = shapemap.trim_end().trim_end_matches(',').trim_end();
+    if current.is_empty() {
+        association.to_string()
+    } else {
+        format!("{},\n{}", current, association)
+    }
+}
+
+/// Analiza un ShapeMap en formato compacto.
+///
+/// # Parámetros
+/// * `content` - Contenido del ShapeMap.
+///
+/// # Retorna
+/// Devuelve la lista de asociaciones o un `ShapeMapError` con la línea y columna del primer error.
+pub fn parse_shapemap(content: &str) -> Result<Vec<ShapeMapAssociation>, ShapeMapError> {
+    let mut parser = Parser::new(content);
+    let mut associations = Vec::new();
+
+    parser.skip_whitespace();
+    if parser.is_at_end() {
+        return Ok(associations);
+    }
+
+    loop {
+        associations.push(parser.parse_association()?);
+        parser.skip_whitespace();
+        if parser.is_at_end() {
+            break;
+        }
+        parser.expect(',')?;
+    }
+
+    Ok(associations)
+}
+
+/// Analiza un ShapeMap en formato JSON (`[{"node": ..., "shape": ...}]`).
+///
+/// # Parámetros
+/// * `content` - Contenido del ShapeMap.
+///
+/// # Retorna
+/// Devuelve la lista de asociaciones o un `ShapeMapError` con la línea y columna del primer error.
+pub fn parse_json_shapemap(content: &str) -> Result<Vec<ShapeMapAssociation>, ShapeMapError> {
+    let entries: Vec<serde_json::Value> =
+        serde_json::from_str(content).map_err(|e| ShapeMapError {
+            line: e.line(),
+            column: e.column(),
+            message: e.to_string(),
+        })?;
+
+    entries
+        .iter()
+        .map(|entry| {
+            let node = entry.get("node").and_then(|node| node.as_str());
+            let shape = entry.get("shape").and_then(|shape| shape.as_str());
+            match (node, shape) {
+                (Some(node), Some(shape)) => Ok(ShapeMapAssociation {
+                    node: NodeSelector::Node(format!("<{}>", node)),
+                    shape: if shape == "START" {
+                        ShapeLabel::Start
+                    } else {
+                        ShapeLabel::Label(format!("<{}>", shape))
+                    },
+                    line: 1,
+                    column: 1,
+                }),
+                _ => Err(ShapeMapError {
+                    line: 1,
+                    column: 1,
+                    message: "Every entry needs a \"node\" and a \"shape\"".to_string(),
+                }),
+            }
+        })
+        .collect()
+}
+
+/// Analizador por descenso recursivo del formato compacto de ShapeMap.
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+    line: usize,
+    column: usize,
+}
+
+impl Parser {
+    fn new(content: &str) -> Self {
+        Self {
+            chars: content.chars().collect(),
+            pos: 0,
+            line: 1,
+            column: 1,
+        }
+    }
+
+    fn is_at_end(&self) -> bool {
+        self.pos >= self.chars.len()
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn advance(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += 1;
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        Some(c)
+    }
+
+    fn error(&self, message: &str) -> ShapeMapError {
+        ShapeMapError {
+            line: self.line,
+            column: self.column,
+            message: message.to_string(),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(c) = self.peek() {
+            if c.is_whitespace() {
+                self.advance();
+            } else if c == '#' {
+                while let Some(c) = self.advance() {
+                    if c == '\n' {
+                        break;
+                    }
+                }
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), ShapeMapError> {
+        self.skip_whitespace();
+        if self.peek() == Some(expected) {
+            self.advance();
+            Ok(())
+        } else {
+            Err(self.error(&format!("Expected '{}'", expected)))
+        }
+    }
+
+    fn starts_with_keyword(&self, keyword: &str) -> bool {
+        let end = self.pos + keyword.len();
+        end <= self.chars.len()
+            && self.chars[self.pos..end]
+                .iter()
+                .collect::<String>()
+                .eq_ignore_ascii_case(keyword)
+            && self
+                .chars
+                .get(end)
+                .is_none_or(|c| !is_name_char(*c) && *c != ':')
+    }
+
+    fn consume_keyword(&mut self, keyword: &str) {
+        for _ in 0..keyword.len() {
+            self.advance();
+        }
+    }
+
+    fn parse_association(&mut self) -> Result<ShapeMapAssociation, ShapeMapError> {
+        self.skip_whitespace();
+        let line = self.line;
+        let column = self.column;
+        let node = self.parse_node_selector()?;
+        self.expect('@')?;
+        self.skip_whitespace();
+        let shape = if self.starts_with_keyword("START") {
+            self.consume_keyword("START");
+            ShapeLabel::Start
+        } else {
+            ShapeLabel::Label(self.parse_iri("Expected a shape label or START after '@'")?)
+        };
+        Ok(ShapeMapAssociation {
+            node,
+            shape,
+            line,
+            column,
+        })
+    }
+
+    fn parse_node_selector(&mut self) -> Result<NodeSelector, ShapeMapError> {
+        match self.peek() {
+            Some('{') => self.parse_triple_pattern(),
+            _ if self.starts_with_keyword("SPARQL") => {
+                self.consume_keyword("SPARQL");
+                self.skip_whitespace();
+                if self.peek() != Some('"') {
+                    return Err(self.error("Expected a quoted query after SPARQL"));
+                }
+                let query = self.parse_string()?;
+                Ok(NodeSelector::Sparql(unescape_string(&query)))
+            }
+            _ => Ok(NodeSelector::Node(self.parse_object_term()?)),
+        }
+    }
+
+    fn parse_triple_pattern(&mut self) -> Result<NodeSelector, ShapeMapError> {
+        self.expect('{')?;
+        self.skip_whitespace();
+        let pattern = if self.starts_with_keyword("FOCUS") {
+            self.consume_keyword("FOCUS");
+            self.skip_whitespace();
+            let predicate = self.parse_predicate()?;
+            self.skip_whitespace();
+            let object = if self.peek() == Some('_') && !self.is_blank_node_start() {
+                self.advance();
+                "_".to_string()
+            } else {
+                self.parse_object_term()?
+            };
+            NodeSelector::TriplePattern {
+                subject: "FOCUS".to_string(),
+                predicate,
+                object,
+            }
+        } else {
+            let subject = if self.peek() == Some('_') && !self.is_blank_node_start() {
+                self.advance();
+                "_".to_string()
+            } else {
+                self.parse_iri("Expected FOCUS, '_' or a subject in the triple pattern")?
+            };
+            self.skip_whitespace();
+            let predicate = self.parse_predicate()?;
+            self.skip_whitespace();
+            if !self.starts_with_keyword("FOCUS") {
+                return Err(self.error("Expected FOCUS as subject or object of the triple pattern"));
+            }
+            self.consume_keyword("FOCUS");
+            NodeSelector::TriplePattern {
+                subject,
+                predicate,
+                object: "FOCUS".to_string(),
+            }
+        };
+        self.expect('}')?;
+        Ok(pattern)
+    }
+
+    fn is_blank_node_start(&self) -> bool {
+        self.chars.get(self.pos + 1) == Some(&':')
+    }
+
+    fn parse_predicate(&mut self) -> Result<String, ShapeMapError> {
+        if self.peek() == Some('a')
+            && self
+                .chars
+                .get(self.pos + 1)
+                .is_some_and(|c| c.is_whitespace())
+        {
+            self.advance();
+            return Ok("a".to_string());
+        }
+        self.parse_iri("Expected a predicate in the triple pattern")
+    }
+
+    fn parse_object_term(&mut self) -> Result<String, ShapeMapError> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some('"') | Some('\'') => {
+                let value = self.parse_string()?;
+                let mut literal = format!("\"{}\"", value);
+                if self.peek() == Some('@')
+                    && self
+                        .chars
+                        .get(self.pos + 1)
+                        .is_some_and(|c| c.is_ascii_alphabetic())
+                {
+                    self.advance();
+                    literal.push('@');
+                    literal.push_str(&self.take_while(|c| c.is_ascii_alphanumeric() || c == '-'));
+                } else if self.peek() == Some('^') {
+                    self.advance();
+                    if self.peek() != Some('^') {
+                        return Err(self.error("Expected '^^' before the datatype"));
+                    }
+                    self.advance();
+                    literal.push_str("^^");
+                    literal.push_str(&self.parse_iri("Expected a datatype after '^^'")?);
+                }
+                Ok(literal)
+            }
+            Some(c) if c.is_ascii_digit() || c == '-' || c == '+' => Ok(self.take_while(|c| {
+                c.is_ascii_digit() || c == '.' || c == '-' || c == '+' || c == 'e' || c == 'E'
+            })),
+            Some('_') if self.is_blank_node_start() => {
+                self.advance();
+                self.advance();
+                let label = self.take_while(is_name_char);
+                if label.is_empty() {
+                    return Err(self.error("Expected a blank node label after '_:'"));
+                }
+                Ok(format!("_:{}", label))
+            }
+            _ => self.parse_iri("Expected a node (IRI, prefixed name, literal or blank node)"),
+        }
+    }
+
+    fn parse_iri(&mut self, message: &str) -> Result<String, ShapeMapError> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some('<') => {
+                let mut iri = String::new();
+                while let Some(c) = self.advance() {
+                    iri.push(c);
+                    if c == '>' {
+                        return Ok(iri);
+                    }
+                    if c.is_whitespace() {
+                        break;
+                    }
+                }
+                Err(self.error("Unterminated IRI, expected '>'"))
+            }
+            Some(c) if c == ':' || c.is_alphabetic() => {
+                let prefix = self.take_while(is_name_char);
+                if self.peek() != Some(':') {
+                    return Err(self.error(message));
+                }
+                self.advance();
+                let mut local =
+                    self.take_while(|c| is_name_char(c) || c == '.' || c == ':' || c == '%');
+                while local.ends_with('.') {
+                    local.pop();
+                    self.pos -= 1;
+                    self.column -= 1;
+                }
+                Ok(format!("{}:{}", prefix, local))
+            }
+            _ => Err(self.error(message)),
+        }
+    }
+
+    fn parse_string(&mut self) -> Result<String, ShapeMapError> {
+        let quote = self.advance().unwrap_or('"');
+        let triple = self.peek() == Some(quote) && self.chars.get(self.pos + 1) == Some(&quote);
+        if triple {
+            self.advance();
+            self.advance();
+        }
+
+        let mut value = String::new();
+        let mut escaped = false;
+        while let Some(c) = self.advance() {
+            if escaped {
+                value.push(c);
+                escaped = false;
+            } else if c == '\\' {
+                value.push(c);
+                escaped = true;
+            } else if c == quote
+                && (!triple
+                    || (self.peek() == Some(quote) && self.chars.get(self.pos + 1) == Some(&quote)))
+            {
+                if triple {
+                    self.advance();
+                    self.advance();
+                }
+                return Ok(value);
+            } else {
+                value.push(c);
+            }
+        }
+        Err(self.error("Unterminated string"))
+    }
+
+    fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> String {
+        let mut value = String::new();
+        while let Some(c) = self.peek() {
+            if !predicate(c) {
+                break;
+            }
+            value.push(c);
+            self.advance();
+        }
+        value
+    }
+}
+
+/// Escapa las barras invertidas y las comillas de una cadena para escribirla entre comillas en el ShapeMap,
+/// de modo que una consulta SPARQL que contenga `"""` no cierre la cadena antes de tiempo.
+fn escape_string(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Sustituye las secuencias de escape de una cadena del ShapeMap por los caracteres que representan.
+fn unescape_string(value: &str) -> String {
+    let mut unescaped = String::new();
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => unescaped.push('\n'),
+            Some('t') => unescaped.push('\t'),
+            Some('r') => unescaped.push('\r'),
+            Some(other) => unescaped.push(other),
+            None => unescaped.push('\\'),
+        }
+    }
+    unescaped
+}
+
+/// Indica si el carácter puede formar parte de un prefijo o nombre local.
+fn is_name_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '-'
+}
@@ -0,0 +1,99 @@
+use crate::app::api::Prefix;
+use crate::app::ntriples::{self, Term, Triple};
+use crate::app::shapemap::{self, NodeSelector, ShapeLabel};
+
+/// Criterio con el que se proponen las asociaciones del ShapeMap generado.
+#[derive(Clone, Debug, PartialEq)]
+pub enum GenerationStrategy {
+    /// Asocia cada sujeto con las formas cuyo nombre coincide con el de alguna de sus clases (`rdf:type`).
+    TypeMatching,
+    /// Asocia todos los sujetos con la forma indicada.
+    AllSubjects(ShapeLabel),
+}
+
+/// Genera un ShapeMap compacto a partir de las tripletas de los datos y las formas del esquema.
+///
+/// # Parámetros
+/// * `triples` - Tripletas de los datos RDF.
+/// * `shapes` - IRIs de las formas del esquema (`InfoShexResult.shapes`).
+/// * `prefixes` - Prefijos con los que se acortan los nodos y las formas.
+/// * `strategy` - Criterio para proponer las asociaciones.
+///
+/// # Retorna
+/// Devuelve el ShapeMap propuesto, vacío si no se ha encontrado ninguna asociación.
+pub fn generate_shapemap(
+    triples: &[Triple],
+    shapes: &[String],
+    prefixes: &[Prefix],
+    strategy: &GenerationStrategy,
+) -> String {
+    let mut associations: Vec<String> = Vec::new();
+    for subject in subjects(triples) {
+        let labels: Vec<ShapeLabel> = match strategy {
+            GenerationStrategy::AllSubjects(shape) => vec![shape.clone()],
+            GenerationStrategy::TypeMatching => types_of(triples, &subject)
+                .iter()
+                .flat_map(|class| {
+                    shapes
+                        .iter()
+                        .filter(move |shape| shape_matches_class(shape, class))
+                })
+                .map(|shape| ShapeLabel::Label(shapemap::compact_iri(shape, prefixes)))
+                .collect(),
+        };
+        let node = NodeSelector::Node(shapemap::compact_iri(&subject, prefixes));
+        for label in labels {
+            let association = shapemap::format_association(&node, &label);
+            if !associations.contains(&association) {
+                associations.push(association);
+            }
+        }
+    }
+    associations.join(",\n")
+}
+
+/// Indica si el nombre de la forma corresponde al de la clase.
+///
+/// Se comparan los nombres locales sin distinguir mayúsculas y admitiendo el sufijo `Shape`
+/// en la forma, de modo que `schema:Person` encaja con `:Person` y con `:PersonShape`.
+pub fn shape_matches_class(shape: &str, class: &str) -> bool {
+    let shape = local_name(shape).to_lowercase();
+    let class = local_name(class).to_lowercase();
+    !class.is_empty() && (shape == class || shape == format!("{}shape", class))
+}
+
+/// Sujetos IRI de los datos en el orden en que aparecen.
+///
+/// Los nodos en blanco se omiten porque sus etiquetas no se conservan entre documentos.
+fn subjects(triples: &[Triple]) -> Vec<String> {
+    let mut subjects: Vec<String> = Vec::new();
+    for triple in triples {
+        if let Term::Iri(iri) = &triple.subject {
+            if !subjects.contains(iri) {
+                subjects.push(iri.clone());
+            }
+        }
+    }
+    subjects
+}
+
+/// Clases (`rdf:type`) del sujeto indicado.
+fn types_of(triples: &[Triple], subject: &str) -> Vec<String> {
+    triples
+        .iter()
+        .filter(|triple| {
+            triple.predicate == ntriples::RDF_TYPE
+                && matches!(&triple.subject, Term::Iri(iri) if iri == subject)
+        })
+        .filter_map(|triple| match &triple.object {
+            Term::Iri(iri) => Some(iri.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Devuelve el nombre local de una IRI (lo que sigue al último `#`, `/` o `:`).
+fn local_name(iri: &str) -> &str {
+    let iri = iri.trim_start_matches('<').trim_end_matches('>');
+    iri.rsplit(['#', '/', ':']).next().unwrap_or(iri)
+}
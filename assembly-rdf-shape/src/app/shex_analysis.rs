@@ -0,0 +1,156 @@
+use crate::app::api::Prefix;
+use crate::app::shapemap;
+use crate::app::shexj::{self, StartShape};
+
+/// Forma declarada en el esquema, tal y como se lista en el modal de análisis.
+///
+/// # Campos
+/// * `label` - Etiqueta de la forma, acortada con los prefijos del esquema
+/// * `line` - Línea (desde 1) de su declaración en el editor, si se ha encontrado
+/// * `references` - Formas a las que hace referencia
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ShapeSummary {
+    pub label: String,
+    pub line: Option<usize>,
+    pub references: Vec<String>,
+}
+
+/// Análisis detallado de un esquema ShEx.
+///
+/// # Campos
+/// * `shapes` - Formas en orden de declaración
+/// * `warnings` - Formas sin referencias, referencias sin definir, ciclos y prefijos sin usar
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ShexAnalysis {
+    pub shapes: Vec<ShapeSummary>,
+    pub warnings: Vec<String>,
+}
+
+/// Analiza un esquema a partir de su versión ShExJ y de su texto original.
+///
+/// # Parámetros
+/// * `shexj` - Esquema en formato ShExJ.
+/// * `source` - Texto del esquema en el editor, donde se buscan las declaraciones y los prefijos usados.
+/// * `prefixes` - Prefijos del esquema (`InfoShexResult.prefix_map`).
+///
+/// # Retorna
+/// Devuelve las formas del esquema y las advertencias encontradas.
+pub fn analyze(shexj: &str, source: &str, prefixes: &[Prefix]) -> ShexAnalysis {
+    let references = shexj::shape_references(shexj);
+    let start = match shexj::start_shape(shexj) {
+        Some(StartShape::Reference(label)) => Some(label),
+        _ => None,
+    };
+    let compact = |iri: &str| shapemap::compact_iri(iri, prefixes);
+
+    let shapes: Vec<ShapeSummary> = references
+        .iter()
+        .map(|(id, targets)| ShapeSummary {
+            label: compact(id),
+            line: declaration_line(source, &compact(id))
+                .or_else(|| declaration_line(source, &format!("<{}>", id))),
+            references: targets.iter().map(|target| compact(target)).collect(),
+        })
+        .collect();
+
+    let mut warnings = Vec::new();
+    for (id, _) in references.iter() {
+        let referenced = references
+            .iter()
+            .any(|(other, targets)| other != id && targets.contains(id));
+        if !referenced && start.as_ref() != Some(id) {
+            warnings.push(format!(
+                "Shape {} is not referenced by any other shape.",
+                compact(id)
+            ));
+        }
+    }
+    for (id, targets) in references.iter() {
+        for target in targets {
+            if !references.iter().any(|(declared, _)| declared == target) {
+                warnings.push(format!(
+                    "Shape {} references undefined shape {}.",
+                    compact(id),
+                    compact(target)
+                ));
+            }
+        }
+    }
+    let cyclic = shexj::recursive_shapes(&references);
+    if !cyclic.is_empty() {
+        let cyclic: Vec<String> = cyclic.iter().map(|id| compact(id)).collect();
+        warnings.push(format!(
+            "Cyclic references between shapes: {}.",
+            cyclic.join(", ")
+        ));
+    }
+    for prefix in unused_prefixes(source, prefixes) {
+        warnings.push(format!("Prefix {}: is declared but never used.", prefix));
+    }
+
+    ShexAnalysis { shapes, warnings }
+}
+
+/// Prefijos declarados cuyo nombre no aparece en el esquema fuera de las declaraciones `PREFIX`.
+pub fn unused_prefixes(source: &str, prefixes: &[Prefix]) -> Vec<String> {
+    let body: Vec<&str> = source
+        .lines()
+        .filter(|line| !is_prefix_declaration(line))
+        .collect();
+    prefixes
+        .iter()
+        .filter(|prefix| {
+            let name = format!("{}:", prefix.prefix_name);
+            !body.iter().any(|line| {
+                line.match_indices(&name).any(|(index, _)| {
+                    // El prefijo no debe ser el final de otro nombre (`ex:` dentro de `myex:`)
+                    !line[..index]
+                        .chars()
+                        .last()
+                        .map(|c| c.is_alphanumeric() || c == '_' || c == '-' || c == '.')
+                        .unwrap_or(false)
+                })
+            })
+        })
+        .map(|prefix| prefix.prefix_name.clone())
+        .collect()
+}
+
+/// Línea (desde 1) en la que se declara la forma con la etiqueta indicada.
+///
+/// Se busca la primera línea que empieza por la etiqueta, sin contar las declaraciones `PREFIX`.
+fn declaration_line(source: &str, label: &str) -> Option<usize> {
+    source
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !is_prefix_declaration(line))
+        .find(|(_, line)| {
+            let line = line.trim_start();
+            let line = line
+                .strip_prefix("ABSTRACT ")
+                .or_else(|| line.strip_prefix("abstract "))
+                .map(str::trim_start)
+                .unwrap_or(line);
+            line.strip_prefix(label)
+                .map(|rest| {
+                    rest.chars()
+                        .next()
+                        .map(|c| !(c.is_alphanumeric() || c == '_' || c == '-' || c == ':'))
+                        .unwrap_or(true)
+                })
+                .unwrap_or(false)
+        })
+        .map(|(index, _)| index + 1)
+}
+
+/// Indica si la línea es una declaración `PREFIX` o `BASE`.
+fn is_prefix_declaration(line: &str) -> bool {
+    let line = line.trim_start().to_lowercase();
+    ["prefix", "@prefix", "base", "@base"]
+        .iter()
+        .any(|keyword| {
+            line.strip_prefix(keyword)
+                .map(|rest| rest.starts_with(char::is_whitespace))
+                .unwrap_or(false)
+        })
+}
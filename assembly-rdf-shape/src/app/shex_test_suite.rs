@@ -0,0 +1,243 @@
+use crate::app::api::{self, ShapeMapEntry};
+use crate::app::examples_manager::ExampleData;
+use serde_json::Value;
+
+/// Caso de prueba de validación de la batería de tests de ShEx (shexTest).
+///
+/// # Campos
+/// * `name` - Nombre del test en el manifiesto
+/// * `comment` - Descripción del test
+/// * `data` - Datos, esquema y ShapeMap que se cargan en los editores
+/// * `focus` - Nodo foco en sintaxis de ShapeMap (`<iri>` o literal)
+/// * `shape` - Forma con la que se valida el nodo (`<iri>` o `START`)
+/// * `expected_valid` - Indica si el test espera que el nodo sea conforme (`sht:ValidationTest`) o no (`sht:ValidationFailure`)
+/// * `matched` - Resultado de la última validación del test: si coincidió con lo esperado
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShexTestCase {
+    pub name: String,
+    pub comment: String,
+    pub data: ExampleData,
+    pub focus: String,
+    pub shape: String,
+    pub expected_valid: bool,
+    pub matched: Option<bool>,
+}
+
+impl ShexTestCase {
+    /// Texto del resultado esperado.
+    pub fn expected(&self) -> &'static str {
+        if self.expected_valid {
+            "conformant"
+        } else {
+            "nonconformant"
+        }
+    }
+
+    /// Comprueba si el resultado de una validación coincide con el esperado por el test.
+    ///
+    /// Las entradas del resultado tienen los nodos abreviados como `:<último segmento>`
+    /// (ver `api::format_shape_maps`), así que el nodo foco se abrevia del mismo modo.
+    ///
+    /// # Retorna
+    /// Devuelve `None` si el resultado no contiene el nodo foco del test.
+    pub fn check(&self, entries: &[ShapeMapEntry]) -> Option<bool> {
+        let node = format!(":{}", api::extract_last_segment(&self.focus));
+        let statuses: Vec<&String> = entries
+            .iter()
+            .filter(|entry| entry.node == node || entry.node == self.focus)
+            .map(|entry| &entry.status)
+            .collect();
+        if statuses.is_empty() {
+            return None;
+        }
+        let valid = statuses.iter().all(|status| *status == "Valid");
+        Some(valid == self.expected_valid)
+    }
+}
+
+/// Resultado de importar un manifiesto de shexTest.
+///
+/// # Campos
+/// * `tests` - Casos de prueba importados
+/// * `skipped` - Tests que no se han podido importar, con el motivo
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ImportedManifest {
+    pub tests: Vec<ShexTestCase>,
+    pub skipped: Vec<String>,
+}
+
+/// Importa los tests de validación de un `manifest.jsonld` de shexTest.
+///
+/// Los ficheros a los que hace referencia el manifiesto (esquemas, datos y ShapeMaps) se buscan por
+/// su nombre entre los ficheros proporcionados, ya que las rutas relativas del manifiesto no se
+/// conservan al seleccionarlos en el navegador.
+///
+/// # Parámetros
+/// * `files` - Ficheros seleccionados por el usuario: nombre y contenido. Debe incluir un `.jsonld`.
+///
+/// # Retorna
+/// Devuelve los tests importados y los omitidos, o un mensaje de error si no hay un manifiesto válido.
+pub fn import_manifest(files: &[(String, String)]) -> Result<ImportedManifest, String> {
+    let (_, content) = files
+        .iter()
+        .find(|(name, _)| name.ends_with(".jsonld"))
+        .ok_or_else(|| "No manifest.jsonld file was selected.".to_string())?;
+    let manifest: Value = serde_json::from_str(content)
+        .map_err(|err| format!("The manifest is not valid JSON-LD: {}", err))?;
+
+    let entries: Vec<&Value> = match manifest.get("@graph").and_then(Value::as_array) {
+        Some(graph) => graph
+            .iter()
+            .filter_map(|item| item.get("entries").and_then(Value::as_array))
+            .flatten()
+            .collect(),
+        None => manifest
+            .get("entries")
+            .and_then(Value::as_array)
+            .map(|entries| entries.iter().collect())
+            .unwrap_or_default(),
+    };
+    if entries.is_empty() {
+        return Err("The manifest has no entries.".to_string());
+    }
+
+    let mut imported = ImportedManifest::default();
+    for entry in entries {
+        let name = text(entry.get("name"))
+            .or_else(|| text(entry.get("@id")))
+            .unwrap_or_default();
+        match import_entry(entry, files) {
+            Ok(Some(test)) => imported.tests.push(test),
+            Ok(None) => {}
+            Err(reason) => imported.skipped.push(format!("{}: {}", name, reason)),
+        }
+    }
+    Ok(imported)
+}
+
+/// Importa un test del manifiesto.
+///
+/// # Retorna
+/// Devuelve `None` si la entrada no es un test de validación y un error si le falta algún fichero.
+fn import_entry(entry: &Value, files: &[(String, String)]) -> Result<Option<ShexTestCase>, String> {
+    let kind = text(entry.get("@type")).unwrap_or_default();
+    let expected_valid = if kind.ends_with("ValidationTest") {
+        true
+    } else if kind.ends_with("ValidationFailure") {
+        false
+    } else {
+        return Ok(None);
+    };
+
+    let action = entry
+        .get("action")
+        .ok_or_else(|| "the test has no action".to_string())?;
+    let find = |key: &str| -> Result<(String, String), String> {
+        let path = text(action.get(key)).ok_or_else(|| format!("the test has no {}", key))?;
+        let file_name = path.rsplit('/').next().unwrap_or(&path).to_string();
+        files
+            .iter()
+            .find(|(name, _)| *name == file_name)
+            .map(|(_, content)| (file_name.clone(), content.clone()))
+            .ok_or_else(|| format!("{} was not selected", file_name))
+    };
+
+    let (schema_file, shex) = find("schema")?;
+    let (data_file, rdf) = find("data")?;
+    let focus = action.get("focus").map(focus_term).unwrap_or_default();
+    let shape = text(action.get("shape"))
+        .map(|shape| format!("<{}>", shape))
+        .unwrap_or_else(|| "START".to_string());
+
+    let (shapemap, shapemap_format) = if action.get("map").is_some() {
+        (find("map")?.1, "JSON".to_string())
+    } else if focus.is_empty() {
+        return Err("the test has no focus node".to_string());
+    } else {
+        (format!("{}@{}", focus, shape), "Compact".to_string())
+    };
+
+    Ok(Some(ShexTestCase {
+        name: text(entry.get("name")).unwrap_or_default(),
+        comment: text(entry.get("comment")).unwrap_or_default(),
+        data: ExampleData {
+            rdf,
+            shex,
+            shapemap,
+            rdf_format: rdf_format(&data_file),
+            shex_format: shex_format(&schema_file),
+            shapemap_format,
+        },
+        focus,
+        shape,
+        expected_valid,
+        matched: None,
+    }))
+}
+
+/// Devuelve el texto de un valor JSON-LD (cadena, `@id` o `@value`).
+fn text(value: Option<&Value>) -> Option<String> {
+    match value? {
+        Value::String(text) => Some(text.clone()),
+        Value::Array(items) => text(items.first()),
+        Value::Object(object) => object
+            .get("@id")
+            .or_else(|| object.get("@value"))
+            .and_then(Value::as_str)
+            .map(str::to_string),
+        _ => None,
+    }
+}
+
+/// Convierte el nodo foco del manifiesto en un término de ShapeMap.
+fn focus_term(value: &Value) -> String {
+    match value {
+        Value::Object(object) if object.contains_key("@value") => {
+            let literal = format!(
+                "\"{}\"",
+                object
+                    .get("@value")
+                    .map(|value| match value {
+                        Value::String(text) => text.clone(),
+                        other => other.to_string(),
+                    })
+                    .unwrap_or_default()
+                    .replace('"', "\\\"")
+            );
+            match (
+                object.get("@language").and_then(Value::as_str),
+                object.get("@type").and_then(Value::as_str),
+            ) {
+                (Some(language), _) => format!("{}@{}", literal, language),
+                (None, Some(datatype)) => format!("{}^^<{}>", literal, datatype),
+                (None, None) => literal,
+            }
+        }
+        _ => match text(Some(value)) {
+            Some(iri) if iri.starts_with("_:") => iri,
+            Some(iri) => format!("<{}>", iri),
+            None => String::new(),
+        },
+    }
+}
+
+/// Formato de los datos según la extensión del fichero.
+fn rdf_format(file: &str) -> String {
+    match file.rsplit('.').next().unwrap_or_default() {
+        "nt" => "N-Triples",
+        "jsonld" | "json" => "JSON-LD",
+        "rdf" | "xml" => "RDF/XML",
+        _ => "Turtle",
+    }
+    .to_string()
+}
+
+/// Formato del esquema según la extensión del fichero.
+fn shex_format(file: &str) -> String {
+    match file.rsplit('.').next().unwrap_or_default() {
+        "json" => "ShExJ",
+        "ttl" => "Turtle",
+        _ => "ShExC",
+    }
+    .to_string()
+}
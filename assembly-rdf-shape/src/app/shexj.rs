@@ -0,0 +1,240 @@
+use crate::app::api::{self, Prefix};
+use crate::app::request_policy::RequestOptions;
+use crate::app::shapemap;
+use serde_json::Value;
+
+/// Declaración `start` de un esquema ShEx.
+#[derive(Clone, Debug, PartialEq)]
+pub enum StartShape {
+    /// `start = @<label>`: referencia a una forma del esquema por su IRI.
+    Reference(String),
+    /// `start = { ... }`: expresión de forma escrita directamente en la declaración.
+    Inline,
+}
+
+impl StartShape {
+    /// Devuelve el texto con el que se muestra la forma inicial, acortando su IRI con los prefijos indicados.
+    pub fn describe(&self, prefixes: &[Prefix]) -> String {
+        match self {
+            StartShape::Reference(label) => shapemap::compact_iri(label, prefixes),
+            StartShape::Inline => "Inline shape expression".to_string(),
+        }
+    }
+}
+
+/// Obtiene la declaración `start` de un esquema en formato ShExJ.
+///
+/// # Parámetros
+/// * `shexj` - Esquema en formato ShExJ.
+///
+/// # Retorna
+/// Devuelve la forma inicial o `None` si el esquema no la declara o no es un JSON válido.
+pub fn start_shape(shexj: &str) -> Option<StartShape> {
+    let schema: Value = serde_json::from_str(shexj).ok()?;
+    match schema.get("start")? {
+        Value::String(label) => Some(StartShape::Reference(label.clone())),
+        Value::Null => None,
+        _ => Some(StartShape::Inline),
+    }
+}
+
+/// Obtiene la declaración `start` de un esquema en cualquier formato convirtiéndolo a ShExJ con la API.
+///
+/// # Parámetros
+/// * `shex` - Contenido del esquema.
+/// * `format` - Formato del esquema.
+/// * `options` - Configuración y señal de cancelación de las solicitudes a la API.
+///
+/// # Retorna
+/// Devuelve la forma inicial o `None` si el esquema no la declara o no se ha podido convertir.
+pub async fn load_start_shape(
+    shex: String,
+    format: String,
+    options: &RequestOptions,
+) -> Option<StartShape> {
+    if shex.trim().is_empty() {
+        return None;
+    }
+    let (response, error) =
+        api::call_shex_convert_api(shex, format, "ShExJ".to_string(), options).await;
+    if !error.is_empty() {
+        return None;
+    }
+    start_shape(&response.result.content)
+}
+
+/// Devuelve las formas declaradas en un esquema ShExJ junto con su expresión de forma.
+///
+/// Admite tanto las declaraciones de ShExJ 2.0 (la forma lleva su `id`) como las de ShExJ 2.1
+/// (`ShapeDecl` con la expresión en `shapeExpr`).
+pub fn shape_declarations(schema: &Value) -> Vec<(String, &Value)> {
+    schema
+        .get("shapes")
+        .and_then(Value::as_array)
+        .map(|shapes| {
+            shapes
+                .iter()
+                .filter_map(|shape| {
+                    let id = shape.get("id")?.as_str()?.to_string();
+                    let expression = match shape.get("type").and_then(Value::as_str) {
+                        Some("ShapeDecl") => shape.get("shapeExpr")?,
+                        _ => shape,
+                    };
+                    Some((id, expression))
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Obtiene las referencias (`@<label>`) que hace cada forma de un esquema ShExJ a otras formas.
+///
+/// # Parámetros
+/// * `shexj` - Esquema en formato ShExJ.
+///
+/// # Retorna
+/// Devuelve, por cada forma en orden de declaración, las etiquetas de las formas a las que hace referencia.
+pub fn shape_references(shexj: &str) -> Vec<(String, Vec<String>)> {
+    let schema: Value = match serde_json::from_str(shexj) {
+        Ok(schema) => schema,
+        Err(_) => return Vec::new(),
+    };
+    shape_declarations(&schema)
+        .into_iter()
+        .map(|(id, expression)| {
+            let mut references = Vec::new();
+            collect_references(expression, &mut references);
+            (id, references)
+        })
+        .collect()
+}
+
+/// Devuelve las formas que forman parte de algún ciclo de referencias, en orden de declaración.
+pub fn recursive_shapes(references: &[(String, Vec<String>)]) -> Vec<String> {
+    references
+        .iter()
+        .filter(|(id, _)| {
+            // Una forma es recursiva si puede alcanzarse a sí misma siguiendo las referencias
+            let mut pending: Vec<&String> = vec![id];
+            let mut visited: Vec<&String> = Vec::new();
+            while let Some(current) = pending.pop() {
+                let targets = references
+                    .iter()
+                    .find(|(candidate, _)| candidate == current)
+                    .map(|(_, targets)| targets.iter().collect::<Vec<_>>())
+                    .unwrap_or_default();
+                for target in targets {
+                    if target == id {
+                        return true;
+                    }
+                    if !visited.contains(&target) {
+                        visited.push(target);
+                        pending.push(target);
+                    }
+                }
+            }
+            false
+        })
+        .map(|(id, _)| id.clone())
+        .collect()
+}
+
+/// Obtiene los predicados de las restricciones de tripleta cuyo valor depende de otra forma
+/// (`:p @<Label>` o `:p { ... }`), con los que el resultado de un nodo depende del de sus vecinos.
+///
+/// # Parámetros
+/// * `shexj` - Esquema en formato ShExJ.
+///
+/// # Retorna
+/// Devuelve cada predicado junto con la indicación de si la restricción es inversa (`^:p`), o `None` si el
+/// esquema no es un JSON válido.
+pub fn shape_reference_predicates(shexj: &str) -> Option<Vec<(String, bool)>> {
+    let schema: Value = serde_json::from_str(shexj).ok()?;
+    let mut predicates = Vec::new();
+    collect_reference_predicates(&schema, &mut predicates);
+    Some(predicates)
+}
+
+/// Recorre una expresión ShExJ acumulando los predicados de las restricciones de tripleta que dependen de otra forma.
+fn collect_reference_predicates(expression: &Value, predicates: &mut Vec<(String, bool)>) {
+    match expression {
+        Value::Object(object) => {
+            if object.get("type").and_then(Value::as_str) == Some("TripleConstraint") {
+                let predicate = object.get("predicate").and_then(Value::as_str);
+                if let (Some(predicate), Some(value_expr)) = (predicate, object.get("valueExpr")) {
+                    let inverse = object
+                        .get("inverse")
+                        .and_then(Value::as_bool)
+                        .unwrap_or(false);
+                    let entry = (predicate.to_string(), inverse);
+                    if depends_on_shape(value_expr) && !predicates.contains(&entry) {
+                        predicates.push(entry);
+                    }
+                }
+            }
+            for value in object.values() {
+                collect_reference_predicates(value, predicates);
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                collect_reference_predicates(item, predicates);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Indica si una expresión de valor depende de otra forma: es una referencia o algo más que una restricción de nodo.
+fn depends_on_shape(value_expr: &Value) -> bool {
+    match value_expr {
+        Value::String(_) => true,
+        Value::Object(object) => match object.get("type").and_then(Value::as_str) {
+            Some("NodeConstraint") => false,
+            Some("ShapeAnd") | Some("ShapeOr") => object
+                .get("shapeExprs")
+                .and_then(Value::as_array)
+                .is_some_and(|items| items.iter().any(depends_on_shape)),
+            Some("ShapeNot") => object.get("shapeExpr").is_some_and(depends_on_shape),
+            _ => true,
+        },
+        _ => false,
+    }
+}
+
+/// Recorre una expresión ShExJ acumulando las etiquetas de las formas referenciadas.
+fn collect_references(expression: &Value, references: &mut Vec<String>) {
+    match expression {
+        Value::Object(object) => {
+            for (key, value) in object {
+                match (key.as_str(), value) {
+                    ("valueExpr" | "shapeExpr", Value::String(label)) => {
+                        push_unique(references, label)
+                    }
+                    ("shapeExprs", Value::Array(items)) => {
+                        for item in items {
+                            match item {
+                                Value::String(label) => push_unique(references, label),
+                                _ => collect_references(item, references),
+                            }
+                        }
+                    }
+                    _ => collect_references(value, references),
+                }
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                collect_references(item, references);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Añade la etiqueta a la lista si no estaba ya.
+fn push_unique(references: &mut Vec<String>, label: &str) {
+    if !references.iter().any(|reference| reference == label) {
+        references.push(label.to_string());
+    }
+}
@@ -0,0 +1,111 @@
+use crate::app::examples_manager::ExampleData;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+
+/// Clave del almacenamiento local del navegador en la que se guarda la biblioteca de ejemplos.
+const STORAGE_KEY: &str = "rdf-validator-user-examples";
+
+/// Lectura y escritura en el almacenamiento local del navegador
+#[wasm_bindgen(inline_js = "
+    export function getStorageItem(key) {
+        try {
+            return window.localStorage.getItem(key);
+        } catch (error) {
+            return null;
+        }
+    }
+
+    export function setStorageItem(key, value) {
+        try {
+            window.localStorage.setItem(key, value);
+            return true;
+        } catch (error) {
+            return false;
+        }
+    }
+    ")]
+extern "C" {
+    pub(crate) fn getStorageItem(key: &str) -> Option<String>;
+    pub(crate) fn setStorageItem(key: &str, value: &str) -> bool;
+}
+
+/// Ejemplo guardado por el usuario: un nombre y el contenido de los editores con sus formatos.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct UserExample {
+    pub name: String,
+    #[serde(flatten)]
+    pub data: ExampleData,
+}
+
+/// Acciones sobre la biblioteca de ejemplos del usuario que se lanzan desde el menú de ejemplos.
+#[derive(Clone, Debug)]
+pub enum UserExampleAction {
+    /// Guarda el contenido actual de los editores con el nombre indicado.
+    Save(String),
+    /// Carga en los editores el ejemplo en la posición indicada.
+    Load(usize),
+    /// Cambia el nombre del ejemplo en la posición indicada.
+    Rename(usize, String),
+    /// Elimina el ejemplo en la posición indicada.
+    Delete(usize),
+    /// Importa los ejemplos del documento JSON indicado.
+    Import(String),
+    /// Descarga la biblioteca completa como JSON.
+    Export,
+}
+
+/// Lee la biblioteca de ejemplos del almacenamiento local; vacía si no existe o no es válida.
+pub fn load_library() -> Vec<UserExample> {
+    getStorageItem(STORAGE_KEY)
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+/// Guarda la biblioteca de ejemplos en el almacenamiento local.
+///
+/// # Retorna
+/// Devuelve un mensaje de error si el navegador no permite guardarla (modo privado, cuota agotada...).
+pub fn save_library(library: &[UserExample]) -> Result<(), String> {
+    let json = export_library(library);
+    if setStorageItem(STORAGE_KEY, &json) {
+        Ok(())
+    } else {
+        Err("The examples could not be saved in the browser storage.".to_string())
+    }
+}
+
+/// Serializa la biblioteca de ejemplos como JSON.
+pub fn export_library(library: &[UserExample]) -> String {
+    serde_json::to_string_pretty(library).unwrap_or_else(|_| "[]".to_string())
+}
+
+/// Añade a la biblioteca los ejemplos de un documento JSON exportado.
+///
+/// Los ejemplos importados sustituyen a los que tienen el mismo nombre.
+///
+/// # Parámetros
+/// * `library` - Biblioteca actual.
+/// * `json` - Documento JSON con una lista de ejemplos.
+///
+/// # Retorna
+/// Devuelve el número de ejemplos importados o un mensaje de error si el documento no es válido.
+pub fn import_library(library: &mut Vec<UserExample>, json: &str) -> Result<usize, String> {
+    let imported: Vec<UserExample> = serde_json::from_str(json)
+        .map_err(|err| format!("The examples file is not valid: {}", err))?;
+    let count = imported.len();
+    for example in imported {
+        upsert(library, example);
+    }
+    Ok(count)
+}
+
+/// Añade el ejemplo a la biblioteca o sustituye al que tiene el mismo nombre.
+pub fn upsert(library: &mut Vec<UserExample>, example: UserExample) {
+    match library
+        .iter_mut()
+        .find(|existing| existing.name == example.name)
+    {
+        Some(existing) => *existing = example,
+        None => library.push(example),
+    }
+}
@@ -0,0 +1,239 @@
+use crate::app::document_tabs::{DocumentTab, DocumentTabs};
+use crate::app::user_examples::{getStorageItem, setStorageItem};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Clave del almacenamiento local del navegador en la que se guarda el espacio de trabajo.
+const STORAGE_KEY: &str = "rdf-validator-workspace";
+
+/// Formatos RDF cuyos documentos se pueden unir concatenando su texto.
+const CONCATENABLE_FORMATS: [&str; 4] = ["Turtle", "N-Triples", "N-Quads", "TriG"];
+
+/// Panel de edición con pestañas.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Panel {
+    Rdf,
+    Shex,
+    ShapeMap,
+}
+
+impl Panel {
+    /// Prefijo de los identificadores HTML de las pestañas del panel.
+    pub fn id(&self) -> &'static str {
+        match self {
+            Panel::Rdf => "rdf",
+            Panel::Shex => "shex",
+            Panel::ShapeMap => "shapemap",
+        }
+    }
+}
+
+/// Espacio de trabajo: las pestañas de los tres paneles de edición.
+///
+/// # Campos
+/// * `rdf`, `shex`, `shapemap` - Pestañas de los paneles de datos, esquemas y ShapeMaps
+/// * `merge_data` - Indica si los datos de las pestañas incluidas se unen en un único documento o se validan por separado
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Workspace {
+    pub rdf: DocumentTabs,
+    pub shex: DocumentTabs,
+    pub shapemap: DocumentTabs,
+    #[serde(default)]
+    pub merge_data: bool,
+}
+
+impl Default for Workspace {
+    fn default() -> Self {
+        Workspace {
+            rdf: DocumentTabs::new("Data"),
+            shex: DocumentTabs::new("Schema"),
+            shapemap: DocumentTabs::new("ShapeMap"),
+            merge_data: true,
+        }
+    }
+}
+
+impl Workspace {
+    /// Pestañas del panel indicado.
+    pub fn tabs(&self, panel: Panel) -> &DocumentTabs {
+        match panel {
+            Panel::Rdf => &self.rdf,
+            Panel::Shex => &self.shex,
+            Panel::ShapeMap => &self.shapemap,
+        }
+    }
+
+    /// Pestañas del panel indicado, para modificarlas.
+    pub fn tabs_mut(&mut self, panel: Panel) -> &mut DocumentTabs {
+        match panel {
+            Panel::Rdf => &mut self.rdf,
+            Panel::Shex => &mut self.shex,
+            Panel::ShapeMap => &mut self.shapemap,
+        }
+    }
+
+    /// Lee el espacio de trabajo de la sesión anterior, si se guardó.
+    ///
+    /// Se descarta si alguno de sus paneles no tiene pestañas; la pestaña activa se corrige si no existe.
+    pub fn load() -> Option<Workspace> {
+        let workspace: Workspace =
+            getStorageItem(STORAGE_KEY).and_then(|json| serde_json::from_str(&json).ok())?;
+        Some(Workspace {
+            rdf: workspace.rdf.validated()?,
+            shex: workspace.shex.validated()?,
+            shapemap: workspace.shapemap.validated()?,
+            merge_data: workspace.merge_data,
+        })
+    }
+
+    /// Guarda el espacio de trabajo con el contenido actual de los editores en las pestañas activas.
+    ///
+    /// # Parámetros
+    /// * `rdf`, `shex`, `shapemap` - Contenido actual de cada editor.
+    pub fn save(&self, rdf: String, shex: String, shapemap: String) -> Result<(), String> {
+        let workspace = Workspace {
+            rdf: with_documents(&self.rdf, rdf),
+            shex: with_documents(&self.shex, shex),
+            shapemap: with_documents(&self.shapemap, shapemap),
+            merge_data: self.merge_data,
+        };
+        let json = serde_json::to_string(&workspace).unwrap_or_default();
+        if setStorageItem(STORAGE_KEY, &json) {
+            Ok(())
+        } else {
+            Err("The workspace could not be saved in the browser storage.".to_string())
+        }
+    }
+}
+
+/// Copia de las pestañas con el contenido actual del editor en la pestaña activa.
+fn with_documents(tabs: &DocumentTabs, current: String) -> DocumentTabs {
+    DocumentTabs {
+        tabs: tabs.documents(current),
+        ..tabs.clone()
+    }
+}
+
+/// Indica si los documentos en el formato RDF indicado se pueden unir concatenando su texto.
+pub fn can_merge_data(format: &str) -> bool {
+    CONCATENABLE_FORMATS.contains(&format)
+}
+
+/// Une los datos de varios documentos en uno solo.
+///
+/// Las etiquetas de nodos en blanco solo tienen sentido dentro de su documento, así que cuando se unen varios
+/// se renombran con la posición de su pestaña (`_:b1` pasa a ser `_:t2_b1`) para que no se confundan.
+pub fn merge_data(documents: &[DocumentTab]) -> String {
+    if documents.len() <= 1 {
+        return documents
+            .first()
+            .map(|document| document.content.trim_end().to_string())
+            .unwrap_or_default();
+    }
+    documents
+        .iter()
+        .enumerate()
+        .map(|(index, document)| {
+            rename_blank_nodes(document.content.trim_end(), &format!("t{}_", index + 1))
+        })
+        .collect::<Vec<String>>()
+        .join("\n\n")
+}
+
+/// Añade el prefijo indicado a las etiquetas de nodos en blanco (`_:etiqueta`) de un documento RDF.
+///
+/// Las IRIs, los literales y los comentarios se copian sin cambios.
+fn rename_blank_nodes(content: &str, prefix: &str) -> String {
+    let chars: Vec<char> = content.chars().collect();
+    let mut renamed = String::with_capacity(content.len());
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            '<' => {
+                let end = chars[i..]
+                    .iter()
+                    .position(|c| *c == '>' || c.is_whitespace())
+                    .map_or(chars.len(), |offset| i + offset + 1);
+                renamed.extend(&chars[i..end.min(chars.len())]);
+                i = end;
+            }
+            '#' => {
+                let end = chars[i..]
+                    .iter()
+                    .position(|c| *c == '\n')
+                    .map_or(chars.len(), |offset| i + offset);
+                renamed.extend(&chars[i..end]);
+                i = end;
+            }
+            quote @ ('"' | '\'') => {
+                let delimiter = if chars[i..].starts_with(&[quote, quote, quote]) {
+                    vec![quote; 3]
+                } else {
+                    vec![quote]
+                };
+                let mut end = i + delimiter.len();
+                while end < chars.len() && !chars[end..].starts_with(&delimiter) {
+                    end += if chars[end] == '\\' { 2 } else { 1 };
+                }
+                let end = (end + delimiter.len()).min(chars.len());
+                renamed.extend(&chars[i..end]);
+                i = end;
+            }
+            '_' if chars.get(i + 1) == Some(&':') && (i == 0 || !is_label_char(chars[i - 1])) => {
+                renamed.push_str("_:");
+                renamed.push_str(prefix);
+                i += 2;
+            }
+            c => {
+                renamed.push(c);
+                i += 1;
+            }
+        }
+    }
+    renamed
+}
+
+/// Indica si el carácter puede formar parte de un nombre prefijado, en cuyo caso `_:` no empieza un nodo en blanco.
+fn is_label_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | '-' | '.' | ':' | '%')
+}
+
+/// Une las asociaciones de varios ShapeMaps en uno solo.
+///
+/// Los ShapeMaps compactos se separan con comas; los ShapeMaps JSON se unen en una sola lista.
+///
+/// # Retorna
+/// Devuelve el ShapeMap unido o, si alguno de los ShapeMaps JSON no es una lista válida, un mensaje de error
+/// con el nombre de su pestaña.
+pub fn merge_shapemaps(documents: &[DocumentTab], format: &str) -> Result<String, String> {
+    let documents: Vec<&DocumentTab> = documents
+        .iter()
+        .filter(|document| !document.content.trim().is_empty())
+        .collect();
+    if documents.len() <= 1 {
+        return Ok(documents
+            .first()
+            .map(|document| document.content.trim().to_string())
+            .unwrap_or_default());
+    }
+    if format == "JSON" {
+        let mut associations: Vec<Value> = Vec::new();
+        for document in documents {
+            match serde_json::from_str::<Vec<Value>>(document.content.trim()) {
+                Ok(mut parsed) => associations.append(&mut parsed),
+                Err(error) => {
+                    return Err(format!(
+                        "The ShapeMap of tab '{}' is not a valid JSON ShapeMap: {}",
+                        document.name, error
+                    ))
+                }
+            }
+        }
+        return serde_json::to_string_pretty(&associations).map_err(|error| error.to_string());
+    }
+    Ok(documents
+        .iter()
+        .map(|document| document.content.trim().trim_end_matches(','))
+        .collect::<Vec<&str>>()
+        .join(",\n"))
+}
@@ -0,0 +1,108 @@
+use crate::app::schema_comparison::{ComparisonRow, SchemaComparison};
+use crate::components::result_table::exportCsv;
+use yew::prelude::*;
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct Props {
+    /// Resultados de la validación con cada versión del esquema.
+    pub comparison: SchemaComparison,
+}
+
+/// Componente `ComparisonMatrix` que muestra el estado de cada nodo con cada versión del esquema.
+pub struct ComparisonMatrix {
+    link: ComponentLink<Self>,
+    props: Props,
+    only_changes: bool,
+}
+
+/// Mensajes del componente `ComparisonMatrix`.
+pub enum Msg {
+    /// Muestra solo los nodos cuyo estado cambia entre versiones, o todos.
+    ToggleOnlyChanges,
+    /// Exporta la matriz a un archivo CSV.
+    ExportToCsv,
+}
+
+impl Component for ComparisonMatrix {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            props,
+            only_changes: false,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::ToggleOnlyChanges => {
+                self.only_changes = !self.only_changes;
+                true
+            }
+            Msg::ExportToCsv => {
+                exportCsv(&self.props.comparison.to_csv(), "comparison.csv");
+                false
+            }
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        self.props = props;
+        true
+    }
+
+    fn view(&self) -> Html {
+        let rows: Vec<ComparisonRow> = self
+            .props
+            .comparison
+            .rows()
+            .into_iter()
+            .filter(|row| !self.only_changes || row.differs())
+            .collect();
+
+        html! {
+            <div class="result" id="comparison">
+                <div class="table-controls">
+                    <label class="comparison-filter">
+                        <input id="comparison-only-changes" type="checkbox" checked=self.only_changes
+                            onclick=self.link.callback(|_| Msg::ToggleOnlyChanges) />
+                        { "Only nodes that change between versions" }
+                    </label>
+                    <button id="export-comparison" class="download-btn" onclick=self.link.callback(|_| Msg::ExportToCsv)> <i class="fas fa-download"></i></button>
+                </div>
+                { for self.props.comparison.errors().iter().map(|error| html! {
+                    <p class="comparison-error">{ error }</p>
+                }) }
+                <table id="comparison-matrix">
+                    <tr>
+                        <th>{"Node"}</th>
+                        <th>{"Shape"}</th>
+                        { for self.props.comparison.versions.iter().map(|version| html! { <th>{ version }</th> }) }
+                    </tr>
+                    { for rows.iter().map(|row| html! {
+                        <tr class={ if row.differs() { "comparison-changed" } else { "" } }>
+                            <td title=row.node_iri.clone()>{ &row.node }</td>
+                            <td title=row.shape_iri.clone()>{ &row.shape }</td>
+                            { for row.statuses.iter().map(|status| html! {
+                                <td class=format!("comparison-cell {}", status_class(status))>
+                                    { if status.is_empty() { "—" } else { status } }
+                                </td>
+                            }) }
+                        </tr>
+                    }) }
+                </table>
+            </div>
+        }
+    }
+}
+
+/// Clase CSS de la celda según el estado del nodo.
+fn status_class(status: &str) -> &'static str {
+    match status {
+        "Valid" => "valid",
+        "" => "missing",
+        _ => "invalid",
+    }
+}
@@ -1,6 +1,44 @@
-use crate::app::api::{self, InfoRdfResponse, InfoShexResponse};
+use crate::app::api::{
+    self, ConvertRdfResponse, ConvertShexResponse, InfoRdfResponse, InfoShexResponse,
+    InfoShexResult, Prefix, RequestError, ShapeMapEntry, ValidationParameters,
+};
+use crate::app::batch_validation::BatchFile;
+use crate::app::diagnostics::{self, Diagnostic};
+use crate::app::document_tabs::DocumentTab;
+use crate::app::format_detection;
+use crate::app::ntriples::{self, Term, Triple};
+use crate::app::query_shapemap::{self, ResolvedAssociation};
+use crate::app::rdf_statistics::RdfStatistics;
+use crate::app::request_policy::{RequestOptions, RequestPolicy};
+use crate::app::schema_diagram::{self, DiagramShape};
+use crate::app::schema_translation::{self, SchemaTranslation};
+use crate::app::shacl::{self, ShapesGraphInfo};
+use crate::app::shapemap::{self, ShapeLabel};
+use crate::app::shapemap_generator::{self, GenerationStrategy};
+use crate::app::shex_analysis::{self, ShexAnalysis};
+use crate::app::shexj::{self, StartShape};
+use crate::app::workspace::{self, Panel, Workspace};
+use crate::components::header::promptText;
+use crate::components::rdf_graph::RdfGraphView;
+use crate::components::result_table::exportCsv;
+use crate::components::schema_diagram::{exportText, SchemaDiagram};
+use crate::components::shapemap_editor::ShapeMapEditor;
+use std::time::Duration;
 use wasm_bindgen::prelude::*;
 use yew::prelude::*;
+use yew::services::reader::File;
+use yew::services::timeout::{TimeoutService, TimeoutTask};
+
+/// Lee el texto de un fichero seleccionado por el usuario
+#[wasm_bindgen(inline_js = "
+export function readFileText(file) {
+    return file.text();
+}
+")]
+extern "C" {
+    #[wasm_bindgen(catch)]
+    async fn readFileText(file: &File) -> Result<JsValue, JsValue>;
+}
 
 /// Obtiene el valor actual del editor YATE.
 #[wasm_bindgen(inline_js = "
@@ -22,6 +60,90 @@ extern "C" {
     fn getYashe() -> String;
 }
 
+/// Asigna un nuevo valor al editor YATE.
+#[wasm_bindgen(inline_js = "
+export function setYate(input) {
+    return window.yateInstance.setValue(input);
+}
+")]
+extern "C" {
+    fn setYate(input: &str);
+}
+
+/// Asigna un nuevo valor al editor YASHE.
+#[wasm_bindgen(inline_js = "
+export function setYashe(input) {
+    return window.yasheInstance.setValue(input);
+}
+")]
+extern "C" {
+    fn setYashe(input: &str);
+}
+
+/// Registra un callback que se ejecuta cuando se pega contenido en el editor indicado.
+#[wasm_bindgen(inline_js = "
+export function onEditorPaste(editor, callback) {
+    const instance = editor === 'yate' ? window.yateInstance : window.yasheInstance;
+    instance.on('change', (cm, change) => {
+        if (change.origin === 'paste') {
+            callback();
+        }
+    });
+}
+")]
+extern "C" {
+    fn onEditorPaste(editor: &str, callback: &Closure<dyn FnMut()>);
+}
+
+/// Registra un callback que se ejecuta cada vez que cambia el contenido del editor indicado.
+#[wasm_bindgen(inline_js = "
+export function onEditorChange(editor, callback) {
+    const instance = editor === 'yate' ? window.yateInstance : window.yasheInstance;
+    instance.on('change', () => callback());
+}
+")]
+extern "C" {
+    fn onEditorChange(editor: &str, callback: &Closure<dyn FnMut()>);
+}
+
+/// Muestra los errores de sintaxis como marcadores en el margen del editor indicado.
+#[wasm_bindgen(inline_js = "
+export function setEditorDiagnostics(editor, diagnostics) {
+    const instance = editor === 'yate' ? window.yateInstance : window.yasheInstance;
+    const gutters = instance.getOption('gutters') || [];
+    if (!gutters.includes('diagnostics-gutter')) {
+        instance.setOption('gutters', gutters.concat(['diagnostics-gutter']));
+    }
+    instance.clearGutter('diagnostics-gutter');
+    JSON.parse(diagnostics).forEach((diagnostic) => {
+        if (diagnostic.line === null || diagnostic.line > instance.lineCount()) {
+            return;
+        }
+        const marker = document.createElement('span');
+        marker.className = 'diagnostics-marker';
+        marker.title = diagnostic.message;
+        marker.textContent = '●';
+        instance.setGutterMarker(diagnostic.line - 1, 'diagnostics-gutter', marker);
+    });
+}
+")]
+extern "C" {
+    fn setEditorDiagnostics(editor: &str, diagnostics: &str);
+}
+
+/// Sitúa el cursor del editor YASHE al comienzo de la línea indicada y la muestra.
+#[wasm_bindgen(inline_js = "
+export function goToYasheLine(line) {
+    const instance = window.yasheInstance;
+    instance.setCursor({ line: line - 1, ch: 0 });
+    instance.scrollIntoView({ line: line - 1, ch: 0 }, 100);
+    instance.focus();
+}
+")]
+extern "C" {
+    fn goToYasheLine(line: usize);
+}
+
 /// Inicializa el editor YATE en la página.
 #[wasm_bindgen(inline_js = "
 import YATE from 'perfectkb-yate';
@@ -50,17 +172,33 @@ extern "C" {
 #[derive(Properties, Clone, PartialEq)]
 pub struct Props {
     pub shapemap_value: String,
+    /// Configuración de las solicitudes a la API.
+    pub request_policy: RequestPolicy,
     pub rdf_format: String,
     pub shex_format: String,
     pub shapemap_format: String,
     pub on_update_shapemap_value: Callback<String>,
-    pub on_validate: Callback<(String, String, String)>,
+    /// `Callback` de la validación: datos RDF que se validan (los de las pestañas incluidas) y sus parámetros.
+    pub on_validate: Callback<(String, ValidationParameters)>,
+    /// `Callback` de la validación por lotes: ficheros de datos (nombre, contenido y formato) y los mismos
+    /// parámetros que `on_validate`.
+    pub on_validate_batch: Callback<(Vec<BatchFile>, ValidationParameters)>,
+    /// `Callback` de la comparación de versiones del esquema: documentos de las pestañas incluidas del panel ShEx,
+    /// datos RDF y los mismos parámetros que `on_validate`.
+    pub on_compare_schemas: Callback<(Vec<DocumentTab>, String, ValidationParameters)>,
     pub on_open_modal: Callback<(String, Html)>,
     pub rdf_parameters: Vec<String>,
+    pub rdf_conversion_parameters: Vec<String>,
     pub shex_parameters: Vec<String>,
+    pub shex_conversion_parameters: Vec<String>,
     pub shapemap_parameters: Vec<String>,
+    pub inference_parameters: Vec<String>,
+    pub engine_parameters: Vec<String>,
     pub example_loaded: bool,
     pub reset_example_loaded: Callback<()>,
+    /// Entradas del último resultado de validación, para colorear el grafo de los datos RDF.
+    #[prop_or_default]
+    pub validation_entries: Vec<ShapeMapEntry>,
 }
 
 /// Componente `Editor` que maneja editores de texto para RDF, ShEx y ShapeMap.
@@ -70,7 +208,39 @@ pub struct Editor {
     analyzer_error: bool,
     rdf_param_selected: String,
     shex_param_selected: String,
+    /// Formato ShEx seleccionado antes de cambiar a un motor SHACL, para restaurarlo al volver a ShEx.
+    shex_format_before_shacl: Option<String>,
     shapemap_param_selected: String,
+    rdf_format_chosen: bool,
+    shex_format_chosen: bool,
+    shapemap_format_chosen: bool,
+    inference_selected: String,
+    engine_selected: String,
+    rdf_convert_selected: String,
+    shex_convert_selected: String,
+    format_notice: String,
+    rdf_diagnostics: Vec<Diagnostic>,
+    shex_diagnostics: Vec<Diagnostic>,
+    shapemap_diagnostics: Vec<Diagnostic>,
+    diagnostics_task: Option<TimeoutTask>,
+    diagnostics_generation: u32,
+    rdf_check_error: Option<String>,
+    shex_check_error: Option<String>,
+    rdf_triples: Vec<Triple>,
+    triples_source: Option<(String, String)>,
+    schema_info: InfoShexResult,
+    start_shape: Option<StartShape>,
+    start_shape_source: Option<(String, String)>,
+    shapemap_preview_count: Option<usize>,
+    unmatched_selectors: Vec<String>,
+    shapemap_merge_error: Option<String>,
+    generation_selected: String,
+    batch_files: Vec<BatchFile>,
+    batch_errors: Vec<(String, String)>,
+    batch_pending: usize,
+    batch_generation: u32,
+    workspace: Workspace,
+    restore_workspace: bool,
 }
 
 /// Mensajes internos del componente para manejar la lógica de la interfaz.
@@ -83,16 +253,94 @@ pub enum Msg {
     AnalyzeRDF,
     /// Lanza proceso de análisis dada la entrada ShEx
     AnalyzeShex,
-    /// Recibe respuesta del proceso de análisis ShEx
-    ReceiveShexAnalysis((InfoShexResponse, String)),
-    /// Recibe respuesta del proceso de análisis RDF
-    ReceiveRDFAnalysis((InfoRdfResponse, String)),
+    /// Recibe respuesta del proceso de análisis ShEx junto con la forma inicial y el análisis detallado del esquema
+    ReceiveShexAnalysis((InfoShexResponse, String), Option<StartShape>, ShexAnalysis),
+    /// Recibe respuesta del proceso de análisis RDF junto con las tripletas de los datos para las estadísticas y el grafo
+    ReceiveRDFAnalysis((InfoRdfResponse, String), Vec<Triple>),
     /// Actualiza formato seleccionado para RDF
     UpdateRdfParamSelected(String),
     /// Actualiza formato seleccionado para ShEx
     UpdateShexParamSelected(String),
     /// Actualiza formato seleccionado para ShapeMap
     UpdateShapeMapParamSelected(String),
+    /// Actualiza el régimen de inferencia seleccionado para los datos RDF
+    UpdateInferenceSelected(String),
+    /// Actualiza el motor de validación seleccionado (ShEx o SHACL)
+    UpdateEngineSelected(String),
+    /// Recibe el análisis del grafo de formas SHACL o el error de sintaxis
+    ReceiveShaclAnalysis(Result<ShapesGraphInfo, String>),
+    /// Traduce el esquema de ShEx a SHACL o de SHACL a ShEx
+    TranslateSchema,
+    /// Recibe la traducción del esquema o el mensaje de error
+    ReceiveSchemaTranslation(Result<SchemaTranslation, String>),
+    /// Construye el diagrama de las formas del esquema ShEx
+    ShowSchemaDiagram,
+    /// Recibe las formas del diagrama o el mensaje de error
+    ReceiveSchemaDiagram(Result<Vec<DiagramShape>, String>),
+    /// Actualiza formato de destino seleccionado para la conversión RDF
+    UpdateRdfConvertSelected(String),
+    /// Lanza proceso de conversión de la entrada RDF al formato de destino seleccionado
+    ConvertRDF,
+    /// Recibe respuesta del proceso de conversión RDF junto con el formato de destino
+    ReceiveRDFConversion((ConvertRdfResponse, String), String),
+    /// Actualiza formato de destino seleccionado para la conversión ShEx
+    UpdateShexConvertSelected(String),
+    /// Lanza proceso de conversión de la entrada ShEx al formato de destino seleccionado
+    ConvertShex,
+    /// Recibe respuesta del proceso de conversión ShEx junto con el formato de destino
+    ReceiveShexConversion((ConvertShexResponse, String), String),
+    /// Detecta el formato de las entradas y ajusta los selectores si no coinciden
+    DetectFormats,
+    /// Programa una comprobación de sintaxis tras un periodo sin cambios en las entradas
+    ScheduleSyntaxCheck,
+    /// Lanza la comprobación de sintaxis de todas las entradas
+    CheckSyntax,
+    /// Recibe el resultado de la comprobación indicada de la sintaxis RDF
+    ReceiveRdfDiagnostics(u32, Result<(), RequestError>),
+    /// Recibe el análisis ShEx de la comprobación indicada, o el error por el que no se ha podido obtener
+    ReceiveShexDiagnostics(u32, Result<InfoShexResult, RequestError>),
+    /// Carga los nodos de los datos y la forma inicial del esquema que se sugieren al editar el ShapeMap
+    LoadSuggestions,
+    /// Recibe los datos RDF (contenido y formato) convertidos a N-Triples para las sugerencias
+    ReceiveRdfTriples((String, String), (ConvertRdfResponse, String)),
+    /// Recibe la forma inicial del esquema (contenido y formato) para las sugerencias
+    ReceiveStartShape((String, String), Option<StartShape>),
+    /// Expande los selectores del ShapeMap a los nodos concretos de los datos
+    PreviewShapeMap,
+    /// Recibe los selectores expandidos junto con los prefijos usados para mostrarlos
+    ReceiveShapeMapPreview(Result<Vec<ResolvedAssociation>, String>, Vec<Prefix>),
+    /// Recibe el ShapeMap fijo con el que se lanza la validación de un ShapeMap de consulta, junto con los
+    /// selectores que no seleccionan ningún nodo
+    ReceiveResolvedShapeMap(String, Vec<String>),
+    /// Actualiza el criterio seleccionado para generar el ShapeMap
+    UpdateGenerationSelected(String),
+    /// Genera un ShapeMap a partir de los datos RDF y las formas del esquema
+    GenerateShapeMap,
+    /// Recibe el ShapeMap generado o el motivo por el que no se ha podido generar
+    ReceiveGeneratedShapeMap(Result<String, String>),
+    /// Lee los ficheros de datos seleccionados para la validación por lotes
+    SelectBatchFiles(Vec<File>),
+    /// Recibe el contenido de uno de los ficheros de datos del lote, o el error por el que no se ha podido leer,
+    /// junto con la selección de ficheros a la que pertenece y su nombre
+    ReceiveBatchFile(u32, String, Result<String, String>),
+    /// Quita los ficheros de datos del lote
+    ClearBatchFiles,
+    /// Lanza la validación por lotes de los ficheros de datos con el esquema y el ShapeMap actuales
+    ValidateBatch,
+    /// Activa la pestaña indicada de un panel
+    SelectTab(Panel, usize),
+    /// Añade una pestaña vacía a un panel
+    AddTab(Panel),
+    /// Pide un nuevo nombre para la pestaña indicada de un panel
+    RenameTab(Panel, usize),
+    /// Cierra la pestaña indicada de un panel
+    CloseTab(Panel, usize),
+    /// Incluye o excluye de la validación el documento de la pestaña indicada de un panel
+    ToggleTabIncluded(Panel, usize),
+    /// Alterna entre unir los datos de las pestañas incluidas o validarlos por separado
+    ToggleMergeData,
+    /// Valida los datos con el esquema de cada pestaña incluida del panel ShEx
+    CompareSchemas,
 }
 
 impl Component for Editor {
@@ -104,6 +352,23 @@ impl Component for Editor {
         let rdf_param_selected = props.rdf_format.clone();
         let shex_param_selected = props.shex_format.clone();
         let shapemap_param_selected = props.shapemap_format.clone();
+        let inference_selected = props
+            .inference_parameters
+            .first()
+            .cloned()
+            .unwrap_or_default();
+        let engine_selected = props.engine_parameters.first().cloned().unwrap_or_default();
+        let rdf_convert_selected = props
+            .rdf_conversion_parameters
+            .first()
+            .cloned()
+            .unwrap_or_default();
+        let shex_convert_selected = props
+            .shex_conversion_parameters
+            .first()
+            .cloned()
+            .unwrap_or_default();
+        let workspace = Workspace::load();
 
         Self {
             link,
@@ -111,7 +376,38 @@ impl Component for Editor {
             analyzer_error: false,
             rdf_param_selected,
             shex_param_selected,
+            shex_format_before_shacl: None,
             shapemap_param_selected,
+            rdf_format_chosen: false,
+            shex_format_chosen: false,
+            shapemap_format_chosen: false,
+            inference_selected,
+            engine_selected,
+            rdf_convert_selected,
+            shex_convert_selected,
+            format_notice: String::new(),
+            rdf_diagnostics: Vec::new(),
+            shex_diagnostics: Vec::new(),
+            shapemap_diagnostics: Vec::new(),
+            diagnostics_task: None,
+            diagnostics_generation: 0,
+            rdf_check_error: None,
+            shex_check_error: None,
+            rdf_triples: Vec::new(),
+            triples_source: None,
+            schema_info: Default::default(),
+            start_shape: None,
+            start_shape_source: None,
+            shapemap_preview_count: None,
+            unmatched_selectors: Vec::new(),
+            shapemap_merge_error: None,
+            generation_selected: GENERATE_BY_TYPE.to_string(),
+            batch_files: Vec::new(),
+            batch_errors: Vec::new(),
+            batch_pending: 0,
+            batch_generation: 0,
+            restore_workspace: workspace.is_some(),
+            workspace: workspace.unwrap_or_default(),
         }
     }
 
@@ -119,32 +415,557 @@ impl Component for Editor {
     fn update(&mut self, msg: Self::Message) -> ShouldRender {
         match msg {
             Msg::UpdateShapeMapValue(value) => {
+                let changed = self.apply_detected_shapemap_format(&value);
+                let had_preview = self.shapemap_preview_count.take().is_some()
+                    || !self.unmatched_selectors.is_empty();
+                self.unmatched_selectors.clear();
                 self.props.on_update_shapemap_value.emit(value);
+                self.link.send_message(Msg::ScheduleSyntaxCheck);
+                self.link.send_message(Msg::LoadSuggestions);
+                changed || had_preview
+            }
+            Msg::ScheduleSyntaxCheck => {
+                self.diagnostics_task = Some(TimeoutService::spawn(
+                    Duration::from_millis(800),
+                    self.link.callback(|_| Msg::CheckSyntax),
+                ));
                 false
             }
+            Msg::CheckSyntax => {
+                self.diagnostics_task = None;
+                self.save_workspace();
+                self.diagnostics_generation += 1;
+                let generation = self.diagnostics_generation;
+
+                self.shapemap_diagnostics = diagnostics::check_shapemap(
+                    &self.props.shapemap_value,
+                    &self.shapemap_param_selected,
+                );
+
+                let rdf = getYate();
+                if rdf.trim().is_empty() {
+                    self.link
+                        .send_message(Msg::ReceiveRdfDiagnostics(generation, Ok(())));
+                } else {
+                    let link = self.link.clone();
+                    let options = self.request_options();
+                    let rdf_param_selected = self.rdf_param_selected.clone();
+                    wasm_bindgen_futures::spawn_local(async move {
+                        // La comprobación de sintaxis no necesita inferencia
+                        let result = api::request_rdf_info(
+                            rdf,
+                            rdf_param_selected,
+                            "NONE".to_string(),
+                            &options,
+                        )
+                        .await
+                        .map(|_| ());
+                        link.send_message(Msg::ReceiveRdfDiagnostics(generation, result));
+                    });
+                }
+
+                let shex = getYashe();
+                if shex.trim().is_empty() {
+                    self.link.send_message(Msg::ReceiveShexDiagnostics(
+                        generation,
+                        Ok(Default::default()),
+                    ));
+                } else {
+                    let link = self.link.clone();
+                    let options = self.request_options();
+                    let shex_param_selected = self.shex_param_selected.clone();
+                    let is_shex = self.is_shex();
+                    wasm_bindgen_futures::spawn_local(async move {
+                        // Los grafos de formas SHACL son RDF, por lo que se comprueban como datos
+                        let result = if is_shex {
+                            api::request_shex_info(shex, shex_param_selected, &options)
+                                .await
+                                .map(|response| response.result)
+                        } else {
+                            api::request_rdf_info(
+                                shex,
+                                shex_param_selected,
+                                "NONE".to_string(),
+                                &options,
+                            )
+                            .await
+                            .map(|_| Default::default())
+                        };
+                        link.send_message(Msg::ReceiveShexDiagnostics(generation, result));
+                    });
+                }
+                true
+            }
+            Msg::ReceiveRdfDiagnostics(generation, result) => {
+                if generation != self.diagnostics_generation {
+                    return false;
+                }
+                let (diagnostics, check_error) = split_check_result(result);
+                self.rdf_diagnostics = diagnostics;
+                self.rdf_check_error = check_error;
+                setEditorDiagnostics("yate", &diagnostics_json(&self.rdf_diagnostics));
+                true
+            }
+            Msg::ReceiveShexDiagnostics(generation, result) => {
+                if generation != self.diagnostics_generation {
+                    return false;
+                }
+                let schema_info = result.as_ref().ok().cloned();
+                let (diagnostics, check_error) = split_check_result(result.map(|_| ()));
+                self.shex_diagnostics = diagnostics;
+                self.shex_check_error = check_error;
+                setEditorDiagnostics("yashe", &diagnostics_json(&self.shex_diagnostics));
+                if let Some(schema_info) = schema_info {
+                    self.schema_info = schema_info;
+                }
+                true
+            }
+            Msg::LoadSuggestions => {
+                let rdf_source = (getYate(), self.rdf_param_selected.clone());
+                if self.triples_source.as_ref() != Some(&rdf_source) {
+                    self.triples_source = Some(rdf_source.clone());
+                    if rdf_source.0.trim().is_empty() {
+                        self.rdf_triples.clear();
+                    } else {
+                        let link = self.link.clone();
+                        let options = self.request_options();
+                        wasm_bindgen_futures::spawn_local(async move {
+                            let result = api::call_rdf_convert_api(
+                                rdf_source.0.clone(),
+                                rdf_source.1.clone(),
+                                "N-Triples".to_string(),
+                                &options,
+                            )
+                            .await;
+                            link.send_message(Msg::ReceiveRdfTriples(rdf_source, result));
+                        });
+                    }
+                }
+
+                let shex_source = (getYashe(), self.shex_param_selected.clone());
+                if self.is_shex() && self.start_shape_source.as_ref() != Some(&shex_source) {
+                    self.start_shape_source = Some(shex_source.clone());
+                    if shex_source.0.trim().is_empty() {
+                        self.start_shape = None;
+                    } else {
+                        let link = self.link.clone();
+                        let options = self.request_options();
+                        wasm_bindgen_futures::spawn_local(async move {
+                            let start_shape = shexj::load_start_shape(
+                                shex_source.0.clone(),
+                                shex_source.1.clone(),
+                                &options,
+                            )
+                            .await;
+                            link.send_message(Msg::ReceiveStartShape(shex_source, start_shape));
+                        });
+                    }
+                }
+                false
+            }
+            Msg::ReceiveRdfTriples(source, result) => {
+                if self.triples_source.as_ref() != Some(&source) || !result.1.is_empty() {
+                    return false;
+                }
+                self.rdf_triples = ntriples::parse_ntriples(&result.0.result.content);
+                true
+            }
+            Msg::ReceiveStartShape(source, start_shape) => {
+                if self.start_shape_source.as_ref() != Some(&source) {
+                    return false;
+                }
+                self.start_shape = start_shape;
+                true
+            }
+            Msg::DetectFormats => {
+                let shapemap = self.props.shapemap_value.clone();
+                let rdf_changed = self.apply_detected_rdf_format();
+                let shex_changed = self.apply_detected_shex_format();
+                let shapemap_changed = self.apply_detected_shapemap_format(&shapemap);
+                rdf_changed || shex_changed || shapemap_changed
+            }
             Msg::Validate => {
+                self.apply_detected_rdf_format();
+                self.apply_detected_shex_format();
+                self.apply_detected_shapemap_format(&self.props.shapemap_value.clone());
+                let shapemap = match self.validation_shapemap() {
+                    Some(shapemap) => shapemap,
+                    None => return true,
+                };
+
+                // SHACL usa las declaraciones de objetivos del grafo de formas en lugar del ShapeMap
+                let is_query_shapemap = self.is_shex()
+                    && self.shapemap_param_selected == "Compact"
+                    && shapemap::parse_shapemap(&shapemap).is_ok_and(|associations| {
+                        query_shapemap::has_query_selectors(&associations)
+                    });
+                if !is_query_shapemap {
+                    self.link
+                        .send_message(Msg::ReceiveResolvedShapeMap(shapemap, Vec::new()));
+                    return true;
+                }
+
+                let link = self.link.clone();
+                let options = self.request_options();
                 let rdf_param_selected = self.rdf_param_selected.clone();
                 let shex_param_selected = self.shex_param_selected.clone();
-                let shapemap_param_selected = self.shapemap_param_selected.clone();
-
-                self.props.on_validate.emit((
-                    rdf_param_selected,
-                    shex_param_selected,
-                    shapemap_param_selected,
+                let inference_selected = self.inference_selected.clone();
+                let rdf = self.validation_data();
+                wasm_bindgen_futures::spawn_local(async move {
+                    let schema_prefixes =
+                        api::call_shex_info_api(getYashe(), shex_param_selected, &options)
+                            .await
+                            .0
+                            .result
+                            .prefix_map;
+                    let prefixes = query_shapemap::shapemap_prefixes(&rdf, schema_prefixes);
+                    let resolved = query_shapemap::resolve_shapemap(
+                        shapemap.clone(),
+                        rdf,
+                        rdf_param_selected,
+                        inference_selected,
+                        prefixes,
+                        &options,
+                    )
+                    .await;
+                    // Si los selectores no se pueden expandir, el servidor recibe el ShapeMap original
+                    let (content, unmatched) = match resolved {
+                        Ok(resolved) => (
+                            query_shapemap::to_fixed_shapemap(&resolved),
+                            query_shapemap::unmatched_selectors(&resolved),
+                        ),
+                        Err(_) => (shapemap, Vec::new()),
+                    };
+                    link.send_message(Msg::ReceiveResolvedShapeMap(content, unmatched));
+                });
+                true
+            }
+            Msg::ReceiveResolvedShapeMap(shapemap, unmatched) => {
+                self.unmatched_selectors = unmatched;
+                // Si ningún selector selecciona nodos no hay nada que validar; el aviso lo explica
+                if shapemap.trim().is_empty() && !self.unmatched_selectors.is_empty() {
+                    return true;
+                }
+                let documents = self.workspace.rdf.participating(getYate());
+                let parameters = self.validation_parameters(shapemap);
+                // Los datos que no se pueden unir (o si así se ha elegido) se validan como un lote
+                if documents.len() > 1
+                    && !(self.workspace.merge_data
+                        && workspace::can_merge_data(&self.rdf_param_selected))
+                {
+                    self.props.on_validate_batch.emit((
+                        documents
+                            .into_iter()
+                            .map(|document| BatchFile {
+                                name: document.name,
+                                content: document.content,
+                                format: parameters.rdf_format.clone(),
+                            })
+                            .collect(),
+                        parameters,
+                    ));
+                } else {
+                    self.props
+                        .on_validate
+                        .emit((workspace::merge_data(&documents), parameters));
+                }
+                true
+            }
+            Msg::SelectBatchFiles(files) => {
+                self.batch_files.clear();
+                self.batch_errors.clear();
+                self.batch_generation += 1;
+                self.batch_pending = files.len();
+                for file in files {
+                    let generation = self.batch_generation;
+                    let link = self.link.clone();
+                    wasm_bindgen_futures::spawn_local(async move {
+                        let result = readFileText(&file)
+                            .await
+                            .map(|text| text.as_string().unwrap_or_default())
+                            .map_err(|error| {
+                                error
+                                    .as_string()
+                                    .unwrap_or_else(|| "The file could not be read.".to_string())
+                            });
+                        link.send_message(Msg::ReceiveBatchFile(generation, file.name(), result));
+                    });
+                }
+                true
+            }
+            Msg::ReceiveBatchFile(generation, name, result) => {
+                if generation != self.batch_generation {
+                    return false;
+                }
+                self.batch_pending = self.batch_pending.saturating_sub(1);
+                match result {
+                    Ok(content) => {
+                        // Cada fichero se valida con su propio formato; si no se reconoce, con el seleccionado
+                        let format = format_detection::detect_rdf_format(&content)
+                            .unwrap_or_else(|| self.rdf_param_selected.clone());
+                        self.batch_files.push(BatchFile {
+                            name,
+                            content,
+                            format,
+                        });
+                    }
+                    Err(error) => self.batch_errors.push((name, error)),
+                }
+                if self.batch_pending == 0 {
+                    self.batch_files.sort_by(|a, b| a.name.cmp(&b.name));
+                    self.batch_errors.sort();
+                }
+                true
+            }
+            Msg::ClearBatchFiles => {
+                self.batch_files.clear();
+                self.batch_errors.clear();
+                self.batch_generation += 1;
+                self.batch_pending = 0;
+                true
+            }
+            Msg::ValidateBatch => {
+                self.apply_detected_shex_format();
+                self.apply_detected_shapemap_format(&self.props.shapemap_value.clone());
+                let parameters = match self.validation_shapemap() {
+                    Some(shapemap) => self.validation_parameters(shapemap),
+                    None => return true,
+                };
+                self.props
+                    .on_validate_batch
+                    .emit((self.batch_files.clone(), parameters));
+                true
+            }
+            Msg::SelectTab(panel, index) => {
+                let current = self.editor_content(panel);
+                if let Some(content) = self.workspace.tabs_mut(panel).select(index, current) {
+                    self.set_editor_content(panel, &content);
+                }
+                self.save_workspace();
+                true
+            }
+            Msg::AddTab(panel) => {
+                let current = self.editor_content(panel);
+                self.workspace.tabs_mut(panel).add(current);
+                self.set_editor_content(panel, "");
+                self.save_workspace();
+                true
+            }
+            Msg::RenameTab(panel, index) => {
+                if let Some(tab) = self.workspace.tabs(panel).tabs.get(index) {
+                    if let Some(name) = promptText("New name for the tab", &tab.name) {
+                        self.workspace.tabs_mut(panel).rename(index, &name);
+                        self.save_workspace();
+                    }
+                }
+                true
+            }
+            Msg::CloseTab(panel, index) => {
+                if let Some(content) = self.workspace.tabs_mut(panel).close(index) {
+                    self.set_editor_content(panel, &content);
+                }
+                self.save_workspace();
+                true
+            }
+            Msg::ToggleTabIncluded(panel, index) => {
+                self.workspace.tabs_mut(panel).toggle_included(index);
+                self.save_workspace();
+                true
+            }
+            Msg::ToggleMergeData => {
+                self.workspace.merge_data = !self.workspace.merge_data;
+                self.save_workspace();
+                true
+            }
+            Msg::CompareSchemas => {
+                self.apply_detected_rdf_format();
+                self.apply_detected_shapemap_format(&self.props.shapemap_value.clone());
+                let parameters = match self.validation_shapemap() {
+                    Some(shapemap) => self.validation_parameters(shapemap),
+                    None => return true,
+                };
+                self.props.on_compare_schemas.emit((
+                    self.workspace.shex.participating(getYashe()),
+                    self.validation_data(),
+                    parameters,
                 ));
-                false
+                true
+            }
+            Msg::UpdateGenerationSelected(value) => {
+                self.generation_selected = value;
+                true
+            }
+            Msg::GenerateShapeMap => {
+                let changed = self.apply_detected_rdf_format();
+                let strategy = match self.generation_selected.as_str() {
+                    GENERATE_BY_TYPE => GenerationStrategy::TypeMatching,
+                    "START" => GenerationStrategy::AllSubjects(ShapeLabel::Start),
+                    shape => GenerationStrategy::AllSubjects(ShapeLabel::Label(shape.to_string())),
+                };
+                let link = self.link.clone();
+                let options = self.request_options();
+                let rdf_param_selected = self.rdf_param_selected.clone();
+                let shex_param_selected = self.shex_param_selected.clone();
+
+                wasm_bindgen_futures::spawn_local(async move {
+                    let (rdf_response, rdf_error) = api::call_rdf_convert_api(
+                        getYate(),
+                        rdf_param_selected,
+                        "N-Triples".to_string(),
+                        &options,
+                    )
+                    .await;
+                    let (shex_response, shex_error) =
+                        api::call_shex_info_api(getYashe(), shex_param_selected, &options).await;
+
+                    let result = if !rdf_error.is_empty() {
+                        Err("Status: RDF is not well-formed.".to_string())
+                    } else if !shex_error.is_empty() {
+                        Err("Status: Schema is not well-formed.".to_string())
+                    } else {
+                        let schema = shex_response.result;
+                        // Las formas elegidas en el selector se muestran ya acortadas
+                        let strategy = match strategy {
+                            GenerationStrategy::AllSubjects(ShapeLabel::Label(shape)) => {
+                                GenerationStrategy::AllSubjects(ShapeLabel::Label(
+                                    shapemap::compact_iri(&shape, &schema.prefix_map),
+                                ))
+                            }
+                            strategy => strategy,
+                        };
+                        Ok(shapemap_generator::generate_shapemap(
+                            &ntriples::parse_ntriples(&rdf_response.result.content),
+                            &schema.shapes,
+                            &schema.prefix_map,
+                            &strategy,
+                        ))
+                    };
+                    link.send_message(Msg::ReceiveGeneratedShapeMap(result));
+                });
+                changed
+            }
+            Msg::ReceiveGeneratedShapeMap(result) => match result {
+                Ok(generated) if !generated.is_empty() => {
+                    self.shapemap_param_selected = "Compact".to_string();
+                    self.link.send_message(Msg::UpdateShapeMapValue(generated));
+                    true
+                }
+                result => {
+                    let status = result.err().unwrap_or_else(|| {
+                        "Status: No associations could be proposed. Choose a shape to associate all subjects with it.".to_string()
+                    });
+                    self.props.on_open_modal.emit((
+                        "SHAPEMAP GENERATION".to_string(),
+                        html! { <p>{ status }</p> },
+                    ));
+                    false
+                }
+            },
+            Msg::PreviewShapeMap => {
+                let shapemap = self.props.shapemap_value.clone();
+                let changed = self.apply_detected_rdf_format();
+                if self.shapemap_param_selected != "Compact" {
+                    self.link.send_message(Msg::ReceiveShapeMapPreview(
+                        Err("The preview is only available for Compact ShapeMaps.".to_string()),
+                        Vec::new(),
+                    ));
+                    return changed;
+                }
+
+                let link = self.link.clone();
+                let options = self.request_options();
+                let rdf_param_selected = self.rdf_param_selected.clone();
+                let shex_param_selected = self.shex_param_selected.clone();
+                let inference_selected = self.inference_selected.clone();
+                wasm_bindgen_futures::spawn_local(async move {
+                    let rdf = getYate();
+                    let schema_prefixes =
+                        api::call_shex_info_api(getYashe(), shex_param_selected, &options)
+                            .await
+                            .0
+                            .result
+                            .prefix_map;
+                    let prefixes = query_shapemap::shapemap_prefixes(&rdf, schema_prefixes);
+                    let resolved = query_shapemap::resolve_shapemap(
+                        shapemap,
+                        rdf,
+                        rdf_param_selected,
+                        inference_selected,
+                        prefixes.clone(),
+                        &options,
+                    )
+                    .await;
+                    link.send_message(Msg::ReceiveShapeMapPreview(resolved, prefixes));
+                });
+                changed
+            }
+            Msg::ReceiveShapeMapPreview(result, prefixes) => {
+                let content = match result {
+                    Err(error) => {
+                        self.shapemap_preview_count = None;
+                        html! {
+                            <>
+                                <p>{"Status: ShapeMap selectors could not be expanded."}</p>
+                                <p>{ error }</p>
+                            </>
+                        }
+                    }
+                    Ok(resolved) => {
+                        let count = resolved
+                            .iter()
+                            .map(|association| association.nodes.len())
+                            .sum();
+                        self.shapemap_preview_count = Some(count);
+                        html! {
+                            <>
+                                <p id="shapemap-preview-total">{format!("Nodes to check: {}", count)}</p>
+                                { for resolved.iter().map(|association| view_resolved_association(association, &prefixes)) }
+                            </>
+                        }
+                    }
+                };
+
+                self.props
+                    .on_open_modal
+                    .emit(("SHAPEMAP PREVIEW".to_string(), content));
+                true
             }
             Msg::AnalyzeRDF => {
+                let changed = self.apply_detected_rdf_format();
                 let link = self.link.clone();
+                let options = self.request_options();
                 let rdf_param_selected = self.rdf_param_selected.clone();
+                let inference_selected = self.inference_selected.clone();
 
                 wasm_bindgen_futures::spawn_local(async move {
-                    let content = api::call_rdf_info_api(getYate(), rdf_param_selected).await;
-                    link.send_message(Msg::ReceiveRDFAnalysis(content));
+                    let content = api::call_rdf_info_api(
+                        getYate(),
+                        rdf_param_selected.clone(),
+                        inference_selected,
+                        &options,
+                    )
+                    .await;
+                    let triples = if content.1.is_empty() {
+                        let (response, error) = api::call_rdf_convert_api(
+                            getYate(),
+                            rdf_param_selected,
+                            "N-Triples".to_string(),
+                            &options,
+                        )
+                        .await;
+                        if error.is_empty() {
+                            ntriples::parse_ntriples(&response.result.content)
+                        } else {
+                            Vec::new()
+                        }
+                    } else {
+                        Vec::new()
+                    };
+                    link.send_message(Msg::ReceiveRDFAnalysis(content, triples));
                 });
-                false
+                changed
             }
-            Msg::ReceiveRDFAnalysis(result) => {
+            Msg::ReceiveRDFAnalysis(result, triples) => {
                 let content = if !result.1.is_empty() {
                     html! {
                         <>
@@ -153,10 +974,15 @@ impl Component for Editor {
                     }
                 } else {
                     let number_statements = result.0.result.number_of_statements;
+                    let statistics = RdfStatistics::new(&triples, &getYate());
                     html! {
                         <>
+                            { view_cached_notice(result.0.cached) }
                             <p>{format!("Status: {}", result.0.message)}</p>
                             <p>{format!("Number of statements: {}", number_statements)}</p>
+                            <p id="rdf-inference">{format!("Inference: {}", self.inference_selected)}</p>
+                            { view_rdf_statistics(&statistics) }
+                            <RdfGraphView triples=triples entries=self.props.validation_entries.clone() />
                         </>
                     }
                 };
@@ -167,17 +993,68 @@ impl Component for Editor {
                 false
             }
             Msg::AnalyzeShex => {
+                let changed = self.apply_detected_shex_format();
                 let link = self.link.clone();
+                let options = self.request_options();
                 let shex_param_selected = self.shex_param_selected.clone();
 
+                if !self.is_shex() {
+                    wasm_bindgen_futures::spawn_local(async move {
+                        let (response, error) = api::call_rdf_convert_api(
+                            getYashe(),
+                            shex_param_selected,
+                            "N-Triples".to_string(),
+                            &options,
+                        )
+                        .await;
+                        let result = if error.is_empty() {
+                            Ok(shacl::shapes_graph_info(&ntriples::parse_ntriples(
+                                &response.result.content,
+                            )))
+                        } else {
+                            Err(error)
+                        };
+                        link.send_message(Msg::ReceiveShaclAnalysis(result));
+                    });
+                    return changed;
+                }
+
                 wasm_bindgen_futures::spawn_local(async move {
-                    let content: (InfoShexResponse, String) =
-                        api::call_shex_info_api(getYashe(), shex_param_selected).await;
-                    link.send_message(Msg::ReceiveShexAnalysis(content));
+                    let shex = getYashe();
+                    let content: (InfoShexResponse, String) = api::call_shex_info_api(
+                        shex.clone(),
+                        shex_param_selected.clone(),
+                        &options,
+                    )
+                    .await;
+                    let (start_shape, analysis) = if content.1.is_empty() {
+                        let (response, error) = api::call_shex_convert_api(
+                            shex.clone(),
+                            shex_param_selected,
+                            "ShExJ".to_string(),
+                            &options,
+                        )
+                        .await;
+                        if error.is_empty() {
+                            (
+                                shexj::start_shape(&response.result.content),
+                                shex_analysis::analyze(
+                                    &response.result.content,
+                                    &shex,
+                                    &content.0.result.prefix_map,
+                                ),
+                            )
+                        } else {
+                            (None, ShexAnalysis::default())
+                        }
+                    } else {
+                        (None, ShexAnalysis::default())
+                    };
+                    link.send_message(Msg::ReceiveShexAnalysis(content, start_shape, analysis));
                 });
-                false
+                changed
             }
-            Msg::ReceiveShexAnalysis(result) => {
+            Msg::ReceiveShexAnalysis(result, start_shape, analysis) => {
                 let content = if !result.1.is_empty() {
                     self.analyzer_error = true;
                     html! {
@@ -188,11 +1065,18 @@ impl Component for Editor {
                 } else {
                     let number_shapes = result.0.result.shapes.len();
                     let number_prefixes = result.0.result.prefix_map.len();
+                    let start = start_shape
+                        .as_ref()
+                        .map(|start| start.describe(&result.0.result.prefix_map))
+                        .unwrap_or_else(|| "Not declared".to_string());
                     html! {
                         <>
+                            { view_cached_notice(result.0.cached) }
                             <p>{format!("Status: {}", result.0.message)}</p>
                             <p>{format!("Number of shapes: {}", number_shapes)}</p>
                             <p>{format!("Number of prefixes: {}", number_prefixes)}</p>
+                            <p id="start-shape">{format!("Start shape: {}", start)}</p>
+                            { view_shex_analysis(&analysis, &result.0.result.prefix_map) }
                         </>
                     }
                 };
@@ -204,16 +1088,265 @@ impl Component for Editor {
             }
             Msg::UpdateRdfParamSelected(value) => {
                 self.rdf_param_selected = value;
+                self.rdf_format_chosen = true;
+                self.format_notice.clear();
+                self.link.send_message(Msg::ScheduleSyntaxCheck);
                 true
             }
             Msg::UpdateShexParamSelected(value) => {
                 self.shex_param_selected = value;
+                self.shex_format_chosen = true;
+                self.format_notice.clear();
+                self.link.send_message(Msg::ScheduleSyntaxCheck);
                 true
             }
             Msg::UpdateShapeMapParamSelected(value) => {
                 self.shapemap_param_selected = value;
+                self.shapemap_format_chosen = true;
+                self.format_notice.clear();
+                self.link.send_message(Msg::ScheduleSyntaxCheck);
+                true
+            }
+            Msg::UpdateInferenceSelected(value) => {
+                self.inference_selected = value;
+                true
+            }
+            Msg::UpdateEngineSelected(value) => {
+                let was_shex = self.is_shex();
+                self.engine_selected = value;
+                if was_shex && !self.is_shex() {
+                    // Los grafos de formas SHACL se escriben en Turtle
+                    self.shex_format_before_shacl = Some(std::mem::replace(
+                        &mut self.shex_param_selected,
+                        "Turtle".to_string(),
+                    ));
+                } else if !was_shex && self.is_shex() {
+                    if let Some(format) = self.shex_format_before_shacl.take() {
+                        self.shex_param_selected = format;
+                    }
+                }
+                self.format_notice.clear();
+                self.link.send_message(Msg::ScheduleSyntaxCheck);
+                true
+            }
+            Msg::TranslateSchema => {
+                let changed = self.apply_detected_shex_format();
+                let target_engine = if self.is_shex() {
+                    self.props
+                        .engine_parameters
+                        .iter()
+                        .find(|engine| *engine != "ShEx")
+                        .cloned()
+                        .unwrap_or_default()
+                } else {
+                    "ShEx".to_string()
+                };
+                let link = self.link.clone();
+                let options = self.request_options();
+                let shex_param_selected = self.shex_param_selected.clone();
+                let engine_selected = self.engine_selected.clone();
+
+                wasm_bindgen_futures::spawn_local(async move {
+                    let result = schema_translation::translate_schema(
+                        getYashe(),
+                        shex_param_selected,
+                        engine_selected,
+                        target_engine,
+                        &options,
+                    )
+                    .await;
+                    link.send_message(Msg::ReceiveSchemaTranslation(result));
+                });
+                changed
+            }
+            Msg::ReceiveSchemaTranslation(result) => match result {
+                Err(_) => {
+                    let target = if self.is_shex() { "SHACL" } else { "ShEx" };
+                    let content = html! {
+                        <>
+                            <p>{format!("Status: Schema could not be translated to {}.", target)}</p>
+                        </>
+                    };
+                    self.props
+                        .on_open_modal
+                        .emit(("SCHEMA TRANSLATION".to_string(), content));
+                    false
+                }
+                Ok(translation) => {
+                    setYashe(&translation.content);
+                    self.engine_selected = translation.engine;
+                    self.shex_param_selected = translation.format;
+                    self.shex_format_before_shacl = None;
+                    let target = if self.is_shex() { "ShEx" } else { "SHACL" };
+                    self.format_notice = format!("Schema translated to {}.", target);
+                    if !translation.warnings.is_empty() {
+                        let content = html! {
+                            <>
+                                <p>{format!("Status: Schema translated to {} with warnings.", target)}</p>
+                                <ul id="translation-warnings" class="translation-warnings">
+                                    { for translation.warnings.iter().map(|warning| html! { <li>{ warning }</li> }) }
+                                </ul>
+                            </>
+                        };
+                        self.props
+                            .on_open_modal
+                            .emit(("SCHEMA TRANSLATION".to_string(), content));
+                    }
+                    self.link.send_message(Msg::ScheduleSyntaxCheck);
+                    true
+                }
+            },
+            Msg::ShowSchemaDiagram => {
+                let changed = self.apply_detected_shex_format();
+                let link = self.link.clone();
+                let options = self.request_options();
+                let shex_param_selected = self.shex_param_selected.clone();
+
+                wasm_bindgen_futures::spawn_local(async move {
+                    let shex = getYashe();
+                    let (response, error) = api::call_shex_convert_api(
+                        shex.clone(),
+                        shex_param_selected.clone(),
+                        "ShExJ".to_string(),
+                        &options,
+                    )
+                    .await;
+                    let result = if error.is_empty() {
+                        let prefixes = api::call_shex_info_api(shex, shex_param_selected, &options)
+                            .await
+                            .0
+                            .result
+                            .prefix_map;
+                        Ok(schema_diagram::diagram_shapes(
+                            &response.result.content,
+                            &prefixes,
+                        ))
+                    } else {
+                        Err(error)
+                    };
+                    link.send_message(Msg::ReceiveSchemaDiagram(result));
+                });
+                changed
+            }
+            Msg::ReceiveSchemaDiagram(result) => {
+                let content = match result {
+                    Err(_) => html! {
+                        <>
+                            <p>{"Status: Schema is not well-formed."}</p>
+                        </>
+                    },
+                    Ok(shapes) => html! {
+                        <SchemaDiagram shapes=shapes />
+                    },
+                };
+                self.props
+                    .on_open_modal
+                    .emit(("SCHEMA DIAGRAM".to_string(), content));
+                false
+            }
+            Msg::ReceiveShaclAnalysis(result) => {
+                let content = match result {
+                    Err(_) => html! {
+                        <>
+                            <p>{"Status: Shapes graph is not well-formed."}</p>
+                        </>
+                    },
+                    Ok(info) => html! {
+                        <>
+                            <p>{"Status: Well formed shapes graph"}</p>
+                            <p>{format!("Number of node shapes: {}", info.node_shapes.len())}</p>
+                            <p>{format!("Number of property shapes: {}", info.property_shapes)}</p>
+                            <p id="shacl-targets">{format!("Number of targets: {}", info.targets.len())}</p>
+                            <ul class="shacl-target-list">
+                                { for info.targets.iter().map(|target| html! { <li>{ target }</li> }) }
+                            </ul>
+                        </>
+                    },
+                };
+
+                self.props
+                    .on_open_modal
+                    .emit(("SHACL PROPERTIES".to_string(), content));
+                false
+            }
+            Msg::UpdateRdfConvertSelected(value) => {
+                self.rdf_convert_selected = value;
+                true
+            }
+            Msg::ConvertRDF => {
+                let link = self.link.clone();
+                let options = self.request_options();
+                let rdf_param_selected = self.rdf_param_selected.clone();
+                let target_format = self.rdf_convert_selected.clone();
+
+                wasm_bindgen_futures::spawn_local(async move {
+                    let content = api::call_rdf_convert_api(
+                        getYate(),
+                        rdf_param_selected,
+                        target_format.clone(),
+                        &options,
+                    )
+                    .await;
+                    link.send_message(Msg::ReceiveRDFConversion(content, target_format));
+                });
+                false
+            }
+            Msg::ReceiveRDFConversion(result, target_format) => {
+                if !result.1.is_empty() || result.0.result.content.is_empty() {
+                    let content = html! {
+                        <>
+                            <p>{format!("Status: RDF could not be converted to {}.", target_format)}</p>
+                        </>
+                    };
+                    self.props
+                        .on_open_modal
+                        .emit(("RDF CONVERSION".to_string(), content));
+                    false
+                } else {
+                    setYate(&result.0.result.content);
+                    self.rdf_param_selected = target_format;
+                    true
+                }
+            }
+            Msg::UpdateShexConvertSelected(value) => {
+                self.shex_convert_selected = value;
                 true
             }
+            Msg::ConvertShex => {
+                let link = self.link.clone();
+                let options = self.request_options();
+                let shex_param_selected = self.shex_param_selected.clone();
+                let target_format = self.shex_convert_selected.clone();
+
+                wasm_bindgen_futures::spawn_local(async move {
+                    let content = api::call_shex_convert_api(
+                        getYashe(),
+                        shex_param_selected,
+                        target_format.clone(),
+                        &options,
+                    )
+                    .await;
+                    link.send_message(Msg::ReceiveShexConversion(content, target_format));
+                });
+                false
+            }
+            Msg::ReceiveShexConversion(result, target_format) => {
+                if !result.1.is_empty() || result.0.result.content.is_empty() {
+                    let content = html! {
+                        <>
+                            <p>{format!("Status: Schema could not be converted to {}.", target_format)}</p>
+                        </>
+                    };
+                    self.props
+                        .on_open_modal
+                        .emit(("SHEX CONVERSION".to_string(), content));
+                    false
+                } else {
+                    setYashe(&result.0.result.content);
+                    self.shex_param_selected = target_format;
+                    true
+                }
+            }
         }
     }
 
@@ -223,6 +1356,9 @@ impl Component for Editor {
             self.rdf_param_selected = props.rdf_format.clone();
             self.shex_param_selected = props.shex_format.clone();
             self.shapemap_param_selected = props.shapemap_format.clone();
+            self.rdf_format_chosen = false;
+            self.shex_format_chosen = false;
+            self.shapemap_format_chosen = false;
             self.props.reset_example_loaded.emit(());
         }
         self.props = props;
@@ -234,6 +1370,34 @@ impl Component for Editor {
         if first_render {
             initializeYate();
             initializeYashe();
+
+            for editor in ["yate", "yashe"].iter() {
+                let link = self.link.clone();
+                let on_paste = Closure::wrap(
+                    Box::new(move || link.send_message(Msg::DetectFormats)) as Box<dyn FnMut()>,
+                );
+                onEditorPaste(editor, &on_paste);
+                on_paste.forget();
+
+                let link = self.link.clone();
+                let on_change =
+                    Closure::wrap(
+                        Box::new(move || link.send_message(Msg::ScheduleSyntaxCheck))
+                            as Box<dyn FnMut()>,
+                    );
+                onEditorChange(editor, &on_change);
+                on_change.forget();
+            }
+
+            // Se recuperan las pestañas activas de la sesión anterior
+            if self.restore_workspace {
+                for panel in [Panel::Rdf, Panel::Shex, Panel::ShapeMap].iter() {
+                    if let Some(tab) = self.workspace.tabs(*panel).active_tab() {
+                        let content = tab.content.clone();
+                        self.set_editor_content(*panel, &content);
+                    }
+                }
+            }
         }
     }
 
@@ -243,33 +1407,106 @@ impl Component for Editor {
             <div id="editors-container" class="editors-container">
                 <div class="yashe-container">
                     <h3 class="title-editor">{"RDF"}</h3>
+                    { self.view_tabs(Panel::Rdf) }
                     <textarea id="editor-yate"></textarea>
+                    { view_diagnostics(&self.rdf_diagnostics, "rdf") }
+                    { view_check_error(&self.rdf_check_error, "rdf") }
                     <div class="editor-tools">
                         { self.view_parameters(&self.props.rdf_parameters, "rdf") }
+                        { self.view_parameters(&self.props.inference_parameters, "inference") }
                         <button id="analyze-rdf" class="analyze-btn" onclick=self.link.callback(|_| Msg::AnalyzeRDF)>{"Analyze"}</button>
                     </div>
+                    <div class="editor-tools">
+                        { self.view_conversion_parameters(&self.props.rdf_conversion_parameters, "rdf") }
+                        <button id="convert-rdf" class="analyze-btn" onclick=self.link.callback(|_| Msg::ConvertRDF)>{"Convert"}</button>
+                    </div>
                     <div class="shapemap-container">
                         <h3 class="title-editor">{"ShapeMap"}</h3>
-                        <textarea
-                            id="shapemap-editor"
-                            class="shapemap-editor"
-                            value=&self.props.shapemap_value
-                            oninput=self.link.callback(|e: InputData| Msg::UpdateShapeMapValue(e.value))
+                        { self.view_tabs(Panel::ShapeMap) }
+                        <ShapeMapEditor
+                            value=self.props.shapemap_value.clone()
+                            node_suggestions=self.node_suggestions()
+                            class_suggestions=self.class_suggestions()
+                            shape_suggestions=self.shape_suggestions()
+                            on_change=self.link.callback(Msg::UpdateShapeMapValue)
+                            on_focus=self.link.callback(|_| Msg::LoadSuggestions)
                         />
-                        { self.view_parameters(&self.props.shapemap_parameters, "shapemap") }
+                        { view_diagnostics(&self.shapemap_diagnostics, "shapemap") }
+                        { if !self.is_shex() {
+                            html! {
+                                <p id="shacl-target-notice" class="format-notice">
+                                    {"SHACL validation uses the target declarations of the shapes graph; the ShapeMap is ignored."}
+                                </p>
+                            }
+                        } else {
+                            html! { <></> }
+                        }}
+                        <div class="editor-tools">
+                            { self.view_parameters(&self.props.shapemap_parameters, "shapemap") }
+                            <button id="preview-shapemap" class="analyze-btn" onclick=self.link.callback(|_| Msg::PreviewShapeMap)>{"Preview"}</button>
+                        </div>
+                        <div class="editor-tools">
+                            { self.view_generation_options() }
+                            <button id="generate-shapemap" class="analyze-btn" onclick=self.link.callback(|_| Msg::GenerateShapeMap)>{"Generate"}</button>
+                        </div>
+                        { match self.shapemap_preview_count {
+                            Some(count) => html! {
+                                <p id="shapemap-preview-count" class="shapemap-preview-count">
+                                    { format!("{} node(s) will be checked", count) }
+                                </p>
+                            },
+                            None => html! { <></> },
+                        }}
+                        { view_unmatched_selectors(&self.unmatched_selectors) }
+                        { view_shapemap_merge_error(&self.shapemap_merge_error) }
                     </div>
                 </div>
                 <div class="yate-container">
-                    <h3 class="title-editor">{"ShEx"}</h3>
+                    <h3 class="title-editor">{ if self.is_shex() { "ShEx" } else { "SHACL" } }</h3>
+                    { self.view_tabs(Panel::Shex) }
                     <textarea id="editor-yashe"></textarea>
+                    { view_diagnostics(&self.shex_diagnostics, "shex") }
+                    { view_check_error(&self.shex_check_error, "shex") }
                     <div class="editor-tools">
-                        { self.view_parameters(&self.props.shex_parameters, "shex") }
+                        { self.view_parameters(&self.props.engine_parameters, "engine") }
+                        { self.view_parameters(self.schema_formats(), "shex") }
                         <button id="analyze-shex" class="analyze-btn" onclick=self.link.callback(|_| Msg::AnalyzeShex)>{"Analyze"}</button>
                     </div>
+                    { if self.is_shex() {
+                        html! {
+                            <div class="editor-tools">
+                                { self.view_conversion_parameters(&self.props.shex_conversion_parameters, "shex") }
+                                <button id="convert-shex" class="analyze-btn" onclick=self.link.callback(|_| Msg::ConvertShex)>{"Convert"}</button>
+                                <button id="diagram-shex" class="analyze-btn" onclick=self.link.callback(|_| Msg::ShowSchemaDiagram)>{"Diagram"}</button>
+                            </div>
+                        }
+                    } else {
+                        html! { <></> }
+                    }}
+                    <div class="editor-tools">
+                        <button id="translate-schema" class="analyze-btn" onclick=self.link.callback(|_| Msg::TranslateSchema)>
+                            { if self.is_shex() { "To SHACL" } else { "To ShEx" } }
+                        </button>
+                    </div>
+                    { if !self.format_notice.is_empty() {
+                        html! { <p id="format-notice" class="format-notice">{ &self.format_notice }</p> }
+                    } else {
+                        html! { <></> }
+                    }}
                     <div style="margin-top: auto;">
                         <button id="validate-btn" class="button-27" onclick=self.link.callback(|_| Msg::Validate)>
                             { "VALIDATE" }
                         </button>
+                        { if self.compared_schemas() > 1 {
+                            html! {
+                                <button id="compare-schemas-btn" class="analyze-btn" onclick=self.link.callback(|_| Msg::CompareSchemas)>
+                                    { format!("Compare {} schema versions", self.compared_schemas()) }
+                                </button>
+                            }
+                        } else {
+                            html! { <></> }
+                        }}
+                        { self.view_batch_files() }
                     </div>
                 </div>
             </div>
@@ -278,20 +1515,341 @@ impl Component for Editor {
 }
 
 impl Editor {
+    /// Renderiza las pestañas de un panel con los controles para incluir cada documento en la validación.
+    fn view_tabs(&self, panel: Panel) -> Html {
+        let tabs = self.workspace.tabs(panel);
+        let several = tabs.tabs.len() > 1;
+        html! {
+            <div id=format!("{}-tabs", panel.id()) class="document-tabs">
+                { for tabs.tabs.iter().enumerate().map(|(index, tab)| html! {
+                    <span class={ if index == tabs.active { "document-tab active" } else { "document-tab" } }>
+                        { if several {
+                            html! {
+                                <input id=format!("{}-tab-included-{}", panel.id(), index) class="document-tab-included"
+                                    type="checkbox" title="Include in validation" checked=tab.included
+                                    onclick=self.link.callback(move |_| Msg::ToggleTabIncluded(panel, index)) />
+                            }
+                        } else {
+                            html! { <></> }
+                        }}
+                        <button id=format!("{}-tab-{}", panel.id(), index) class="document-tab-name" title="Double click to rename"
+                            onclick=self.link.callback(move |_| Msg::SelectTab(panel, index))
+                            ondblclick=self.link.callback(move |_| Msg::RenameTab(panel, index))>
+                            { &tab.name }
+                        </button>
+                        { if several {
+                            html! {
+                                <button class="document-tab-close" title="Close"
+                                    onclick=self.link.callback(move |_| Msg::CloseTab(panel, index))>{ "✕" }</button>
+                            }
+                        } else {
+                            html! { <></> }
+                        }}
+                    </span>
+                }) }
+                <button id=format!("add-{}-tab", panel.id()) class="document-tab-add" title="New tab"
+                    onclick=self.link.callback(move |_| Msg::AddTab(panel))>{ "+" }</button>
+                { if panel == Panel::Rdf && several {
+                    html! {
+                        <label class="document-tabs-merge" title="Validate the included data tabs as a single document or one by one">
+                            <input id="merge-data" type="checkbox" checked=self.workspace.merge_data
+                                onclick=self.link.callback(|_| Msg::ToggleMergeData) />
+                            { "Merge data" }
+                        </label>
+                    }
+                } else {
+                    html! { <></> }
+                }}
+            </div>
+        }
+    }
+
+    /// Contenido actual del editor de un panel.
+    fn editor_content(&self, panel: Panel) -> String {
+        match panel {
+            Panel::Rdf => getYate(),
+            Panel::Shex => getYashe(),
+            Panel::ShapeMap => self.props.shapemap_value.clone(),
+        }
+    }
+
+    /// Carga un documento en el editor de un panel.
+    fn set_editor_content(&mut self, panel: Panel, content: &str) {
+        match panel {
+            Panel::Rdf => setYate(content),
+            Panel::Shex => setYashe(content),
+            Panel::ShapeMap => {
+                // Se actualiza también la copia local para que el espacio de trabajo se guarde con el nuevo valor
+                self.props.shapemap_value = content.to_string();
+                self.props
+                    .on_update_shapemap_value
+                    .emit(content.to_string());
+            }
+        }
+    }
+
+    /// Guarda el espacio de trabajo en el navegador para recuperarlo en la siguiente sesión.
+    fn save_workspace(&self) {
+        if let Err(error) =
+            self.workspace
+                .save(getYate(), getYashe(), self.props.shapemap_value.clone())
+        {
+            log::warn!("{}", error);
+        }
+    }
+
+    /// Datos RDF de las pestañas incluidas, unidos si el formato lo permite; si no, los de la pestaña activa.
+    fn validation_data(&self) -> String {
+        let documents = self.workspace.rdf.participating(getYate());
+        if workspace::can_merge_data(&self.rdf_param_selected) {
+            workspace::merge_data(&documents)
+        } else {
+            getYate()
+        }
+    }
+
+    /// ShapeMap que resulta de unir los de las pestañas incluidas.
+    ///
+    /// Si no se pueden unir guarda el error para mostrarlo bajo el editor y devuelve `None`.
+    fn validation_shapemap(&mut self) -> Option<String> {
+        let merged = workspace::merge_shapemaps(
+            &self
+                .workspace
+                .shapemap
+                .participating(self.props.shapemap_value.clone()),
+            &self.shapemap_param_selected,
+        );
+        match merged {
+            Ok(shapemap) => {
+                self.shapemap_merge_error = None;
+                Some(shapemap)
+            }
+            Err(error) => {
+                self.shapemap_merge_error = Some(error);
+                None
+            }
+        }
+    }
+
+    /// Opciones de las solicitudes a la API con la configuración actual.
+    fn request_options(&self) -> RequestOptions {
+        RequestOptions::with_policy(self.props.request_policy)
+    }
+
+    /// Parámetros de validación seleccionados con el esquema del editor y el ShapeMap indicado.
+    fn validation_parameters(&self, shapemap: String) -> ValidationParameters {
+        ValidationParameters {
+            shex: getYashe(),
+            shapemap,
+            rdf_format: self.rdf_param_selected.clone(),
+            shex_format: self.shex_param_selected.clone(),
+            shapemap_format: self.shapemap_param_selected.clone(),
+            inference: self.inference_selected.clone(),
+            engine: self.engine_selected.clone(),
+        }
+    }
+
+    /// Número de esquemas que participan en la comparación de versiones.
+    fn compared_schemas(&self) -> usize {
+        self.workspace
+            .shex
+            .tabs
+            .iter()
+            .filter(|tab| tab.included)
+            .count()
+    }
+
+    /// Renderiza la zona en la que se sueltan o seleccionan los ficheros de datos de la validación por lotes.
+    fn view_batch_files(&self) -> Html {
+        let reading = self.batch_pending > 0;
+        html! {
+            <div id="batch-validation" class="batch-validation">
+                <label class="batch-drop-zone" for="batch-files">
+                    { "Drop data files here or click to validate several files at once" }
+                    <input id="batch-files" type="file" multiple=true
+                        onchange=self.link.batch_callback(|e: ChangeData| match e {
+                            ChangeData::Files(files) => vec![Msg::SelectBatchFiles(
+                                (0..files.length()).filter_map(|index| files.get(index)).collect(),
+                            )],
+                            _ => vec![],
+                        }) />
+                </label>
+                { if !self.batch_files.is_empty() || !self.batch_errors.is_empty() {
+                    html! {
+                        <>
+                            <ul id="batch-file-list" class="batch-file-list">
+                                { for self.batch_files.iter().map(|file| html! {
+                                    <li>{ format!("{} ({})", file.name, file.format) }</li>
+                                }) }
+                                { for self.batch_errors.iter().map(|(name, error)| html! {
+                                    <li class="batch-file-error">{ format!("{}: {}", name, error) }</li>
+                                }) }
+                            </ul>
+                            <button id="validate-batch-btn" class="analyze-btn" disabled=reading || self.batch_files.is_empty()
+                                onclick=self.link.callback(|_| Msg::ValidateBatch)>
+                                { format!("Validate {} files", self.batch_files.len()) }
+                            </button>
+                            <button id="clear-batch-btn" class="analyze-btn" onclick=self.link.callback(|_| Msg::ClearBatchFiles)>
+                                { "Clear" }
+                            </button>
+                        </>
+                    }
+                } else {
+                    html! { <></> }
+                }}
+            </div>
+        }
+    }
+
+    /// Nodos de los datos RDF (sujetos y objetos que no son literales) para autocompletar el ShapeMap.
+    fn node_suggestions(&self) -> Vec<String> {
+        let mut nodes: Vec<String> = self
+            .rdf_triples
+            .iter()
+            .flat_map(|triple| vec![&triple.subject, &triple.object])
+            .filter_map(|term| match term {
+                Term::Iri(iri) => Some(shapemap::compact_iri(iri, &self.schema_info.prefix_map)),
+                _ => None,
+            })
+            .collect();
+        nodes.sort();
+        nodes.dedup();
+        nodes
+    }
+
+    /// Clases de los datos RDF (objetos de `rdf:type`) para autocompletar el ShapeMap.
+    fn class_suggestions(&self) -> Vec<String> {
+        let mut classes: Vec<String> = self
+            .rdf_triples
+            .iter()
+            .filter(|triple| triple.predicate == ntriples::RDF_TYPE)
+            .filter_map(|triple| match &triple.object {
+                Term::Iri(iri) => Some(shapemap::compact_iri(iri, &self.schema_info.prefix_map)),
+                _ => None,
+            })
+            .collect();
+        classes.sort();
+        classes.dedup();
+        classes
+    }
+
+    /// Etiquetas de las formas del esquema para autocompletar el ShapeMap.
+    ///
+    /// Incluye `START` cuando el esquema declara una forma inicial.
+    fn shape_suggestions(&self) -> Vec<String> {
+        let start = self.start_shape.as_ref().map(|_| "START".to_string());
+        start
+            .into_iter()
+            .chain(
+                self.schema_info
+                    .shapes
+                    .iter()
+                    .map(|shape| shapemap::compact_iri(shape, &self.schema_info.prefix_map)),
+            )
+            .collect()
+    }
+
+    /// Ajusta el formato RDF seleccionado si no es capaz de leer el contenido de YATE.
+    ///
+    /// Devuelve `true` si el selector ha cambiado. Un formato elegido por el usuario no se cambia.
+    fn apply_detected_rdf_format(&mut self) -> bool {
+        if self.rdf_format_chosen {
+            return false;
+        }
+        match format_detection::detect_rdf_format(&getYate()) {
+            Some(detected)
+                if !format_detection::is_rdf_format_compatible(
+                    &self.rdf_param_selected,
+                    &detected,
+                ) =>
+            {
+                self.format_notice = format!(
+                    "RDF format switched from {} to {} based on its content.",
+                    self.rdf_param_selected, detected
+                );
+                self.rdf_param_selected = detected;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Indica si el motor de validación seleccionado es ShEx.
+    fn is_shex(&self) -> bool {
+        self.engine_selected == "ShEx"
+    }
+
+    /// Formatos que admite el esquema con el motor seleccionado: los formatos ShEx o, con SHACL, los
+    /// formatos RDF en los que se puede escribir el grafo de formas.
+    fn schema_formats(&self) -> &Vec<String> {
+        if self.is_shex() {
+            &self.props.shex_parameters
+        } else {
+            &self.props.rdf_conversion_parameters
+        }
+    }
+
+    /// Ajusta el formato ShEx seleccionado si no coincide con el contenido de YASHE.
+    ///
+    /// Devuelve `true` si el selector ha cambiado. Con SHACL o si el usuario ha elegido el formato, no se ajusta.
+    fn apply_detected_shex_format(&mut self) -> bool {
+        if !self.is_shex() || self.shex_format_chosen {
+            return false;
+        }
+        match format_detection::detect_shex_format(&getYashe()) {
+            Some(detected) if detected != self.shex_param_selected => {
+                self.format_notice = format!(
+                    "ShEx format switched from {} to {} based on its content.",
+                    self.shex_param_selected, detected
+                );
+                self.shex_param_selected = detected;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Ajusta el formato ShapeMap seleccionado si no coincide con el contenido indicado.
+    ///
+    /// Devuelve `true` si el selector ha cambiado. Un formato elegido por el usuario no se cambia.
+    fn apply_detected_shapemap_format(&mut self, shapemap: &str) -> bool {
+        if self.shapemap_format_chosen {
+            return false;
+        }
+        match format_detection::detect_shapemap_format(shapemap) {
+            Some(detected) if detected != self.shapemap_param_selected => {
+                self.format_notice = format!(
+                    "ShapeMap format switched from {} to {} based on its content.",
+                    self.shapemap_param_selected, detected
+                );
+                self.shapemap_param_selected = detected;
+                true
+            }
+            _ => false,
+        }
+    }
+
     fn view_parameters(&self, options: &Vec<String>, filter: &str) -> Html {
         let select_class = format!("select parameters param-{}", filter);
         let id = format!("select-{}", filter);
+        let title = match filter {
+            "inference" => "Inference",
+            "engine" => "Engine",
+            _ => "Format",
+        };
         let filter = filter.to_string();
         let selected_value = match filter.as_str() {
             "rdf" => &self.rdf_param_selected,
             "shex" => &self.shex_param_selected,
             "shapemap" => &self.shapemap_param_selected,
+            "inference" => &self.inference_selected,
+            "engine" => &self.engine_selected,
             _ => &String::new(),
         };
 
         html! {
             <select
-                title={"Format"}
+                title={title}
                 class={select_class}
                 id={id}
                 onchange=self.link.callback(move |e: ChangeData| {
@@ -300,6 +1858,8 @@ impl Editor {
                             "rdf" => Msg::UpdateRdfParamSelected(select.value()),
                             "shex" => Msg::UpdateShexParamSelected(select.value()),
                             "shapemap" => Msg::UpdateShapeMapParamSelected(select.value()),
+                            "inference" => Msg::UpdateInferenceSelected(select.value()),
+                            "engine" => Msg::UpdateEngineSelected(select.value()),
                             _ => unreachable!(),
                         }
                     } else {
@@ -307,6 +1867,8 @@ impl Editor {
                             "rdf" => Msg::UpdateRdfParamSelected(String::new()),
                             "shex" => Msg::UpdateShexParamSelected(String::new()),
                             "shapemap" => Msg::UpdateShapeMapParamSelected(String::new()),
+                            "inference" => Msg::UpdateInferenceSelected(String::new()),
+                            "engine" => Msg::UpdateEngineSelected(String::new()),
                             _ => unreachable!(),
                         }
                     }
@@ -326,4 +1888,300 @@ impl Editor {
             </select>
         }
     }
+
+    /// Renderiza el selector del criterio con el que se genera el ShapeMap.
+    fn view_generation_options(&self) -> Html {
+        let prefixes = &self.schema_info.prefix_map;
+        let option = |value: &str, label: String| {
+            html! {
+                <option
+                    class="option-parameters"
+                    value={value}
+                    selected={self.generation_selected == value}
+                >
+                    { label }
+                </option>
+            }
+        };
+
+        html! {
+            <select
+                title={"Generate ShapeMap"}
+                class="select parameters generate-shapemap"
+                id="select-generate-shapemap"
+                onfocus=self.link.callback(|_| Msg::LoadSuggestions)
+                onchange=self.link.callback(|e: ChangeData| match e {
+                    ChangeData::Select(select) => Msg::UpdateGenerationSelected(select.value()),
+                    _ => Msg::UpdateGenerationSelected(GENERATE_BY_TYPE.to_string()),
+                })
+            >
+                { option(GENERATE_BY_TYPE, "Match rdf:type with shape names".to_string()) }
+                { if self.start_shape.is_some() {
+                    option("START", "All subjects @START".to_string())
+                } else {
+                    html! { <></> }
+                }}
+                { for self.schema_info.shapes.iter().map(|shape| {
+                    option(shape, format!("All subjects @{}", shapemap::compact_iri(shape, prefixes)))
+                }) }
+            </select>
+        }
+    }
+
+    /// Renderiza el selector de formato de destino para la conversión de una entrada.
+    fn view_conversion_parameters(&self, options: &[String], filter: &str) -> Html {
+        let select_class = format!("select parameters convert-{}", filter);
+        let id = format!("select-convert-{}", filter);
+        let filter = filter.to_string();
+        let selected_value = match filter.as_str() {
+            "rdf" => &self.rdf_convert_selected,
+            "shex" => &self.shex_convert_selected,
+            _ => &String::new(),
+        };
+
+        html! {
+            <select
+                title={"Convert to"}
+                class={select_class}
+                id={id}
+                onchange=self.link.callback(move |e: ChangeData| {
+                    let value = match e {
+                        ChangeData::Select(select) => select.value(),
+                        _ => String::new(),
+                    };
+                    match filter.as_str() {
+                        "rdf" => Msg::UpdateRdfConvertSelected(value),
+                        "shex" => Msg::UpdateShexConvertSelected(value),
+                        _ => unreachable!(),
+                    }
+                })
+            >
+                { for options.iter().map(|option| {
+                    html! {
+                        <option
+                            class="option-parameters"
+                            value={option}
+                            selected={selected_value == option}
+                        >
+                            {option}
+                        </option>
+                    }
+                }) }
+            </select>
+        }
+    }
+}
+
+/// Valor del selector de generación que asocia los sujetos según su `rdf:type`.
+const GENERATE_BY_TYPE: &str = "types";
+
+/// Separa el resultado de una comprobación de sintaxis en los diagnósticos del contenido y el error por el
+/// que no se ha podido comprobar (red, tiempo agotado o servidor), que no es un problema del contenido.
+fn split_check_result(result: Result<(), RequestError>) -> (Vec<Diagnostic>, Option<String>) {
+    match result {
+        Ok(()) => (Vec::new(), None),
+        Err(RequestError::Rejected(error)) => (vec![Diagnostic::from_message(&error)], None),
+        Err(RequestError::Unavailable(error)) => (Vec::new(), Some(error)),
+    }
+}
+
+/// Serializa los diagnósticos para enviarlos a los editores YATE y YASHE.
+fn diagnostics_json(diagnostics: &[Diagnostic]) -> String {
+    serde_json::to_string(diagnostics).unwrap_or_else(|_| "[]".to_string())
+}
+
+/// Renderiza las formas, los prefijos y las advertencias del análisis detallado de un esquema ShEx.
+///
+/// Cada forma enlaza con la línea de su declaración en el editor YASHE.
+fn view_shex_analysis(analysis: &ShexAnalysis, prefixes: &[Prefix]) -> Html {
+    html! {
+        <div id="shex-analysis" class="rdf-statistics">
+            { if analysis.warnings.is_empty() {
+                html! { <></> }
+            } else {
+                html! {
+                    <ul id="shex-warnings" class="translation-warnings">
+                        { for analysis.warnings.iter().map(|warning| html! { <li>{ warning }</li> }) }
+                    </ul>
+                }
+            }}
+            <table id="shex-shapes" class="rdf-statistics-table">
+                <thead><tr><th>{ "Shape" }</th><th>{ "Line" }</th><th>{ "References" }</th></tr></thead>
+                <tbody>
+                    { for analysis.shapes.iter().map(|shape| {
+                        let label = match shape.line {
+                            Some(line) => html! {
+                                <button type="button" class="shape-link" onclick=Callback::from(move |_| goToYasheLine(line))>
+                                    { &shape.label }
+                                </button>
+                            },
+                            None => html! { { &shape.label } },
+                        };
+                        html! {
+                            <tr>
+                                <td>{ label }</td>
+                                <td>{ shape.line.map(|line| line.to_string()).unwrap_or_default() }</td>
+                                <td>{ shape.references.join(", ") }</td>
+                            </tr>
+                        }
+                    }) }
+                </tbody>
+            </table>
+            <table id="shex-prefixes" class="rdf-statistics-table">
+                <thead><tr><th>{ "Prefix" }</th><th>{ "IRI" }</th></tr></thead>
+                <tbody>
+                    { for prefixes.iter().map(|prefix| html! {
+                        <tr>
+                            <td>{ format!("{}:", prefix.prefix_name) }</td>
+                            <td>{ &prefix.prefix_iri }</td>
+                        </tr>
+                    }) }
+                </tbody>
+            </table>
+        </div>
+    }
+}
+
+/// Indica en el modal de análisis que la respuesta procede de la caché de respuestas.
+fn view_cached_notice(cached: bool) -> Html {
+    if !cached {
+        return html! { <></> };
+    }
+    html! {
+        <p class="cached-result analysis-cached">
+            <i class="fas fa-database"></i>
+            { " Cached result: this content was already analyzed. Disable the cache under 'SETTINGS' to ask the server again." }
+        </p>
+    }
+}
+
+/// Renderiza las estadísticas detalladas de los datos RDF como tablas, con los botones para exportarlas.
+fn view_rdf_statistics(statistics: &RdfStatistics) -> Html {
+    let csv = statistics.to_csv();
+    let json = serde_json::to_string_pretty(statistics).unwrap_or_default();
+    let view_counts = |id: &str, title: &str, counts: &[(String, usize)]| {
+        if counts.is_empty() {
+            return html! { <></> };
+        }
+        html! {
+            <table id=id.to_string() class="rdf-statistics-table">
+                <thead><tr><th>{ title }</th><th>{ "Count" }</th></tr></thead>
+                <tbody>
+                    { for counts.iter().map(|(name, count)| html! { <tr><td>{ name }</td><td>{ count }</td></tr> }) }
+                </tbody>
+            </table>
+        }
+    };
+
+    html! {
+        <div id="rdf-statistics" class="rdf-statistics">
+            <table id="rdf-statistics-summary" class="rdf-statistics-table">
+                <tbody>
+                    <tr><td>{ "Distinct subjects" }</td><td>{ statistics.subjects }</td></tr>
+                    <tr><td>{ "Distinct predicates" }</td><td>{ statistics.predicates }</td></tr>
+                    <tr><td>{ "Distinct objects" }</td><td>{ statistics.objects }</td></tr>
+                    <tr><td>{ "Blank nodes" }</td><td>{ statistics.blank_nodes }</td></tr>
+                    <tr><td>{ "Classes" }</td><td>{ statistics.classes.len() }</td></tr>
+                </tbody>
+            </table>
+            { view_counts("rdf-statistics-classes", "Class (instances)", &statistics.classes) }
+            { view_counts("rdf-statistics-datatypes", "Datatype", &statistics.datatypes) }
+            { view_counts("rdf-statistics-languages", "Language tag", &statistics.languages) }
+            { if statistics.declared_prefixes.is_empty() {
+                html! { <></> }
+            } else {
+                html! {
+                    <table id="rdf-statistics-prefixes" class="rdf-statistics-table">
+                        <thead><tr><th>{ "Prefix" }</th><th>{ "IRI" }</th></tr></thead>
+                        <tbody>
+                            { for statistics.declared_prefixes.iter().map(|prefix| {
+                                let unused = statistics.unused_prefixes.contains(&format!("{}:", prefix.prefix_name));
+                                html! {
+                                    <tr class=if unused { "unused-prefix" } else { "" }>
+                                        <td>{ format!("{}:", prefix.prefix_name) }</td>
+                                        <td>{ &prefix.prefix_iri }{ if unused { " (unused)" } else { "" } }</td>
+                                    </tr>
+                                }
+                            }) }
+                        </tbody>
+                    </table>
+                }
+            }}
+            <div class="editor-tools">
+                <button id="export-rdf-statistics-csv" class="analyze-btn" onclick=Callback::from(move |_| exportCsv(&csv, "rdf-statistics.csv"))>{ "Export CSV" }</button>
+                <button id="export-rdf-statistics-json" class="analyze-btn" onclick=Callback::from(move |_| exportText(&json, "rdf-statistics.json", "application/json"))>{ "Export JSON" }</button>
+            </div>
+        </div>
+    }
+}
+
+/// Renderiza la lista de errores de sintaxis bajo un editor.
+fn view_diagnostics(diagnostics: &[Diagnostic], filter: &str) -> Html {
+    if diagnostics.is_empty() {
+        return html! { <></> };
+    }
+
+    html! {
+        <ul id={format!("diagnostics-{}", filter)} class="diagnostics-list">
+            { for diagnostics.iter().map(|diagnostic| html! {
+                <li class="diagnostics-item">{ diagnostic.describe() }</li>
+            }) }
+        </ul>
+    }
+}
+
+/// Renderiza el aviso de que no se ha podido comprobar la sintaxis de una entrada.
+fn view_check_error(error: &Option<String>, filter: &str) -> Html {
+    match error {
+        Some(error) => html! {
+            <p id={format!("check-error-{}", filter)} class="check-error">
+                { format!("Syntax could not be checked: {}", error) }
+            </p>
+        },
+        None => html! { <></> },
+    }
+}
+
+/// Renderiza el aviso de los selectores del ShapeMap que no seleccionan ningún nodo en la última validación.
+fn view_unmatched_selectors(selectors: &[String]) -> Html {
+    if selectors.is_empty() {
+        return html! { <></> };
+    }
+
+    html! {
+        <p id="unmatched-selectors" class="format-notice">
+            { format!("No nodes match these selectors, so they are not validated: {}", selectors.join(", ")) }
+        </p>
+    }
+}
+
+/// Renderiza el error por el que no se han podido unir los ShapeMaps de las pestañas incluidas.
+fn view_shapemap_merge_error(error: &Option<String>) -> Html {
+    match error {
+        Some(error) => html! {
+            <p id="shapemap-merge-error" class="check-error">{ error }</p>
+        },
+        None => html! { <></> },
+    }
+}
+
+/// Renderiza una asociación del ShapeMap con los nodos a los que se expande su selector.
+fn view_resolved_association(association: &ResolvedAssociation, prefixes: &[Prefix]) -> Html {
+    html! {
+        <div class="shapemap-preview-item">
+            <p class="shapemap-preview-selector">
+                { format!("{} ({} node(s))", association.selector, association.nodes.len()) }
+            </p>
+            { match &association.error {
+                Some(error) => html! { <p class="shapemap-preview-error">{ error }</p> },
+                None => html! {
+                    <ul class="shapemap-preview-nodes">
+                        { for association.nodes.iter().map(|node| html! {
+                            <li>{ query_shapemap::display_term(node, prefixes) }</li>
+                        }) }
+                    </ul>
+                },
+            }}
+        </div>
+    }
 }
@@ -1,4 +1,35 @@
+use crate::app::examples_manager::ExampleInfo;
+use crate::app::request_policy::RequestPolicy;
+use crate::app::response_cache;
+use crate::app::shex_test_suite::ShexTestCase;
+use crate::app::user_examples::{UserExample, UserExampleAction};
+use wasm_bindgen::prelude::*;
 use yew::prelude::*;
+use yew::services::reader::{File, FileData, ReaderService, ReaderTask};
+
+/// Número máximo de tests de shexTest que se listan en el menú; el resto se encuentran con la búsqueda.
+const MAX_LISTED_TESTS: usize = 50;
+
+/// Tiempo máximo de espera de las solicitudes que se puede configurar, en segundos.
+const MAX_TIMEOUT_SECS: u32 = 600;
+
+/// Número máximo de intentos de las solicitudes que se puede configurar.
+const MAX_ATTEMPTS: u32 = 10;
+
+/// Diálogos del navegador para renombrar y eliminar los ejemplos del usuario y las pestañas de los editores
+#[wasm_bindgen(inline_js = "
+    export function promptText(message, value) {
+        return window.prompt(message, value);
+    }
+
+    export function confirmAction(message) {
+        return window.confirm(message);
+    }
+    ")]
+extern "C" {
+    pub fn promptText(message: &str, value: &str) -> Option<String>;
+    fn confirmAction(message: &str) -> bool;
+}
 
 /// Componente `Header` que proporciona una interfaz de usuario para la navegación y la carga de ejemplos.
 ///
@@ -8,17 +39,68 @@ use yew::prelude::*;
 pub struct Header {
     link: ComponentLink<Self>,
     props: Props,
+    search_text: String,
+    user_example_name: String,
+    reader_task: Option<ReaderTask>,
+    test_reader_tasks: Vec<ReaderTask>,
+    test_files: Vec<(String, String)>,
+    pending_test_files: usize,
 }
 #[derive(Properties, Clone, PartialEq)]
 pub struct Props {
+    /// Ejemplos del manifiesto que se muestran en el menú.
+    pub examples: Vec<ExampleInfo>,
+    /// Ejemplos guardados por el usuario en el navegador.
+    pub user_examples: Vec<UserExample>,
     pub on_load_example: Callback<String>,
+    /// `Callback` con las acciones sobre los ejemplos del usuario.
+    pub on_user_example: Callback<UserExampleAction>,
+    /// Tests importados de la batería de shexTest.
+    #[prop_or_default]
+    pub test_cases: Vec<ShexTestCase>,
+    /// `Callback` con los ficheros (nombre y contenido) seleccionados para importar un manifiesto de shexTest.
+    pub on_import_shex_tests: Callback<Vec<(String, String)>>,
+    /// `Callback` con la posición del test de shexTest que se carga en los editores.
+    pub on_load_test_case: Callback<usize>,
     pub on_open_modal: Callback<(String, Html)>,
+    /// Configuración de las solicitudes a la API.
+    pub request_policy: RequestPolicy,
+    /// `Callback` con la configuración de las solicitudes modificada por el usuario.
+    pub on_update_request_policy: Callback<RequestPolicy>,
 }
 
 pub enum Msg {
     /// Mensaje que indica la carga de un ejemplo específico.
     /// Contiene el identificador del ejemplo a cargar.
     LoadExample(String),
+    /// Filtra los ejemplos del menú con el texto de búsqueda.
+    SearchExamples(String),
+    /// Actualiza el nombre con el que se guardará el contenido actual de los editores.
+    UpdateUserExampleName(String),
+    /// Guarda el contenido actual de los editores con el nombre introducido.
+    SaveUserExample,
+    /// Pide un nuevo nombre para el ejemplo del usuario en la posición indicada.
+    RenameUserExample(usize),
+    /// Elimina, tras confirmarlo, el ejemplo del usuario en la posición indicada.
+    DeleteUserExample(usize),
+    /// Lee el fichero JSON seleccionado para importar ejemplos.
+    ImportUserExamples(File),
+    /// Recibe el contenido del fichero JSON importado.
+    ReceiveUserExamplesFile(FileData),
+    /// Lee el manifiesto de shexTest y los ficheros de sus tests.
+    ImportShexTests(Vec<File>),
+    /// Recibe el contenido de uno de los ficheros de shexTest; al recibir el último se importan los tests.
+    ReceiveShexTestFile(FileData),
+    /// Cambia el tiempo máximo de espera de las solicitudes a la API, en segundos.
+    UpdateRequestTimeout(String),
+    /// Cambia el número máximo de intentos de las solicitudes a la API.
+    UpdateRequestAttempts(String),
+    /// Activa o desactiva la reutilización de las respuestas guardadas en la caché.
+    ToggleCache,
+    /// Activa o desactiva la copia de la caché en IndexedDB.
+    TogglePersistentCache,
+    /// Elimina las respuestas guardadas en la caché.
+    ClearCache,
     Help(),
 }
 
@@ -27,7 +109,16 @@ impl Component for Header {
     type Properties = Props;
 
     fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
-        Self { link, props }
+        Self {
+            link,
+            props,
+            search_text: String::new(),
+            user_example_name: String::new(),
+            reader_task: None,
+            test_reader_tasks: Vec::new(),
+            test_files: Vec::new(),
+            pending_test_files: 0,
+        }
     }
 
     fn update(&mut self, msg: Self::Message) -> ShouldRender {
@@ -36,6 +127,115 @@ impl Component for Header {
                 self.props.on_load_example.emit(example);
                 true
             }
+            Msg::SearchExamples(text) => {
+                self.search_text = text;
+                true
+            }
+            Msg::UpdateUserExampleName(name) => {
+                self.user_example_name = name;
+                false
+            }
+            Msg::SaveUserExample => {
+                self.props
+                    .on_user_example
+                    .emit(UserExampleAction::Save(self.user_example_name.clone()));
+                self.user_example_name.clear();
+                true
+            }
+            Msg::RenameUserExample(index) => {
+                if let Some(example) = self.props.user_examples.get(index) {
+                    if let Some(name) = promptText("New name for the example", &example.name) {
+                        self.props
+                            .on_user_example
+                            .emit(UserExampleAction::Rename(index, name));
+                    }
+                }
+                false
+            }
+            Msg::DeleteUserExample(index) => {
+                if let Some(example) = self.props.user_examples.get(index) {
+                    if confirmAction(&format!("Delete the example '{}'?", example.name)) {
+                        self.props
+                            .on_user_example
+                            .emit(UserExampleAction::Delete(index));
+                    }
+                }
+                false
+            }
+            Msg::ImportUserExamples(file) => {
+                let callback = self.link.callback(Msg::ReceiveUserExamplesFile);
+                self.reader_task = ReaderService::new().read_file(file, callback).ok();
+                false
+            }
+            Msg::ReceiveUserExamplesFile(file) => {
+                self.reader_task = None;
+                self.props.on_user_example.emit(UserExampleAction::Import(
+                    String::from_utf8_lossy(&file.content).to_string(),
+                ));
+                false
+            }
+            Msg::ImportShexTests(files) => {
+                self.test_files.clear();
+                self.test_reader_tasks = files
+                    .into_iter()
+                    .filter_map(|file| {
+                        let callback = self.link.callback(Msg::ReceiveShexTestFile);
+                        ReaderService::new().read_file(file, callback).ok()
+                    })
+                    .collect();
+                self.pending_test_files = self.test_reader_tasks.len();
+                false
+            }
+            Msg::ReceiveShexTestFile(file) => {
+                self.test_files.push((
+                    file.name,
+                    String::from_utf8_lossy(&file.content).to_string(),
+                ));
+                self.pending_test_files = self.pending_test_files.saturating_sub(1);
+                if self.pending_test_files == 0 {
+                    self.test_reader_tasks.clear();
+                    self.props
+                        .on_import_shex_tests
+                        .emit(std::mem::take(&mut self.test_files));
+                }
+                false
+            }
+            Msg::UpdateRequestTimeout(value) => {
+                if let Ok(timeout) = value.trim().parse::<u32>() {
+                    self.props.on_update_request_policy.emit(RequestPolicy {
+                        timeout_secs: timeout.clamp(1, MAX_TIMEOUT_SECS),
+                        ..self.props.request_policy
+                    });
+                }
+                true
+            }
+            Msg::UpdateRequestAttempts(value) => {
+                if let Ok(attempts) = value.trim().parse::<u32>() {
+                    self.props.on_update_request_policy.emit(RequestPolicy {
+                        max_attempts: attempts.clamp(1, MAX_ATTEMPTS),
+                        ..self.props.request_policy
+                    });
+                }
+                true
+            }
+            Msg::ToggleCache => {
+                self.props.on_update_request_policy.emit(RequestPolicy {
+                    use_cache: !self.props.request_policy.use_cache,
+                    ..self.props.request_policy
+                });
+                false
+            }
+            Msg::TogglePersistentCache => {
+                self.props.on_update_request_policy.emit(RequestPolicy {
+                    persistent_cache: !self.props.request_policy.persistent_cache,
+                    ..self.props.request_policy
+                });
+                false
+            }
+            Msg::ClearCache => {
+                wasm_bindgen_futures::spawn_local(response_cache::clear());
+                false
+            }
             Msg::Help() => {
                 let title = "HELP";
                 let body = html! {
@@ -62,14 +262,35 @@ impl Component for Header {
                             <li>{"• html-rdfa11"}</li>
                             <li>{"• html-microdata"}</li>
                         </ul>
-                        <li>{"Shex: You can choose between ShexC and ShExJ formats."}</li>
+                        <li>{"Inference: You can apply NONE, RDFS or OWL inference to the RDF data before analyzing or validating it."}</li>
+                        <li>{"Shex: You can choose between ShexC, ShExJ and Turtle (ShExR) formats."}</li>
+                        <li>{"Engine: Choose ShEx, or SHACLex or JenaSHACL to validate the data against a SHACL shapes graph written in Turtle. SHACL validation uses the targets declared in the shapes graph instead of the ShapeMap."}</li>
                         <li>{"ShapeMap: You can choose between Compact format or JSON formats."}</li>
+                        <li>{"ShapeMap: Use node@START to validate a node against the start shape declared in the schema (start = @:Shape)."}</li>
                     </ul>
+                    <p>{"Use the 'LOAD EXAMPLE' menu to load one of the examples. They are grouped by category and can be filtered by title, description, tag or format."}</p>
+                    <p>{"Under 'shexTest' you can import a manifest.jsonld of the ShEx test suite: select the manifest together with the schema, data and map files it references. Each validation test is loaded as an example; after validating, the test is marked as passed if the result matched the expected one."}</p>
+                    <p>{"Under 'My examples' you can save the current editors with a name, load, rename or delete your saved examples and import or export them as a JSON file. They are kept in this browser."}</p>
                     <hr />
                     <p><strong>{"Analysis and Validation:"}</strong></p>
                     <ul>
                         <li>{"Use the 'Analyze' button to analyze the Shex and RDF inputs separately."}</li>
+                        <li>{"The ShEx analysis lists every shape (click one to jump to its declaration), the prefix table and warnings for unreferenced shapes, undefined references, cyclic references and unused prefixes."}</li>
+                        <li>{"The RDF analysis includes a graph of the data: drag to pan, use the wheel or the +/- buttons to zoom and search for a node by name. After a validation, nodes are coloured by their result."}</li>
+                        <li>{"Use the 'Convert' button to translate the RDF or Shex inputs into the selected format."}</li>
+                        <li>{"Use the 'To SHACL' / 'To ShEx' button to translate the schema between ShEx and SHACL. Constructs that cannot be expressed in the target language are listed as warnings."}</li>
+                        <li>{"Use the 'Diagram' button to draw the ShEx shapes as a UML-style class diagram. It can be zoomed and exported as SVG, PNG or PlantUML."}</li>
+                        <li>{"Use the 'Preview' button to see the nodes selected by each ShapeMap association, including query selectors such as {FOCUS a :Person}@:User or SPARQL \"...\"@:User, and how many nodes will be checked."}</li>
+                        <li>{"Use the 'Generate' button to propose a ShapeMap from your data: subjects are matched with the shapes named like their rdf:type, or all subjects are associated with the chosen shape."}</li>
                         <li>{"Use the 'Validate' button to validate your data. The results will be displayed in a table indicating whether your data complies with the defined shapes."}</li>
+                        <li>{"Every editor has tabs to keep several data fragments, schemas and ShapeMaps open: '+' adds a tab and double click renames it. The tabs are kept in the browser and restored in the next session."}</li>
+                        <li>{"With several tabs, the checkbox of each tab chooses whether it takes part in the validation. The included ShapeMaps are joined; the included data tabs are merged into one document or, unchecking 'Merge data' (or with formats that cannot be merged), validated one by one."}</li>
+                        <li>{"With more than one included schema tab, 'Compare N schema versions' validates the data with every version and shows a matrix with the status of each node under each version."}</li>
+                        <li>{"To validate several data files against the same schema and ShapeMap, drop them on the area below the 'Validate' button and press 'Validate N files'. All files must use the selected RDF format. The result table shows the source file of each row and a summary per file."}</li>
+                        <li>{"Under 'SETTINGS' you can change how long each request to the RDFShape server may take and how many times it is attempted. Requests that fail with a network error, a timeout or a temporary server error (502, 503, 504) are retried with increasing waits; the spinner shows the retry in progress."}</li>
+                        <li>{"Analyzing or validating content that was already sent reuses the previous answer, marked as 'Cached result'. 'Validate again' under a cached validation asks the server anyway. Under 'SETTINGS' the cache can be disabled, kept between sessions in IndexedDB or cleared."}</li>
+                        <li>{"With 'Incremental validation' checked, validating again after editing only the data compares it with the last validated version and re-validates just the ShapeMap nodes whose triples changed and the nodes that reference them through shapes. The other results are kept. ShapeMaps with query selectors are always validated in full."}</li>
+                        <li>{"While a validation is running, 'Cancel' stops it. Starting a new validation also cancels the previous one, so only the latest results are shown."}</li>
                     </ul>
                     <hr />
                     <p><strong>{"What is validation?"}</strong></p>
@@ -97,9 +318,18 @@ impl Component for Header {
                             <li class="dropdown">
                                 <button id="examples-dropdown" class="dropbtn">{"LOAD EXAMPLE"}</button>
                                 <div class="dropdown-content">
-                                    <a id="example-1" class="dropdown-btn" href="#" onclick=self.link.callback(|_| Msg::LoadExample("example1".to_string()))>{"Example 1"}</a>
-                                    <a id="example-2" class="dropdown-btn" href="#" onclick=self.link.callback(|_| Msg::LoadExample("example2".to_string()))>{"Example 2"}</a>
-                                    <a id="example-3" class="dropdown-btn" href="#" onclick=self.link.callback(|_| Msg::LoadExample("example3".to_string()))>{"Example 3"}</a>
+                                    <input id="examples-search" class="examples-search" type="text" placeholder="Search examples..."
+                                        value=self.search_text.clone()
+                                        oninput=self.link.callback(|e: InputData| Msg::SearchExamples(e.value)) />
+                                    { self.view_examples() }
+                                    { self.view_user_examples() }
+                                    { self.view_test_cases() }
+                                </div>
+                            </li>
+                            <li class="dropdown">
+                                <button id="settings-dropdown" class="dropbtn">{"SETTINGS"}</button>
+                                <div class="dropdown-content">
+                                    { self.view_request_settings() }
                                 </div>
                             </li>
                             <button class="help-btn" onclick=self.link.callback(|_| Msg::Help())>{"HELP"}</button>
@@ -110,3 +340,173 @@ impl Component for Header {
         }
     }
 }
+
+impl Header {
+    /// Renderiza la configuración del tiempo máximo, los reintentos y la caché de las solicitudes a la API.
+    fn view_request_settings(&self) -> Html {
+        html! {
+            <div id="request-settings" class="request-settings">
+                <label for="request-timeout">{ "Request timeout (s)" }</label>
+                <input id="request-timeout" type="number" min="1" max=MAX_TIMEOUT_SECS.to_string()
+                    value=self.props.request_policy.timeout_secs.to_string()
+                    onchange=self.link.batch_callback(|e: ChangeData| match e {
+                        ChangeData::Value(value) => vec![Msg::UpdateRequestTimeout(value)],
+                        _ => vec![],
+                    }) />
+                <label for="request-attempts">{ "Attempts (with retries)" }</label>
+                <input id="request-attempts" type="number" min="1" max=MAX_ATTEMPTS.to_string()
+                    value=self.props.request_policy.max_attempts.to_string()
+                    onchange=self.link.batch_callback(|e: ChangeData| match e {
+                        ChangeData::Value(value) => vec![Msg::UpdateRequestAttempts(value)],
+                        _ => vec![],
+                    }) />
+                <label class="request-settings-check">
+                    <input id="use-cache" type="checkbox" checked=self.props.request_policy.use_cache
+                        onclick=self.link.callback(|_| Msg::ToggleCache) />
+                    { "Reuse cached results" }
+                </label>
+                <label class="request-settings-check">
+                    <input id="persistent-cache" type="checkbox" checked=self.props.request_policy.persistent_cache
+                        disabled=!self.props.request_policy.use_cache
+                        onclick=self.link.callback(|_| Msg::TogglePersistentCache) />
+                    { "Keep the cache between sessions (IndexedDB)" }
+                </label>
+                <button id="clear-cache" class="user-example-action" onclick=self.link.callback(|_| Msg::ClearCache)>
+                    { "Clear cache" }
+                </button>
+            </div>
+        }
+    }
+
+    /// Renderiza los ejemplos que encajan con la búsqueda agrupados por categoría.
+    fn view_examples(&self) -> Html {
+        let examples: Vec<&ExampleInfo> = self
+            .props
+            .examples
+            .iter()
+            .filter(|example| example.matches(&self.search_text))
+            .collect();
+        if examples.is_empty() {
+            return html! { <p id="examples-empty" class="examples-empty">{ "No examples found" }</p> };
+        }
+
+        let mut categories: Vec<String> = Vec::new();
+        for example in examples.iter() {
+            if !categories.contains(&example.category()) {
+                categories.push(example.category());
+            }
+        }
+
+        html! {
+            { for categories.iter().map(|category| html! {
+                <div class="examples-category">
+                    { if categories.len() > 1 {
+                        html! { <p class="examples-category-title">{ category }</p> }
+                    } else {
+                        html! { <></> }
+                    }}
+                    { for examples.iter().filter(|example| &example.category() == category).map(|example| {
+                        let id = example.id.clone();
+                        html! {
+                            <a id=format!("example-{}", example.id) class="dropdown-btn" href="#" title=example.description.clone()
+                                onclick=self.link.callback(move |_| Msg::LoadExample(id.clone()))>
+                                { &example.title }
+                                { for example.tags.iter().skip(1).map(|tag| html! { <span class="example-tag">{ tag }</span> }) }
+                            </a>
+                        }
+                    }) }
+                </div>
+            }) }
+        }
+    }
+
+    /// Renderiza los ejemplos guardados por el usuario con sus acciones y los controles para guardar,
+    /// importar y exportar la biblioteca.
+    fn view_user_examples(&self) -> Html {
+        html! {
+            <div id="user-examples" class="examples-category">
+                <p class="examples-category-title">{ "My examples" }</p>
+                { for self.props.user_examples.iter().enumerate()
+                    .filter(|(_, example)| example.name.to_lowercase().contains(&self.search_text.trim().to_lowercase()))
+                    .map(|(index, example)| html! {
+                        <div class="user-example">
+                            <a id=format!("user-example-{}", index) class="dropdown-btn" href="#"
+                                onclick=self.props.on_user_example.reform(move |_| UserExampleAction::Load(index))>
+                                { &example.name }
+                            </a>
+                            <button class="user-example-action rename-user-example" title="Rename"
+                                onclick=self.link.callback(move |_| Msg::RenameUserExample(index))>{ "✎" }</button>
+                            <button class="user-example-action delete-user-example" title="Delete"
+                                onclick=self.link.callback(move |_| Msg::DeleteUserExample(index))>{ "✕" }</button>
+                        </div>
+                    }) }
+                <div class="user-example-save">
+                    <input id="user-example-name" type="text" placeholder="Name for the current editors"
+                        value=self.user_example_name.clone()
+                        oninput=self.link.callback(|e: InputData| Msg::UpdateUserExampleName(e.value)) />
+                    <button id="save-user-example" class="user-example-action" onclick=self.link.callback(|_| Msg::SaveUserExample)>{ "Save" }</button>
+                </div>
+                <div class="user-example-save">
+                    <label class="user-example-action" for="import-user-examples">{ "Import" }</label>
+                    <input id="import-user-examples" type="file" accept=".json,application/json" style="display: none;"
+                        onchange=self.link.batch_callback(|e: ChangeData| match e {
+                            ChangeData::Files(files) => files.get(0).map(Msg::ImportUserExamples).into_iter().collect(),
+                            _ => vec![],
+                        }) />
+                    <button id="export-user-examples" class="user-example-action"
+                        onclick=self.props.on_user_example.reform(|_| UserExampleAction::Export)>{ "Export" }</button>
+                </div>
+            </div>
+        }
+    }
+
+    /// Renderiza los tests importados de shexTest que encajan con la búsqueda y el control para importarlos.
+    fn view_test_cases(&self) -> Html {
+        let search = self.search_text.trim().to_lowercase();
+        let tests: Vec<(usize, &ShexTestCase)> = self
+            .props
+            .test_cases
+            .iter()
+            .enumerate()
+            .filter(|(_, test)| {
+                test.name.to_lowercase().contains(&search)
+                    || test.comment.to_lowercase().contains(&search)
+            })
+            .collect();
+        html! {
+            <div id="shex-tests" class="examples-category">
+                <p class="examples-category-title">{ "shexTest" }</p>
+                { for tests.iter().take(MAX_LISTED_TESTS).map(|(index, test)| {
+                    let index = *index;
+                    let class = match test.matched {
+                        Some(true) => "dropdown-btn test-passed",
+                        Some(false) => "dropdown-btn test-failed",
+                        None => "dropdown-btn",
+                    };
+                    html! {
+                        <a id=format!("shex-test-{}", index) class=class href="#" title=test.comment.clone()
+                            onclick=self.props.on_load_test_case.reform(move |_| index)>
+                            { &test.name }
+                            <span class="example-tag">{ test.expected() }</span>
+                        </a>
+                    }
+                }) }
+                { if tests.len() > MAX_LISTED_TESTS {
+                    html! { <p class="examples-empty">{ format!("{} more tests, refine the search", tests.len() - MAX_LISTED_TESTS) }</p> }
+                } else {
+                    html! { <></> }
+                }}
+                <div class="user-example-save">
+                    <label class="user-example-action" for="import-shex-tests">{ "Import manifest" }</label>
+                    <input id="import-shex-tests" type="file" multiple=true style="display: none;"
+                        onchange=self.link.batch_callback(|e: ChangeData| match e {
+                            ChangeData::Files(files) => vec![Msg::ImportShexTests(
+                                (0..files.length()).filter_map(|index| files.get(index)).collect(),
+                            )],
+                            _ => vec![],
+                        }) />
+                </div>
+            </div>
+        }
+    }
+}
@@ -1,5 +1,9 @@
+pub(crate) mod comparison_matrix;
 pub(crate) mod editors;
 pub(crate) mod header;
+pub(crate) mod rdf_graph;
 pub(crate) mod result_table;
+pub(crate) mod schema_diagram;
 pub(crate) mod search_bar;
+pub(crate) mod shapemap_editor;
 pub(crate) mod modal;
\ No newline at end of file
@@ -0,0 +1,184 @@
+use crate::app::api::ShapeMapEntry;
+use crate::app::ntriples::Triple;
+use crate::app::rdf_graph::{self, NodeStatus, RdfGraph};
+use yew::prelude::*;
+use yew::virtual_dom::VNode;
+
+/// Factor de ampliación de cada paso de zoom.
+const ZOOM_FACTOR: f64 = 1.25;
+/// Zoom mínimo y máximo permitido.
+const ZOOM_LIMITS: (f64, f64) = (0.25, 4.0);
+
+#[derive(Properties, Clone)]
+pub struct Props {
+    /// Tripletas de los datos RDF que se dibujan.
+    pub triples: Vec<Triple>,
+    /// Entradas del último resultado de validación con las que se colorean los nodos.
+    #[prop_or_default]
+    pub entries: Vec<ShapeMapEntry>,
+}
+
+/// Estado y lógica del componente `RdfGraphView`.
+pub struct RdfGraphView {
+    link: ComponentLink<Self>,
+    graph: RdfGraph,
+    search_text: String,
+    matches: Vec<usize>,
+    svg: String,
+    zoom: f64,
+    offset: (f64, f64),
+    drag_start: Option<(i32, i32)>,
+}
+
+/// Mensajes utilizados por `RdfGraphView` para manejar eventos de la interfaz de usuario.
+pub enum Msg {
+    /// Busca los nodos que contienen el texto y centra la vista en el primero.
+    Search(String),
+    /// Amplía la vista.
+    ZoomIn,
+    /// Reduce la vista.
+    ZoomOut,
+    /// Vuelve al zoom y a la posición iniciales.
+    ResetView,
+    /// Comienza a desplazar la vista desde la posición indicada del ratón.
+    StartPan(i32, i32),
+    /// Desplaza la vista hasta la posición indicada del ratón.
+    Pan(i32, i32),
+    /// Termina el desplazamiento de la vista.
+    EndPan,
+}
+
+impl Component for RdfGraphView {
+    type Message = Msg;
+    type Properties = Props;
+
+    /// Construye y coloca el grafo a partir de las tripletas recibidas.
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let graph = RdfGraph::new(&props.triples, &props.entries);
+        let svg = graph.to_svg(&[]);
+        Self {
+            link,
+            graph,
+            search_text: String::new(),
+            matches: Vec::new(),
+            svg,
+            zoom: 1.0,
+            offset: (0.0, 0.0),
+            drag_start: None,
+        }
+    }
+
+    /// Actualiza la búsqueda, el zoom o el desplazamiento de la vista.
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Search(text) => {
+                self.matches = self.graph.search(&text);
+                self.search_text = text;
+                self.svg = self.graph.to_svg(&self.matches);
+                if let Some(node) = self.matches.first().map(|index| &self.graph.nodes[*index]) {
+                    let (width, height) = RdfGraph::size();
+                    self.offset = (
+                        width / 2.0 - node.x * self.zoom,
+                        height / 2.0 - node.y * self.zoom,
+                    );
+                }
+                true
+            }
+            Msg::ZoomIn => {
+                self.zoom = (self.zoom * ZOOM_FACTOR).min(ZOOM_LIMITS.1);
+                true
+            }
+            Msg::ZoomOut => {
+                self.zoom = (self.zoom / ZOOM_FACTOR).max(ZOOM_LIMITS.0);
+                true
+            }
+            Msg::ResetView => {
+                self.zoom = 1.0;
+                self.offset = (0.0, 0.0);
+                true
+            }
+            Msg::StartPan(x, y) => {
+                self.drag_start = Some((x, y));
+                false
+            }
+            Msg::Pan(x, y) => match self.drag_start {
+                Some((start_x, start_y)) => {
+                    self.offset.0 += (x - start_x) as f64;
+                    self.offset.1 += (y - start_y) as f64;
+                    self.drag_start = Some((x, y));
+                    true
+                }
+                None => false,
+            },
+            Msg::EndPan => {
+                self.drag_start = None;
+                false
+            }
+        }
+    }
+
+    /// Reconstruye el grafo cuando cambian las tripletas o el resultado de validación.
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        self.graph = RdfGraph::new(&props.triples, &props.entries);
+        self.matches = self.graph.search(&self.search_text);
+        self.svg = self.graph.to_svg(&self.matches);
+        true
+    }
+
+    /// Renderiza la barra de herramientas, el resumen y el grafo.
+    fn view(&self) -> Html {
+        if self.graph.nodes.is_empty() {
+            return html! { <p id="rdf-graph-empty">{ "The RDF data has no triples to draw." }</p> };
+        }
+
+        html! {
+            <div class="rdf-graph-container">
+                <div class="rdf-graph-tools">
+                    <input id="rdf-graph-search" class="rdf-graph-search" type="text" placeholder="Search node..."
+                        value=self.search_text.clone()
+                        oninput=self.link.callback(|e: InputData| Msg::Search(e.value)) />
+                    <span id="rdf-graph-matches">{ format!("{} match(es)", self.matches.len()) }</span>
+                    <button id="rdf-graph-zoom-out" class="analyze-btn" onclick=self.link.callback(|_| Msg::ZoomOut)>{ "-" }</button>
+                    <button id="rdf-graph-zoom-in" class="analyze-btn" onclick=self.link.callback(|_| Msg::ZoomIn)>{ "+" }</button>
+                    <button id="rdf-graph-reset" class="analyze-btn" onclick=self.link.callback(|_| Msg::ResetView)>{ "Reset" }</button>
+                </div>
+                <p id="rdf-graph-summary" class="rdf-graph-summary">
+                    { format!("{} node(s), {} edge(s) — ", self.graph.nodes.len(), self.graph.edges.len()) }
+                    <span class="rdf-graph-legend status-invalid">{ format!("{} invalid", self.graph.count(NodeStatus::Invalid)) }</span>
+                    <span class="rdf-graph-legend status-valid">{ format!("{} valid", self.graph.count(NodeStatus::Valid)) }</span>
+                    <span class="rdf-graph-legend status-unchecked">{ format!("{} not validated", self.graph.count(NodeStatus::Unchecked)) }</span>
+                </p>
+                { if self.graph.truncated {
+                    html! { <p id="rdf-graph-truncated" class="format-notice">{ format!("Only the first {} nodes are drawn.", rdf_graph::MAX_NODES) }</p> }
+                } else {
+                    html! { <></> }
+                }}
+                <div class="rdf-graph-viewport"
+                    onmousedown=self.link.callback(|e: MouseEvent| Msg::StartPan(e.client_x(), e.client_y()))
+                    onmousemove=self.link.callback(|e: MouseEvent| Msg::Pan(e.client_x(), e.client_y()))
+                    onmouseup=self.link.callback(|_| Msg::EndPan)
+                    onmouseleave=self.link.callback(|_| Msg::EndPan)
+                    onwheel=self.link.callback(|e: WheelEvent| {
+                        e.prevent_default();
+                        if e.delta_y() < 0.0 { Msg::ZoomIn } else { Msg::ZoomOut }
+                    })>
+                    <div id="rdf-graph" class="rdf-graph"
+                        style=format!("transform: translate({}px, {}px) scale({}); transform-origin: 0 0;", self.offset.0, self.offset.1, self.zoom)>
+                        { self.view_svg() }
+                    </div>
+                </div>
+            </div>
+        }
+    }
+}
+
+impl RdfGraphView {
+    /// Inserta el SVG del grafo como nodo del DOM, ya que `html!` no admite marcado sin procesar.
+    fn view_svg(&self) -> Html {
+        let container = yew::utils::document()
+            .create_element("div")
+            .expect("No se ha podido crear el contenedor del grafo");
+        container.set_inner_html(&self.svg);
+        VNode::VRef(container.into())
+    }
+}
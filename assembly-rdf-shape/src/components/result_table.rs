@@ -1,4 +1,5 @@
 use crate::app::api::ShapeMapEntry;
+use crate::app::batch_validation::BatchFileSummary;
 use crate::components::search_bar::SearchBar;
 use wasm_bindgen::prelude::*;
 use yew::prelude::*;
@@ -30,6 +31,15 @@ pub struct Props {
     pub search_text: String,
     /// Callback para abrir un modal con información detallada.
     pub on_open_modal: Callback<(String, Html)>,
+    /// Forma inicial del esquema con la que se han validado las asociaciones `@START`.
+    #[prop_or_default]
+    pub start_shape: Option<String>,
+    /// Régimen de inferencia con el que se han validado los datos.
+    #[prop_or_default]
+    pub inference: String,
+    /// Resumen por fichero de la validación por lotes; vacío si se ha validado un único documento.
+    #[prop_or_default]
+    pub batch_summary: Vec<BatchFileSummary>,
 }
 
 /// Estado y lógica del componente `ResultTable`.
@@ -133,10 +143,9 @@ impl Component for ResultTable {
             .entries
             .iter()
             .filter(|entry| {
-                entry
-                    .node
-                    .to_lowercase()
-                    .contains(&self.props.search_text.to_lowercase())
+                let search_text = self.props.search_text.to_lowercase();
+                entry.node.to_lowercase().contains(&search_text)
+                    || entry.source.to_lowercase().contains(&search_text)
             })
             .collect();
 
@@ -153,17 +162,44 @@ impl Component for ResultTable {
 
         html! {
             <div class="result" id="result">
+                { self.view_batch_summary() }
                 <div class="table-controls">
                     <SearchBar on_search=self.link.callback(Msg::UpdateSearchText) />
+                    { if !self.props.inference.is_empty() {
+                        html! {
+                            <span id="inference-used" class="inference-used">
+                                { format!("Inference: {}", self.props.inference) }
+                            </span>
+                        }
+                    } else {
+                        html! { <></> }
+                    }}
                     <button id="export-btn" class="download-btn" onclick=self.link.callback(|_| Msg::ExportToCsv)> <i class="fas fa-download"></i></button>
                 </div>
                 <table id="result-table">
-                    <tr>
-                        <th>{"Node"}</th>
-                        <th>{"Shape"}</th>
-                        <th>{"Status"}</th>
-                        <th class="details-col">{"Details"}</th>
-                    </tr>
+                    { if self.is_shacl() {
+                        html! {
+                            <tr>
+                                { self.view_source_header() }
+                                <th>{"Focus node"}</th>
+                                <th>{"Source shape"}</th>
+                                <th>{"Severity"}</th>
+                                <th>{"Path"}</th>
+                                <th>{"Value"}</th>
+                                <th class="details-col">{"Details"}</th>
+                            </tr>
+                        }
+                    } else {
+                        html! {
+                            <tr>
+                                { self.view_source_header() }
+                                <th>{"Node"}</th>
+                                <th>{"Shape"}</th>
+                                <th>{"Status"}</th>
+                                <th class="details-col">{"Details"}</th>
+                            </tr>
+                        }
+                    }}
                     { for entries_to_display.iter().map(|entry| self.view_entry(entry)) }
                 </table>
                 { self.view_pagination() }
@@ -181,11 +217,31 @@ impl ResultTable {
 
     /// Formatea las entradas actuales para la exportación CSV.
     fn format_csv_data(&self) -> String {
-        let header = "Node;Shape;Status;Reason\n".to_string();
+        if self.is_shacl() {
+            let header = format!(
+                "{}Focus node;Source shape;Severity;Path;Value;Message\n",
+                self.source_csv_header()
+            );
+            return self.props.entries.iter().fold(header, |acc, entry| {
+                format!(
+                    "{}{}{};{};{};{};{};{}\n",
+                    acc,
+                    self.source_csv_cell(entry),
+                    entry.node,
+                    entry.shape,
+                    entry.severity,
+                    entry.path,
+                    entry.value,
+                    entry.reason.replace('\n', "")
+                )
+            });
+        }
+        let header = format!("{}Node;Shape;Status;Reason\n", self.source_csv_header());
         let csv_data = self.props.entries.iter().fold(header, |acc, entry| {
             format!(
-                "{}{};{};{};{}\n",
+                "{}{}{};{};{};{}\n",
                 acc,
+                self.source_csv_cell(entry),
                 entry.node,
                 entry.shape,
                 entry.status,
@@ -195,8 +251,45 @@ impl ResultTable {
         csv_data
     }
 
+    /// Indica si las entradas proceden de una validación por lotes y llevan el fichero de origen.
+    fn has_sources(&self) -> bool {
+        self.props
+            .entries
+            .iter()
+            .any(|entry| !entry.source.is_empty())
+    }
+
+    /// Cabecera de la columna del fichero de origen en el CSV, vacía si no es una validación por lotes.
+    fn source_csv_header(&self) -> &'static str {
+        if self.has_sources() {
+            "Source file;"
+        } else {
+            ""
+        }
+    }
+
+    /// Celda del fichero de origen de una entrada en el CSV.
+    fn source_csv_cell(&self, entry: &ShapeMapEntry) -> String {
+        if self.has_sources() {
+            format!("{};", entry.source)
+        } else {
+            String::new()
+        }
+    }
+
+    /// Indica si las entradas proceden de un informe de validación SHACL.
+    fn is_shacl(&self) -> bool {
+        self.props
+            .entries
+            .iter()
+            .any(|entry| !entry.severity.is_empty())
+    }
+
     /// Renderiza una entrada individual en la tabla.
     fn view_entry(&self, entry: &ShapeMapEntry) -> Html {
+        if self.is_shacl() {
+            return self.view_shacl_entry(entry);
+        }
         let node = entry.node.clone();
         let content = html! {
             <>
@@ -206,7 +299,7 @@ impl ResultTable {
                 <hr />
                 <hr />
                 <p><strong>{"Shape"}</strong></p>
-                <p>{entry.shape.clone()}</p>
+                <p>{ self.view_shape(&entry.shape) }</p>
                 <hr />
                 <p><strong>{"Reason"}</strong></p>
                 <p>{entry.reason.clone()}</p>
@@ -215,8 +308,9 @@ impl ResultTable {
 
         html! {
             <tr class={ if entry.status == "Valid" { "valid" } else { "invalid" } }>
+                { self.view_source_cell(entry) }
                 <td>{ &entry.node }</td>
-                <td>{ &entry.shape }</td>
+                <td>{ self.view_shape(&entry.shape) }</td>
                 <td class="details-row">{ &entry.status }</td>
                 <td>
                     <button type="button" class="show-btn" onclick=self.link.callback(move |_| Msg::OpenModal(node.clone(), content.clone()))>
@@ -227,6 +321,108 @@ impl ResultTable {
         }
     }
 
+    /// Renderiza un resultado de un informe de validación SHACL.
+    fn view_shacl_entry(&self, entry: &ShapeMapEntry) -> Html {
+        let node = entry.node.clone();
+        let content = html! {
+            <>
+                <hr />
+                <p><strong>{"Severity"}</strong></p>
+                <p>{entry.severity.clone()}</p>
+                <hr />
+                <p><strong>{"Source shape"}</strong></p>
+                <p>{entry.shape.clone()}</p>
+                <hr />
+                <p><strong>{"Path"}</strong></p>
+                <p>{entry.path.clone()}</p>
+                <hr />
+                <p><strong>{"Value"}</strong></p>
+                <p>{entry.value.clone()}</p>
+                <hr />
+                <p><strong>{"Message"}</strong></p>
+                <p>{entry.reason.clone()}</p>
+            </>
+        };
+
+        html! {
+            <tr class={ format!("invalid severity-{}", entry.severity.to_lowercase()) }>
+                { self.view_source_cell(entry) }
+                <td>{ &entry.node }</td>
+                <td>{ &entry.shape }</td>
+                <td class="details-row">{ &entry.severity }</td>
+                <td>{ &entry.path }</td>
+                <td>{ &entry.value }</td>
+                <td>
+                    <button type="button" class="show-btn" onclick=self.link.callback(move |_| Msg::OpenModal(node.clone(), content.clone()))>
+                        <i class="fas fa-plus"></i>
+                    </button>
+                </td>
+            </tr>
+        }
+    }
+
+    /// Renderiza la cabecera de la columna del fichero de origen en las validaciones por lotes.
+    fn view_source_header(&self) -> Html {
+        if self.has_sources() {
+            html! { <th>{"Source file"}</th> }
+        } else {
+            html! { <></> }
+        }
+    }
+
+    /// Renderiza el fichero de origen de una entrada en las validaciones por lotes.
+    fn view_source_cell(&self, entry: &ShapeMapEntry) -> Html {
+        if self.has_sources() {
+            html! { <td class="source-file">{ &entry.source }</td> }
+        } else {
+            html! { <></> }
+        }
+    }
+
+    /// Renderiza el resumen por fichero de la validación por lotes.
+    fn view_batch_summary(&self) -> Html {
+        if self.props.batch_summary.is_empty() {
+            return html! { <></> };
+        }
+        html! {
+            <table id="batch-summary" class="batch-summary">
+                <tr>
+                    <th>{"Source file"}</th>
+                    <th>{"Valid"}</th>
+                    <th>{"Invalid"}</th>
+                    <th>{"Error"}</th>
+                </tr>
+                { for self.props.batch_summary.iter().map(|summary| html! {
+                    <tr class={ if summary.error.is_empty() && summary.invalid == 0 { "valid" } else { "invalid" } }>
+                        <td>{ &summary.file }</td>
+                        <td>{ summary.valid }</td>
+                        <td>{ summary.invalid }</td>
+                        <td>{ &summary.error }</td>
+                    </tr>
+                }) }
+            </table>
+        }
+    }
+
+    /// Renderiza la forma de una entrada, distinguiendo la forma inicial del esquema (`START`).
+    fn view_shape(&self, shape: &str) -> Html {
+        if !is_start_shape(shape) {
+            return html! { { shape } };
+        }
+
+        html! {
+            <>
+                <span class="start-shape-badge">{"START"}</span>
+                { match &self.props.start_shape {
+                    Some(start_shape) => html! {
+                        <span class="start-shape-label">{ start_shape }</span>
+                    },
+                    None => html! { <></> },
+                }}
+            </>
+        }
+    }
+
     /// Renderiza los controles de paginación.
     fn view_pagination(&self) -> Html {
         let max_page = self.max_page();
@@ -272,3 +468,14 @@ impl ResultTable {
         }
     }
 }
+
+/// Indica si la forma de una entrada es la forma inicial del esquema (`START`).
+///
+/// `format_shape_maps` antepone `:` a las formas, por lo que también se reconoce `:START`.
+fn is_start_shape(shape: &str) -> bool {
+    shape
+        .trim()
+        .trim_start_matches(['<', ':'])
+        .trim_end_matches('>')
+        .eq_ignore_ascii_case("START")
+}
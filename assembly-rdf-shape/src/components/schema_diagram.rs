@@ -0,0 +1,163 @@
+use crate::app::schema_diagram::{self, DiagramShape};
+use wasm_bindgen::prelude::*;
+use yew::prelude::*;
+use yew::virtual_dom::VNode;
+
+/// Declaración de las funciones JavaScript para exportar el diagrama como texto o como imagen PNG
+#[wasm_bindgen(inline_js = r#"
+    function download(url, fileName) {
+        const link = document.createElement('a');
+        link.setAttribute('href', url);
+        link.setAttribute('download', fileName);
+        link.style.visibility = 'hidden';
+        document.body.appendChild(link);
+        link.click();
+        document.body.removeChild(link);
+    }
+
+    export function exportText(content, fileName, mimeType) {
+        const url = URL.createObjectURL(new Blob([content], { type: mimeType }));
+        download(url, fileName);
+        URL.revokeObjectURL(url);
+    }
+
+    export function exportSvgAsPng(svg, fileName) {
+        const url = URL.createObjectURL(new Blob([svg], { type: 'image/svg+xml;charset=utf-8' }));
+        const image = new Image();
+        image.onload = () => {
+            const canvas = document.createElement('canvas');
+            canvas.width = image.width;
+            canvas.height = image.height;
+            canvas.getContext('2d').drawImage(image, 0, 0);
+            URL.revokeObjectURL(url);
+            download(canvas.toDataURL('image/png'), fileName);
+        };
+        image.src = url;
+    }
+"#)]
+extern "C" {
+    pub fn exportText(content: &str, fileName: &str, mimeType: &str);
+    pub fn exportSvgAsPng(svg: &str, fileName: &str);
+}
+
+/// Paso de ampliación de los botones de zoom.
+const ZOOM_STEP: f64 = 0.25;
+
+#[derive(Properties, Clone)]
+pub struct Props {
+    /// Formas del esquema que se dibujan en el diagrama.
+    pub shapes: Vec<DiagramShape>,
+}
+
+/// Estado y lógica del componente `SchemaDiagram`.
+pub struct SchemaDiagram {
+    link: ComponentLink<Self>,
+    props: Props,
+    zoom: f64,
+}
+
+/// Mensajes utilizados por `SchemaDiagram` para manejar eventos de la interfaz de usuario.
+pub enum Msg {
+    /// Amplía el diagrama.
+    ZoomIn,
+    /// Reduce el diagrama.
+    ZoomOut,
+    /// Descarga el diagrama como imagen SVG.
+    ExportSvg,
+    /// Descarga el diagrama como imagen PNG.
+    ExportPng,
+    /// Descarga el diagrama como texto PlantUML.
+    ExportPlantUml,
+}
+
+impl Component for SchemaDiagram {
+    type Message = Msg;
+    type Properties = Props;
+
+    /// Crea el componente con el diagrama a su tamaño original.
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            props,
+            zoom: 1.0,
+        }
+    }
+
+    /// Actualiza el zoom o exporta el diagrama según el mensaje recibido.
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::ZoomIn => {
+                self.zoom += ZOOM_STEP;
+                true
+            }
+            Msg::ZoomOut => {
+                if self.zoom > ZOOM_STEP {
+                    self.zoom -= ZOOM_STEP;
+                }
+                true
+            }
+            Msg::ExportSvg => {
+                exportText(
+                    &schema_diagram::to_svg(&self.props.shapes),
+                    "schema.svg",
+                    "image/svg+xml;charset=utf-8",
+                );
+                false
+            }
+            Msg::ExportPng => {
+                exportSvgAsPng(&schema_diagram::to_svg(&self.props.shapes), "schema.png");
+                false
+            }
+            Msg::ExportPlantUml => {
+                exportText(
+                    &schema_diagram::to_plantuml(&self.props.shapes),
+                    "schema.puml",
+                    "text/plain;charset=utf-8",
+                );
+                false
+            }
+        }
+    }
+
+    /// Actualiza las formas del diagrama cuando cambian las propiedades.
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        self.props = props;
+        true
+    }
+
+    /// Renderiza la barra de herramientas y el diagrama SVG.
+    fn view(&self) -> Html {
+        if self.props.shapes.is_empty() {
+            return html! { <p id="schema-diagram-empty">{ "The schema has no shapes to draw." }</p> };
+        }
+
+        html! {
+            <div class="schema-diagram-container">
+                <div class="schema-diagram-tools">
+                    <button id="diagram-zoom-out" class="analyze-btn" onclick=self.link.callback(|_| Msg::ZoomOut)>{ "-" }</button>
+                    <span id="diagram-zoom" class="schema-diagram-zoom">{ format!("{:.0}%", self.zoom * 100.0) }</span>
+                    <button id="diagram-zoom-in" class="analyze-btn" onclick=self.link.callback(|_| Msg::ZoomIn)>{ "+" }</button>
+                    <button id="export-svg" class="analyze-btn" onclick=self.link.callback(|_| Msg::ExportSvg)>{ "SVG" }</button>
+                    <button id="export-png" class="analyze-btn" onclick=self.link.callback(|_| Msg::ExportPng)>{ "PNG" }</button>
+                    <button id="export-plantuml" class="analyze-btn" onclick=self.link.callback(|_| Msg::ExportPlantUml)>{ "PlantUML" }</button>
+                </div>
+                <div class="schema-diagram-viewport">
+                    <div id="schema-diagram" class="schema-diagram" style=format!("transform: scale({}); transform-origin: 0 0;", self.zoom)>
+                        { self.view_svg() }
+                    </div>
+                </div>
+            </div>
+        }
+    }
+}
+
+impl SchemaDiagram {
+    /// Inserta el SVG generado como nodo del DOM, ya que `html!` no admite marcado sin procesar.
+    fn view_svg(&self) -> Html {
+        let container = yew::utils::document()
+            .create_element("div")
+            .expect("No se ha podido crear el contenedor del diagrama");
+        container.set_inner_html(&schema_diagram::to_svg(&self.props.shapes));
+        VNode::VRef(container.into())
+    }
+}
@@ -0,0 +1,378 @@
+use crate::app::shapemap::{self, NodeSelector, ShapeLabel};
+use web_sys::HtmlTextAreaElement;
+use yew::prelude::*;
+
+/// Número máximo de sugerencias que se muestran a la vez.
+const MAX_SUGGESTIONS: usize = 8;
+
+/// Propiedades del componente `ShapeMapEditor`.
+#[derive(Properties, Clone, PartialEq)]
+pub struct Props {
+    /// Contenido actual del ShapeMap.
+    pub value: String,
+    /// Nodos de los datos RDF que se ofrecen al autocompletar.
+    pub node_suggestions: Vec<String>,
+    /// Clases (objetos de `rdf:type`) de los datos RDF que se ofrecen al autocompletar.
+    pub class_suggestions: Vec<String>,
+    /// Etiquetas de las formas del esquema que se ofrecen tras `@`.
+    pub shape_suggestions: Vec<String>,
+    /// Callback que recibe el nuevo contenido del ShapeMap.
+    pub on_change: Callback<String>,
+    /// Callback que se avisa cuando el usuario empieza a editar, para cargar las sugerencias.
+    #[prop_or_default]
+    pub on_focus: Callback<()>,
+}
+
+/// Componente `ShapeMapEditor` que muestra un editor de ShapeMap con resaltado de sintaxis,
+/// autocompletado de nodos y formas, y un formulario para añadir asociaciones sin escribirlas.
+pub struct ShapeMapEditor {
+    link: ComponentLink<Self>,
+    props: Props,
+    textarea_ref: NodeRef,
+    highlight_ref: NodeRef,
+    suggestions: Vec<String>,
+    selected_suggestion: usize,
+    token_start: usize,
+    cursor: usize,
+    pending_cursor: Option<usize>,
+    builder_mode: String,
+    builder_node: String,
+    builder_shape: String,
+}
+
+/// Mensajes del componente `ShapeMapEditor`.
+pub enum Msg {
+    /// El usuario ha modificado el texto del ShapeMap.
+    Input(String),
+    /// El usuario ha pulsado una tecla con la lista de sugerencias abierta.
+    KeyDown(String),
+    /// Sustituye el fragmento que se está escribiendo por la sugerencia indicada.
+    AcceptSuggestion(usize),
+    /// Cierra la lista de sugerencias.
+    CloseSuggestions,
+    /// Sincroniza el desplazamiento del resaltado con el del área de texto.
+    SyncScroll,
+    /// Actualiza el tipo de selector del formulario (`node` o `type`).
+    UpdateBuilderMode(String),
+    /// Actualiza el nodo o clase del formulario.
+    UpdateBuilderNode(String),
+    /// Actualiza la forma del formulario.
+    UpdateBuilderShape(String),
+    /// Añade al ShapeMap la asociación definida en el formulario.
+    AddAssociation,
+}
+
+impl Component for ShapeMapEditor {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            props,
+            textarea_ref: NodeRef::default(),
+            highlight_ref: NodeRef::default(),
+            suggestions: Vec::new(),
+            selected_suggestion: 0,
+            token_start: 0,
+            cursor: 0,
+            pending_cursor: None,
+            builder_mode: "node".to_string(),
+            builder_node: String::new(),
+            builder_shape: String::new(),
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Input(value) => {
+                self.cursor = self
+                    .textarea_ref
+                    .cast::<HtmlTextAreaElement>()
+                    .and_then(|textarea| textarea.selection_start().ok().flatten())
+                    .map(|cursor| cursor as usize)
+                    .unwrap_or_else(|| value.chars().count());
+                self.update_suggestions(&value);
+                self.props.value = value.clone();
+                self.props.on_change.emit(value);
+                true
+            }
+            Msg::KeyDown(key) => match key.as_str() {
+                "ArrowDown" => {
+                    self.selected_suggestion =
+                        (self.selected_suggestion + 1) % self.suggestions.len().max(1);
+                    true
+                }
+                "ArrowUp" => {
+                    self.selected_suggestion = self
+                        .selected_suggestion
+                        .checked_sub(1)
+                        .unwrap_or_else(|| self.suggestions.len().saturating_sub(1));
+                    true
+                }
+                "Enter" | "Tab" => {
+                    self.link
+                        .send_message(Msg::AcceptSuggestion(self.selected_suggestion));
+                    false
+                }
+                "Escape" => {
+                    self.suggestions.clear();
+                    true
+                }
+                _ => false,
+            },
+            Msg::AcceptSuggestion(index) => {
+                if let Some(suggestion) = self.suggestions.get(index).cloned() {
+                    let chars: Vec<char> = self.props.value.chars().collect();
+                    let cursor = self.cursor.min(chars.len());
+                    let start = self.token_start.min(cursor);
+                    let value: String = chars[..start]
+                        .iter()
+                        .chain(suggestion.chars().collect::<Vec<char>>().iter())
+                        .chain(chars[cursor..].iter())
+                        .collect();
+                    self.pending_cursor = Some(start + suggestion.chars().count());
+                    self.suggestions.clear();
+                    self.props.value = value.clone();
+                    self.props.on_change.emit(value);
+                }
+                true
+            }
+            Msg::CloseSuggestions => {
+                self.suggestions.clear();
+                true
+            }
+            Msg::SyncScroll => {
+                if let (Some(textarea), Some(highlight)) = (
+                    self.textarea_ref.cast::<HtmlTextAreaElement>(),
+                    self.highlight_ref.cast::<web_sys::Element>(),
+                ) {
+                    highlight.set_scroll_top(textarea.scroll_top());
+                    highlight.set_scroll_left(textarea.scroll_left());
+                }
+                false
+            }
+            Msg::UpdateBuilderMode(mode) => {
+                self.builder_mode = mode;
+                self.builder_node.clear();
+                true
+            }
+            Msg::UpdateBuilderNode(node) => {
+                self.builder_node = node;
+                true
+            }
+            Msg::UpdateBuilderShape(shape) => {
+                self.builder_shape = shape;
+                true
+            }
+            Msg::AddAssociation => {
+                let node = self.builder_node.trim().to_string();
+                let shape = self.builder_shape.trim().to_string();
+                if node.is_empty() || shape.is_empty() {
+                    return false;
+                }
+
+                let node = if self.builder_mode == "type" {
+                    NodeSelector::TriplePattern {
+                        subject: "FOCUS".to_string(),
+                        predicate: "a".to_string(),
+                        object: node,
+                    }
+                } else {
+                    NodeSelector::Node(node)
+                };
+                let association = shapemap::format_association(&node, &ShapeLabel::Label(shape));
+                let value = shapemap::append_association(&self.props.value, &association);
+                self.builder_node.clear();
+                self.props.value = value.clone();
+                self.props.on_change.emit(value);
+                true
+            }
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn rendered(&mut self, _first_render: bool) {
+        if let Some(cursor) = self.pending_cursor.take() {
+            if let Some(textarea) = self.textarea_ref.cast::<HtmlTextAreaElement>() {
+                let _ = textarea.focus();
+                let _ = textarea.set_selection_range(cursor as u32, cursor as u32);
+            }
+        }
+        self.link.send_message(Msg::SyncScroll);
+    }
+
+    fn view(&self) -> Html {
+        let suggestions_open = !self.suggestions.is_empty();
+
+        html! {
+            <div class="shapemap-editor-container">
+                <div class="shapemap-editor-wrapper">
+                    <pre ref=self.highlight_ref.clone() class="shapemap-highlight" aria-hidden="true">
+                        { for shapemap::tokenize(&self.props.value).into_iter().map(|(kind, text)| html! {
+                            <span class=kind.css_class()>{ text }</span>
+                        }) }
+                        { "\n" }
+                    </pre>
+                    <textarea
+                        ref=self.textarea_ref.clone()
+                        id="shapemap-editor"
+                        class="shapemap-editor"
+                        spellcheck="false"
+                        value=&self.props.value
+                        oninput=self.link.callback(|e: InputData| Msg::Input(e.value))
+                        onscroll=self.link.callback(|_| Msg::SyncScroll)
+                        onfocus=self.props.on_focus.reform(|_| ())
+                        onblur=self.link.callback(|_| Msg::CloseSuggestions)
+                        onkeydown=self.link.callback(move |e: KeyboardEvent| {
+                            let key = e.key();
+                            if suggestions_open
+                                && ["ArrowDown", "ArrowUp", "Enter", "Tab", "Escape"].contains(&key.as_str())
+                            {
+                                e.prevent_default();
+                                Msg::KeyDown(key)
+                            } else {
+                                Msg::KeyDown(String::new())
+                            }
+                        })
+                    />
+                    { self.view_suggestions() }
+                </div>
+                { self.view_builder() }
+            </div>
+        }
+    }
+}
+
+impl ShapeMapEditor {
+    /// Recalcula las sugerencias para el fragmento que termina en la posición del cursor.
+    fn update_suggestions(&mut self, value: &str) {
+        let chars: Vec<char> = value.chars().collect();
+        let cursor = self.cursor.min(chars.len());
+        let mut start = cursor;
+        while start > 0 && !is_separator(chars[start - 1]) {
+            start -= 1;
+        }
+        self.token_start = start;
+        self.selected_suggestion = 0;
+
+        let token: String = chars[start..cursor]
+            .iter()
+            .collect::<String>()
+            .to_lowercase();
+        if token.is_empty() {
+            self.suggestions.clear();
+            return;
+        }
+
+        let candidates: Vec<&String> = if start > 0 && chars[start - 1] == '@' {
+            self.props.shape_suggestions.iter().collect()
+        } else {
+            self.props
+                .node_suggestions
+                .iter()
+                .chain(self.props.class_suggestions.iter())
+                .collect()
+        };
+
+        let mut suggestions: Vec<String> = Vec::new();
+        for candidate in candidates {
+            let lowercase = candidate.to_lowercase();
+            if lowercase.contains(&token) && lowercase != token && !suggestions.contains(candidate)
+            {
+                suggestions.push(candidate.clone());
+            }
+            if suggestions.len() == MAX_SUGGESTIONS {
+                break;
+            }
+        }
+        self.suggestions = suggestions;
+    }
+
+    /// Renderiza la lista de sugerencias de autocompletado.
+    fn view_suggestions(&self) -> Html {
+        if self.suggestions.is_empty() {
+            return html! { <></> };
+        }
+
+        html! {
+            <ul id="shapemap-suggestions" class="shapemap-suggestions">
+                { for self.suggestions.iter().enumerate().map(|(index, suggestion)| html! {
+                    <li
+                        class={ if index == self.selected_suggestion { "shapemap-suggestion active" } else { "shapemap-suggestion" } }
+                        onmousedown=self.link.callback(move |e: MouseEvent| {
+                            e.prevent_default();
+                            Msg::AcceptSuggestion(index)
+                        })
+                    >
+                        { suggestion }
+                    </li>
+                }) }
+            </ul>
+        }
+    }
+
+    /// Renderiza el formulario para añadir asociaciones `nodo@forma` o `{FOCUS a Clase}@forma`.
+    fn view_builder(&self) -> Html {
+        let node_options = if self.builder_mode == "type" {
+            &self.props.class_suggestions
+        } else {
+            &self.props.node_suggestions
+        };
+
+        html! {
+            <div id="shapemap-builder" class="shapemap-builder">
+                <select
+                    id="builder-mode"
+                    title={"Node selector"}
+                    class="select parameters"
+                    onchange=self.link.callback(|e: ChangeData| match e {
+                        ChangeData::Select(select) => Msg::UpdateBuilderMode(select.value()),
+                        _ => Msg::UpdateBuilderMode("node".to_string()),
+                    })
+                >
+                    <option class="option-parameters" value="node" selected={self.builder_mode == "node"}>{"Node"}</option>
+                    <option class="option-parameters" value="type" selected={self.builder_mode == "type"}>{"Instances of"}</option>
+                </select>
+                <input
+                    id="builder-node"
+                    class="builder-input"
+                    list="builder-node-options"
+                    placeholder={ if self.builder_mode == "type" { "Class" } else { "Node" } }
+                    value=&self.builder_node
+                    onfocus=self.props.on_focus.reform(|_| ())
+                    oninput=self.link.callback(|e: InputData| Msg::UpdateBuilderNode(e.value))
+                />
+                <datalist id="builder-node-options">
+                    { for node_options.iter().map(|option| html! { <option value={option} /> }) }
+                </datalist>
+                <span class="builder-at">{"@"}</span>
+                <input
+                    id="builder-shape"
+                    class="builder-input"
+                    list="builder-shape-options"
+                    placeholder="Shape"
+                    value=&self.builder_shape
+                    oninput=self.link.callback(|e: InputData| Msg::UpdateBuilderShape(e.value))
+                />
+                <datalist id="builder-shape-options">
+                    { for self.props.shape_suggestions.iter().map(|option| html! { <option value={option} /> }) }
+                </datalist>
+                <button id="builder-add" class="analyze-btn" onclick=self.link.callback(|_| Msg::AddAssociation)>{"Add"}</button>
+            </div>
+        }
+    }
+}
+
+/// Indica si el carácter separa fragmentos del ShapeMap a efectos de autocompletado.
+fn is_separator(c: char) -> bool {
+    c.is_whitespace() || c == ',' || c == '@' || c == '{' || c == '}'
+}
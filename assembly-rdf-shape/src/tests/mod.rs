@@ -1,10 +1,34 @@
 mod tests_u_2;
 
 mod tests_i_1;
+mod tests_i_10;
+mod tests_i_11;
+mod tests_i_12;
+mod tests_i_13;
+mod tests_i_14;
+mod tests_i_15;
+mod tests_i_16;
+mod tests_i_17;
+mod tests_i_18;
+mod tests_i_19;
 mod tests_i_2;
+mod tests_i_20;
+mod tests_i_21;
+mod tests_i_22;
+mod tests_i_23;
+mod tests_i_24;
+mod tests_i_25;
+mod tests_i_26;
+mod tests_i_27;
+mod tests_i_28;
+mod tests_i_29;
 mod tests_i_3;
+mod tests_i_30;
+mod tests_i_31;
 mod tests_i_4;
 mod tests_i_6;
 mod tests_i_7;
+mod tests_i_8;
+mod tests_i_9;
 mod tests_u_1;
 mod tests_u_3;
@@ -0,0 +1,133 @@
+#[cfg(test)]
+mod tests_i_10 {
+    use headless_chrome::Browser;
+    use std::error::Error;
+
+    #[test]
+    fn i_10_1() -> Result<(), Box<dyn Error>> {
+        println!("Running test: Live diagnostics for an invalid ShapeMap");
+
+        let browser = Browser::default()?;
+        let tab = browser.new_tab()?;
+
+        tab.navigate_to("http://localhost:8000/")?;
+        let selector = "#editors-container";
+        tab.wait_for_element(selector)?;
+
+        tab.evaluate(
+            r#"
+            document.querySelector('#shapemap-editor').value = ':alice@:User,\n:bob :User';
+            var event = new Event('input', { bubbles: true });
+            document.querySelector('#shapemap-editor').dispatchEvent(event);
+            "#,
+            false,
+        )?;
+
+        std::thread::sleep(std::time::Duration::from_secs(2));
+
+        let remote_object = tab.evaluate(
+            r#"
+            document.querySelector('#diagnostics-shapemap li').textContent;
+            "#,
+            false,
+        )?;
+
+        match remote_object.value {
+            Some(returned_value) => {
+                assert_eq!(
+                    returned_value.as_str().unwrap_or("").trim(),
+                    "Line 2, column 6: Expected '@'"
+                )
+            }
+            _ => unreachable!(),
+        };
+
+        Ok(())
+    }
+
+    #[test]
+    fn i_10_2() -> Result<(), Box<dyn Error>> {
+        println!("Running test: Live diagnostics for invalid RDF");
+
+        let browser = Browser::default()?;
+        let tab = browser.new_tab()?;
+
+        tab.navigate_to("http://localhost:8000/")?;
+        let selector = "#editors-container";
+        tab.wait_for_element(selector)?;
+
+        tab.evaluate(
+            r#"
+            window.yateInstance.setValue(`invalid rdf entry`);
+            "#,
+            false,
+        )?;
+
+        std::thread::sleep(std::time::Duration::from_secs(3));
+
+        tab.wait_for_element("#diagnostics-rdf")?;
+        tab.wait_for_element(".diagnostics-marker")?;
+
+        tab.evaluate(
+            r#"
+            window.yateInstance.setValue(`<http://example.org/a> <http://example.org/name> "A" .`);
+            "#,
+            false,
+        )?;
+
+        std::thread::sleep(std::time::Duration::from_secs(3));
+
+        let remote_object = tab.evaluate(
+            r#"
+            document.getElementById('diagnostics-rdf') === null;
+            "#,
+            false,
+        )?;
+
+        assert!(remote_object.value.unwrap().as_bool().unwrap_or(false));
+
+        Ok(())
+    }
+
+    #[test]
+    fn i_10_3() -> Result<(), Box<dyn Error>> {
+        println!("Running test: Report network errors apart from syntax diagnostics");
+
+        let browser = Browser::default()?;
+        let tab = browser.new_tab()?;
+
+        tab.navigate_to("http://localhost:8000/")?;
+        let selector = "#editors-container";
+        tab.wait_for_element(selector)?;
+
+        tab.evaluate(
+            r#"
+            const originalFetch = window.fetch;
+            window.fetch = function (input, init) {
+                const url = typeof input === 'string' ? input : input.url;
+                if (url.endsWith('/data/info')) {
+                    return Promise.reject(new TypeError('Failed to fetch'));
+                }
+                return originalFetch.call(window, input, init);
+            };
+            window.yateInstance.setValue(`<http://example.org/a> <http://example.org/name> "A" .`);
+            "#,
+            false,
+        )?;
+
+        std::thread::sleep(std::time::Duration::from_secs(5));
+
+        tab.wait_for_element("#check-error-rdf")?;
+
+        let remote_object = tab.evaluate(
+            r#"
+            document.getElementById('diagnostics-rdf') === null;
+            "#,
+            false,
+        )?;
+
+        assert!(remote_object.value.unwrap().as_bool().unwrap_or(false));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,144 @@
+#[cfg(test)]
+mod tests_i_11 {
+    use headless_chrome::Browser;
+    use std::error::Error;
+
+    const E_14: &str = r#"
+PREFIX : <http://example.org/>
+PREFIX schema: <http://schema.org/>
+PREFIX xsd: <http://www.w3.org/2001/XMLSchema#>
+
+:User {
+  schema:name xsd:string ;
+  schema:birthDate xsd:date? ;
+  schema:knows IRI @:User*
+}
+"#;
+
+    #[test]
+    fn i_11_1() -> Result<(), Box<dyn Error>> {
+        println!("Running test: Add associations with the ShapeMap builder");
+
+        let browser = Browser::default()?;
+        let tab = browser.new_tab()?;
+
+        tab.navigate_to("http://localhost:8000/")?;
+        let selector = "#editors-container";
+        tab.wait_for_element(selector)?;
+
+        tab.evaluate(
+            r#"
+            function setInput(id, value) {
+                document.getElementById(id).value = value;
+                document.getElementById(id).dispatchEvent(new Event('input', { bubbles: true }));
+            }
+            setInput('builder-node', ':alice');
+            setInput('builder-shape', ':User');
+            "#,
+            false,
+        )?;
+        tab.evaluate(
+            r#"
+            $('#builder-add').click();
+            "#,
+            false,
+        )?;
+
+        tab.evaluate(
+            r#"
+            document.getElementById('builder-mode').value = 'type';
+            var event = new Event('change', { bubbles: true });
+            document.getElementById('builder-mode').dispatchEvent(event);
+            "#,
+            false,
+        )?;
+        tab.evaluate(
+            r#"
+            document.getElementById('builder-node').value = 'schema:Person';
+            document.getElementById('builder-node').dispatchEvent(new Event('input', { bubbles: true }));
+            $('#builder-add').click();
+            "#,
+            false,
+        )?;
+
+        std::thread::sleep(std::time::Duration::from_secs(1));
+
+        let remote_object = tab.evaluate(
+            r#"
+            document.querySelector('#shapemap-editor').value;
+            "#,
+            false,
+        )?;
+
+        match remote_object.value {
+            Some(returned_value) => {
+                assert_eq!(
+                    returned_value.as_str().unwrap_or(""),
+                    ":alice@:User,\n{FOCUS a schema:Person}@:User"
+                )
+            }
+            _ => unreachable!(),
+        };
+
+        let remote_object = tab.evaluate(
+            r#"
+            document.querySelectorAll('.shapemap-highlight .sm-keyword').length;
+            "#,
+            false,
+        )?;
+
+        assert_eq!(remote_object.value.unwrap().as_i64().unwrap_or(0), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn i_11_2() -> Result<(), Box<dyn Error>> {
+        println!("Running test: Autocomplete shape labels from the schema");
+
+        let browser = Browser::default()?;
+        let tab = browser.new_tab()?;
+
+        tab.navigate_to("http://localhost:8000/")?;
+        let selector = "#editors-container";
+        tab.wait_for_element(selector)?;
+
+        tab.evaluate(
+            &format!(
+                r#"
+            window.yasheInstance.setValue(`{}`);
+            "#,
+                E_14.replace("`", "\\`")
+            ),
+            false,
+        )?;
+
+        std::thread::sleep(std::time::Duration::from_secs(3));
+
+        tab.evaluate(
+            r#"
+            document.querySelector('#shapemap-editor').value = ':alice@:U';
+            document.querySelector('#shapemap-editor').dispatchEvent(new Event('input', { bubbles: true }));
+            "#,
+            false,
+        )?;
+
+        std::thread::sleep(std::time::Duration::from_secs(1));
+
+        let remote_object = tab.evaluate(
+            r#"
+            document.querySelector('#shapemap-suggestions li').textContent;
+            "#,
+            false,
+        )?;
+
+        match remote_object.value {
+            Some(returned_value) => {
+                assert_eq!(returned_value.as_str().unwrap_or("").trim(), ":User")
+            }
+            _ => unreachable!(),
+        };
+
+        Ok(())
+    }
+}
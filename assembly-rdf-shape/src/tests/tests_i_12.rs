@@ -0,0 +1,165 @@
+#[cfg(test)]
+mod tests_i_12 {
+    use headless_chrome::Browser;
+    use std::error::Error;
+
+    const E_15_RDF: &str = r#"
+PREFIX : <http://example.org/>
+PREFIX schema: <http://schema.org/>
+
+:alice a schema:Person ;
+  schema:name "Alice" ;
+  schema:knows :bob .
+:bob a schema:Person ;
+  schema:name "Bob" .
+:carol schema:name "Carol" ;
+  schema:knows :alice .
+"#;
+
+    const E_15_SHEX: &str = r#"
+PREFIX : <http://example.org/>
+PREFIX schema: <http://schema.org/>
+PREFIX xsd: <http://www.w3.org/2001/XMLSchema#>
+
+:User {
+  schema:name xsd:string ;
+  schema:knows @:User*
+}
+"#;
+
+    /// Carga los datos y el esquema del ejemplo, escribe el ShapeMap y pulsa el botón de vista previa.
+    fn preview(tab: &headless_chrome::Tab, shapemap: &str) -> Result<(), Box<dyn Error>> {
+        tab.evaluate(
+            &format!(
+                r#"
+            window.yateInstance.setValue(`{}`);
+            window.yasheInstance.setValue(`{}`);
+            document.querySelector('#shapemap-editor').value = `{}`;
+            document.querySelector('#shapemap-editor').dispatchEvent(new Event('input', {{ bubbles: true }}));
+            "#,
+                E_15_RDF, E_15_SHEX, shapemap
+            ),
+            false,
+        )?;
+        tab.evaluate(
+            r#"
+            $('#preview-shapemap').click();
+            "#,
+            false,
+        )?;
+
+        std::thread::sleep(std::time::Duration::from_secs(4));
+        Ok(())
+    }
+
+    #[test]
+    fn i_12_1() -> Result<(), Box<dyn Error>> {
+        println!("Running test: Preview the nodes selected by a FOCUS triple pattern");
+
+        let browser = Browser::default()?;
+        let tab = browser.new_tab()?;
+
+        tab.navigate_to("http://localhost:8000/")?;
+        let selector = "#editors-container";
+        tab.wait_for_element(selector)?;
+
+        preview(&tab, "{FOCUS a schema:Person}@:User")?;
+
+        let remote_object = tab.evaluate(
+            r#"
+            document.querySelector('#shapemap-preview-count').textContent;
+            "#,
+            false,
+        )?;
+
+        match remote_object.value {
+            Some(returned_value) => {
+                assert_eq!(
+                    returned_value.as_str().unwrap_or("").trim(),
+                    "2 node(s) will be checked"
+                )
+            }
+            _ => unreachable!(),
+        };
+
+        Ok(())
+    }
+
+    #[test]
+    fn i_12_2() -> Result<(), Box<dyn Error>> {
+        println!("Running test: Preview a triple pattern with a wildcard");
+
+        let browser = Browser::default()?;
+        let tab = browser.new_tab()?;
+
+        tab.navigate_to("http://localhost:8000/")?;
+        let selector = "#editors-container";
+        tab.wait_for_element(selector)?;
+
+        preview(&tab, "{_ schema:knows FOCUS}@:User")?;
+
+        let remote_object = tab.evaluate(
+            r#"
+            Array.from(document.querySelectorAll('.shapemap-preview-nodes li')).map(li => li.textContent).sort().join(' ');
+            "#,
+            false,
+        )?;
+
+        match remote_object.value {
+            Some(returned_value) => {
+                assert_eq!(returned_value.as_str().unwrap_or(""), ":alice :bob")
+            }
+            _ => unreachable!(),
+        };
+
+        Ok(())
+    }
+
+    #[test]
+    fn i_12_3() -> Result<(), Box<dyn Error>> {
+        println!("Running test: Report query selectors that select no nodes");
+
+        let browser = Browser::default()?;
+        let tab = browser.new_tab()?;
+
+        tab.navigate_to("http://localhost:8000/")?;
+        let selector = "#editors-container";
+        tab.wait_for_element(selector)?;
+
+        tab.evaluate(
+            &format!(
+                r#"
+            window.yateInstance.setValue(`{}`);
+            window.yasheInstance.setValue(`{}`);
+            document.querySelector('#shapemap-editor').value = `:alice@:User,
+{{FOCUS a schema:Organization}}@:User`;
+            document.querySelector('#shapemap-editor').dispatchEvent(new Event('input', {{ bubbles: true }}));
+            $('#validate-btn').click();
+            "#,
+                E_15_RDF, E_15_SHEX
+            ),
+            false,
+        )?;
+
+        std::thread::sleep(std::time::Duration::from_secs(4));
+
+        let remote_object = tab.evaluate(
+            r#"
+            document.getElementById('unmatched-selectors').textContent;
+            "#,
+            false,
+        )?;
+
+        match remote_object.value {
+            Some(returned_value) => {
+                assert!(returned_value
+                    .as_str()
+                    .unwrap_or("")
+                    .ends_with("{FOCUS a schema:Organization}@:User"))
+            }
+            _ => unreachable!(),
+        };
+
+        Ok(())
+    }
+}
@@ -0,0 +1,133 @@
+#[cfg(test)]
+mod tests_i_13 {
+    use headless_chrome::Browser;
+    use std::error::Error;
+
+    const E_16_RDF: &str = r#"
+PREFIX : <http://example.org/>
+PREFIX schema: <http://schema.org/>
+
+:alice a schema:Person ;
+  schema:name "Alice" .
+:book1 a schema:Book ;
+  schema:name "Book One" ;
+  schema:author :alice .
+"#;
+
+    const E_16_SHEX: &str = r#"
+PREFIX : <http://example.org/>
+PREFIX schema: <http://schema.org/>
+PREFIX xsd: <http://www.w3.org/2001/XMLSchema#>
+
+:Person {
+  schema:name xsd:string
+}
+
+:BookShape {
+  schema:name xsd:string ;
+  schema:author @:Person
+}
+"#;
+
+    /// Carga los datos y el esquema del ejemplo.
+    fn load_inputs(tab: &headless_chrome::Tab) -> Result<(), Box<dyn Error>> {
+        tab.evaluate(
+            &format!(
+                r#"
+            window.yateInstance.setValue(`{}`);
+            window.yasheInstance.setValue(`{}`);
+            "#,
+                E_16_RDF, E_16_SHEX
+            ),
+            false,
+        )?;
+
+        std::thread::sleep(std::time::Duration::from_secs(3));
+        Ok(())
+    }
+
+    #[test]
+    fn i_13_1() -> Result<(), Box<dyn Error>> {
+        println!("Running test: Generate a ShapeMap matching types with shape names");
+
+        let browser = Browser::default()?;
+        let tab = browser.new_tab()?;
+
+        tab.navigate_to("http://localhost:8000/")?;
+        let selector = "#editors-container";
+        tab.wait_for_element(selector)?;
+
+        load_inputs(&tab)?;
+        tab.evaluate(
+            r#"
+            $('#generate-shapemap').click();
+            "#,
+            false,
+        )?;
+
+        std::thread::sleep(std::time::Duration::from_secs(3));
+
+        let remote_object = tab.evaluate(
+            r#"
+            document.querySelector('#shapemap-editor').value;
+            "#,
+            false,
+        )?;
+
+        match remote_object.value {
+            Some(returned_value) => {
+                assert_eq!(
+                    returned_value.as_str().unwrap_or(""),
+                    ":alice@:Person,\n:book1@:BookShape"
+                )
+            }
+            _ => unreachable!(),
+        };
+
+        Ok(())
+    }
+
+    #[test]
+    fn i_13_2() -> Result<(), Box<dyn Error>> {
+        println!("Running test: Generate a ShapeMap with all subjects against a chosen shape");
+
+        let browser = Browser::default()?;
+        let tab = browser.new_tab()?;
+
+        tab.navigate_to("http://localhost:8000/")?;
+        let selector = "#editors-container";
+        tab.wait_for_element(selector)?;
+
+        load_inputs(&tab)?;
+        tab.evaluate(
+            r#"
+            document.getElementById('select-generate-shapemap').value = 'http://example.org/Person';
+            var event = new Event('change', { bubbles: true });
+            document.getElementById('select-generate-shapemap').dispatchEvent(event);
+            $('#generate-shapemap').click();
+            "#,
+            false,
+        )?;
+
+        std::thread::sleep(std::time::Duration::from_secs(3));
+
+        let remote_object = tab.evaluate(
+            r#"
+            document.querySelector('#shapemap-editor').value;
+            "#,
+            false,
+        )?;
+
+        match remote_object.value {
+            Some(returned_value) => {
+                assert_eq!(
+                    returned_value.as_str().unwrap_or(""),
+                    ":alice@:Person,\n:book1@:Person"
+                )
+            }
+            _ => unreachable!(),
+        };
+
+        Ok(())
+    }
+}
@@ -0,0 +1,112 @@
+#[cfg(test)]
+mod tests_i_14 {
+    use headless_chrome::Browser;
+    use std::error::Error;
+
+    const E_17_RDF: &str = r#"
+PREFIX : <http://example.org/>
+PREFIX schema: <http://schema.org/>
+
+:alice schema:name "Alice" .
+"#;
+
+    const E_17_SHEX: &str = r#"
+PREFIX : <http://example.org/>
+PREFIX schema: <http://schema.org/>
+PREFIX xsd: <http://www.w3.org/2001/XMLSchema#>
+
+start = @:User
+
+:User {
+  schema:name xsd:string
+}
+"#;
+
+    /// Carga los datos, el esquema y el ShapeMap del ejemplo.
+    fn load_inputs(tab: &headless_chrome::Tab) -> Result<(), Box<dyn Error>> {
+        tab.evaluate(
+            &format!(
+                r#"
+            window.yateInstance.setValue(`{}`);
+            window.yasheInstance.setValue(`{}`);
+            document.querySelector('#shapemap-editor').value = ':alice@START';
+            document.querySelector('#shapemap-editor').dispatchEvent(new Event('input', {{ bubbles: true }}));
+            "#,
+                E_17_RDF, E_17_SHEX
+            ),
+            false,
+        )?;
+        Ok(())
+    }
+
+    #[test]
+    fn i_14_1() -> Result<(), Box<dyn Error>> {
+        println!("Running test: Show the start shape in the schema analysis");
+
+        let browser = Browser::default()?;
+        let tab = browser.new_tab()?;
+
+        tab.navigate_to("http://localhost:8000/")?;
+        let selector = "#editors-container";
+        tab.wait_for_element(selector)?;
+
+        load_inputs(&tab)?;
+        tab.evaluate(
+            r#"
+            $('#analyze-shex').click();
+            "#,
+            false,
+        )?;
+
+        std::thread::sleep(std::time::Duration::from_secs(3));
+
+        let remote_object = tab.evaluate(
+            r#"
+            document.querySelector('#start-shape').textContent;
+            "#,
+            false,
+        )?;
+
+        match remote_object.value {
+            Some(returned_value) => {
+                assert_eq!(returned_value.as_str().unwrap_or(""), "Start shape: :User")
+            }
+            _ => unreachable!(),
+        };
+
+        Ok(())
+    }
+
+    #[test]
+    fn i_14_2() -> Result<(), Box<dyn Error>> {
+        println!("Running test: Validate a node against the start shape");
+
+        let browser = Browser::default()?;
+        let tab = browser.new_tab()?;
+
+        tab.navigate_to("http://localhost:8000/")?;
+        let selector = "#editors-container";
+        tab.wait_for_element(selector)?;
+
+        load_inputs(&tab)?;
+        tab.evaluate(
+            r#"
+            $('#validate-btn').click();
+            "#,
+            false,
+        )?;
+
+        std::thread::sleep(std::time::Duration::from_secs(3));
+
+        let remote_object = tab.evaluate(
+            r#"
+            document.querySelectorAll('#result-table .start-shape-badge').length;
+            "#,
+            false,
+        )?;
+
+        assert_eq!(remote_object.value.unwrap().as_i64().unwrap_or(0), 1);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,124 @@
+#[cfg(test)]
+mod tests_i_15 {
+    use headless_chrome::Browser;
+    use std::error::Error;
+
+    const E_18_RDF: &str = r#"
+PREFIX : <http://example.org/>
+PREFIX rdfs: <http://www.w3.org/2000/01/rdf-schema#>
+PREFIX schema: <http://schema.org/>
+
+:Student rdfs:subClassOf schema:Person .
+:alice a :Student ;
+  schema:name "Alice" .
+"#;
+
+    const E_18_SHEX: &str = r#"
+PREFIX : <http://example.org/>
+PREFIX schema: <http://schema.org/>
+PREFIX xsd: <http://www.w3.org/2001/XMLSchema#>
+
+:Person {
+  a [schema:Person] ;
+  schema:name xsd:string
+}
+"#;
+
+    /// Carga el ejemplo y selecciona el régimen de inferencia indicado.
+    fn load_inputs(tab: &headless_chrome::Tab, inference: &str) -> Result<(), Box<dyn Error>> {
+        tab.evaluate(
+            &format!(
+                r#"
+            window.yateInstance.setValue(`{}`);
+            window.yasheInstance.setValue(`{}`);
+            document.querySelector('#shapemap-editor').value = ':alice@:Person';
+            document.querySelector('#shapemap-editor').dispatchEvent(new Event('input', {{ bubbles: true }}));
+            document.getElementById('select-inference').value = '{}';
+            document.getElementById('select-inference').dispatchEvent(new Event('change', {{ bubbles: true }}));
+            "#,
+                E_18_RDF, E_18_SHEX, inference
+            ),
+            false,
+        )?;
+        Ok(())
+    }
+
+    #[test]
+    fn i_15_1() -> Result<(), Box<dyn Error>> {
+        println!("Running test: Show the inference used in the results");
+
+        let browser = Browser::default()?;
+        let tab = browser.new_tab()?;
+
+        tab.navigate_to("http://localhost:8000/")?;
+        let selector = "#editors-container";
+        tab.wait_for_element(selector)?;
+
+        load_inputs(&tab, "RDFS")?;
+        tab.evaluate(
+            r#"
+            $('#validate-btn').click();
+            "#,
+            false,
+        )?;
+
+        std::thread::sleep(std::time::Duration::from_secs(3));
+
+        let remote_object = tab.evaluate(
+            r#"
+            document.querySelector('#inference-used').textContent;
+            "#,
+            false,
+        )?;
+
+        match remote_object.value {
+            Some(returned_value) => {
+                assert_eq!(
+                    returned_value.as_str().unwrap_or("").trim(),
+                    "Inference: RDFS"
+                )
+            }
+            _ => unreachable!(),
+        };
+
+        Ok(())
+    }
+
+    #[test]
+    fn i_15_2() -> Result<(), Box<dyn Error>> {
+        println!("Running test: Send the inference with the RDF analysis");
+
+        let browser = Browser::default()?;
+        let tab = browser.new_tab()?;
+
+        tab.navigate_to("http://localhost:8000/")?;
+        let selector = "#editors-container";
+        tab.wait_for_element(selector)?;
+
+        load_inputs(&tab, "OWL")?;
+        tab.evaluate(
+            r#"
+            $('#analyze-rdf').click();
+            "#,
+            false,
+        )?;
+
+        std::thread::sleep(std::time::Duration::from_secs(2));
+
+        let remote_object = tab.evaluate(
+            r#"
+            document.querySelector('#rdf-inference').textContent;
+            "#,
+            false,
+        )?;
+
+        match remote_object.value {
+            Some(returned_value) => {
+                assert_eq!(returned_value.as_str().unwrap_or(""), "Inference: OWL")
+            }
+            _ => unreachable!(),
+        };
+
+        Ok(())
+    }
+}
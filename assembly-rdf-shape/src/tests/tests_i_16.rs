@@ -0,0 +1,194 @@
+#[cfg(test)]
+mod tests_i_16 {
+    use headless_chrome::Browser;
+    use std::error::Error;
+
+    const E_19_RDF: &str = r#"
+PREFIX : <http://example.org/>
+PREFIX schema: <http://schema.org/>
+
+:alice a schema:Person ;
+  schema:name "Alice" .
+:bob a schema:Person .
+"#;
+
+    const E_19_SHACL: &str = r#"
+PREFIX : <http://example.org/>
+PREFIX sh: <http://www.w3.org/ns/shacl#>
+PREFIX schema: <http://schema.org/>
+PREFIX xsd: <http://www.w3.org/2001/XMLSchema#>
+
+:PersonShape a sh:NodeShape ;
+  sh:targetClass schema:Person ;
+  sh:property [
+    sh:path schema:name ;
+    sh:minCount 1 ;
+    sh:datatype xsd:string
+  ] .
+"#;
+
+    /// Carga los datos y el grafo de formas y selecciona el motor SHACLex.
+    fn load_inputs(tab: &headless_chrome::Tab) -> Result<(), Box<dyn Error>> {
+        tab.evaluate(
+            &format!(
+                r#"
+            window.yateInstance.setValue(`{}`);
+            window.yasheInstance.setValue(`{}`);
+            document.getElementById('select-engine').value = 'SHACLex';
+            document.getElementById('select-engine').dispatchEvent(new Event('change', {{ bubbles: true }}));
+            "#,
+                E_19_RDF, E_19_SHACL
+            ),
+            false,
+        )?;
+        Ok(())
+    }
+
+    #[test]
+    fn i_16_1() -> Result<(), Box<dyn Error>> {
+        println!("Running test: Map the SHACL validation report into the result table");
+
+        let browser = Browser::default()?;
+        let tab = browser.new_tab()?;
+
+        tab.navigate_to("http://localhost:8000/")?;
+        let selector = "#editors-container";
+        tab.wait_for_element(selector)?;
+
+        load_inputs(&tab)?;
+        tab.evaluate(
+            r#"
+            $('#validate-btn').click();
+            "#,
+            false,
+        )?;
+
+        std::thread::sleep(std::time::Duration::from_secs(4));
+
+        let remote_object = tab.evaluate(
+            r#"
+            Array.from(document.querySelectorAll('#result-table tr')).slice(1).map(row =>
+                Array.from(row.querySelectorAll('td')).slice(0, 4).map(cell => cell.textContent.trim()).join('|')
+            ).join(' ');
+            "#,
+            false,
+        )?;
+
+        match remote_object.value {
+            Some(returned_value) => {
+                assert_eq!(
+                    returned_value.as_str().unwrap_or(""),
+                    ":bob|:PersonShape|Violation|:name"
+                )
+            }
+            _ => unreachable!(),
+        };
+
+        Ok(())
+    }
+
+    #[test]
+    fn i_16_2() -> Result<(), Box<dyn Error>> {
+        println!("Running test: Analyze a SHACL shapes graph");
+
+        let browser = Browser::default()?;
+        let tab = browser.new_tab()?;
+
+        tab.navigate_to("http://localhost:8000/")?;
+        let selector = "#editors-container";
+        tab.wait_for_element(selector)?;
+
+        load_inputs(&tab)?;
+        tab.evaluate(
+            r#"
+            $('#analyze-shex').click();
+            "#,
+            false,
+        )?;
+
+        std::thread::sleep(std::time::Duration::from_secs(2));
+
+        let remote_object = tab.evaluate(
+            r#"
+            document.querySelector('#shacl-targets').textContent;
+            "#,
+            false,
+        )?;
+
+        match remote_object.value {
+            Some(returned_value) => {
+                assert_eq!(
+                    returned_value.as_str().unwrap_or(""),
+                    "Number of targets: 1"
+                )
+            }
+            _ => unreachable!(),
+        };
+
+        Ok(())
+    }
+
+    #[test]
+    fn i_16_3() -> Result<(), Box<dyn Error>> {
+        println!("Running test: Offer RDF formats for SHACL and restore the ShEx format when switching back");
+
+        let browser = Browser::default()?;
+        let tab = browser.new_tab()?;
+
+        tab.navigate_to("http://localhost:8000/")?;
+        let selector = "#editors-container";
+        tab.wait_for_element(selector)?;
+
+        tab.evaluate(
+            r#"
+            document.getElementById('select-shex').value = 'ShExJ';
+            document.getElementById('select-shex').dispatchEvent(new Event('change', { bubbles: true }));
+            document.getElementById('select-engine').value = 'SHACLex';
+            document.getElementById('select-engine').dispatchEvent(new Event('change', { bubbles: true }));
+            "#,
+            false,
+        )?;
+
+        let remote_object = tab.evaluate(
+            r#"
+            document.getElementById('select-shex').value + ' ' +
+                Array.from(document.querySelectorAll('#select-shex option')).map(option => option.value).join(',');
+            "#,
+            false,
+        )?;
+
+        match remote_object.value {
+            Some(returned_value) => {
+                assert_eq!(
+                    returned_value.as_str().unwrap_or(""),
+                    "Turtle Turtle,N-Triples,N-Quads,TriG,JSON-LD,RDF/XML,RDF/JSON"
+                )
+            }
+            _ => unreachable!(),
+        };
+
+        tab.evaluate(
+            r#"
+            document.getElementById('select-engine').value = 'ShEx';
+            document.getElementById('select-engine').dispatchEvent(new Event('change', { bubbles: true }));
+            "#,
+            false,
+        )?;
+
+        let remote_object = tab.evaluate(
+            r#"
+            document.getElementById('select-shex').value;
+            "#,
+            false,
+        )?;
+
+        match remote_object.value {
+            Some(returned_value) => {
+                assert_eq!(returned_value.as_str().unwrap_or(""), "ShExJ")
+            }
+            _ => unreachable!(),
+        };
+
+        Ok(())
+    }
+}
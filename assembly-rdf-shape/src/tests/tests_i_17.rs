@@ -0,0 +1,73 @@
+#[cfg(test)]
+mod tests_i_17 {
+    use headless_chrome::Browser;
+    use std::error::Error;
+
+    const E_20: &str = r#"
+PREFIX : <http://example.org/>
+PREFIX schema: <http://schema.org/>
+PREFIX xsd: <http://www.w3.org/2001/XMLSchema#>
+
+start = @:User
+
+:User EXTRA schema:knows {
+  schema:name xsd:string ;
+  schema:knows @:User*
+}
+"#;
+
+    #[test]
+    fn i_17_1() -> Result<(), Box<dyn Error>> {
+        println!("Running test: Translate a ShEx schema to SHACL with warnings");
+
+        let browser = Browser::default()?;
+        let tab = browser.new_tab()?;
+
+        tab.navigate_to("http://localhost:8000/")?;
+        let selector = "#editors-container";
+        tab.wait_for_element(selector)?;
+
+        tab.evaluate(
+            &format!(
+                r#"
+            window.yasheInstance.setValue(`{}`);
+            "#,
+                E_20
+            ),
+            false,
+        )?;
+        tab.evaluate(
+            r#"
+            $('#translate-schema').click();
+            "#,
+            false,
+        )?;
+
+        std::thread::sleep(std::time::Duration::from_secs(4));
+
+        let remote_object = tab.evaluate(
+            r#"
+            document.querySelectorAll('#translation-warnings li').length;
+            "#,
+            false,
+        )?;
+
+        assert_eq!(remote_object.value.unwrap().as_i64().unwrap_or(0), 3);
+
+        let remote_object = tab.evaluate(
+            r#"
+            document.querySelector('#select-engine').value + ' ' + window.yasheInstance.getValue().includes('sh:NodeShape');
+            "#,
+            false,
+        )?;
+
+        match remote_object.value {
+            Some(returned_value) => {
+                assert_eq!(returned_value.as_str().unwrap_or(""), "SHACLex true")
+            }
+            _ => unreachable!(),
+        };
+
+        Ok(())
+    }
+}
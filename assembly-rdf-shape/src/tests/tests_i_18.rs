@@ -0,0 +1,83 @@
+#[cfg(test)]
+mod tests_i_18 {
+    use headless_chrome::Browser;
+    use std::error::Error;
+
+    const E_21: &str = r#"
+PREFIX : <http://example.org/>
+PREFIX schema: <http://schema.org/>
+PREFIX xsd: <http://www.w3.org/2001/XMLSchema#>
+
+:User {
+  schema:name xsd:string ;
+  schema:worksFor @:Company ? ;
+  schema:knows @:User*
+}
+
+:Company {
+  schema:legalName xsd:string
+}
+"#;
+
+    #[test]
+    fn i_18_1() -> Result<(), Box<dyn Error>> {
+        println!("Running test: Draw the schema diagram with its shapes and references");
+
+        let browser = Browser::default()?;
+        let tab = browser.new_tab()?;
+
+        tab.navigate_to("http://localhost:8000/")?;
+        let selector = "#editors-container";
+        tab.wait_for_element(selector)?;
+
+        tab.evaluate(
+            &format!(
+                r#"
+            window.yasheInstance.setValue(`{}`);
+            "#,
+                E_21
+            ),
+            false,
+        )?;
+        tab.evaluate(
+            r#"
+            $('#diagram-shex').click();
+            "#,
+            false,
+        )?;
+
+        std::thread::sleep(std::time::Duration::from_secs(4));
+
+        let remote_object = tab.evaluate(
+            r#"
+            document.querySelectorAll('#schema-diagram .diagram-shape').length + ' ' +
+            document.querySelectorAll('#schema-diagram .diagram-reference').length;
+            "#,
+            false,
+        )?;
+
+        match remote_object.value {
+            Some(returned_value) => {
+                assert_eq!(returned_value.as_str().unwrap_or(""), "2 2")
+            }
+            _ => unreachable!(),
+        };
+
+        let remote_object = tab.evaluate(
+            r#"
+            $('#diagram-zoom-in').click();
+            document.querySelector('#diagram-zoom').textContent;
+            "#,
+            false,
+        )?;
+
+        match remote_object.value {
+            Some(returned_value) => {
+                assert_eq!(returned_value.as_str().unwrap_or(""), "125%")
+            }
+            _ => unreachable!(),
+        };
+
+        Ok(())
+    }
+}
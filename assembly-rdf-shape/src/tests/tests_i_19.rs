@@ -0,0 +1,80 @@
+#[cfg(test)]
+mod tests_i_19 {
+    use headless_chrome::Browser;
+    use std::error::Error;
+
+    const E_22: &str = r#"
+PREFIX : <http://example.org/>
+PREFIX schema: <http://schema.org/>
+
+:alice schema:name "Alice" ;
+       schema:knows :bob .
+:bob schema:name "Bob" .
+"#;
+
+    #[test]
+    fn i_19_1() -> Result<(), Box<dyn Error>> {
+        println!("Running test: Draw the RDF data graph and search a node");
+
+        let browser = Browser::default()?;
+        let tab = browser.new_tab()?;
+
+        tab.navigate_to("http://localhost:8000/")?;
+        let selector = "#editors-container";
+        tab.wait_for_element(selector)?;
+
+        tab.evaluate(
+            &format!(
+                r#"
+            window.yateInstance.setValue(`{}`);
+            "#,
+                E_22
+            ),
+            false,
+        )?;
+        tab.evaluate(
+            r#"
+            $('#analyze-rdf').click();
+            "#,
+            false,
+        )?;
+
+        std::thread::sleep(std::time::Duration::from_secs(4));
+
+        let remote_object = tab.evaluate(
+            r#"
+            document.querySelectorAll('#rdf-graph .graph-node').length + ' ' +
+            document.querySelectorAll('#rdf-graph .graph-edge').length;
+            "#,
+            false,
+        )?;
+
+        match remote_object.value {
+            Some(returned_value) => {
+                assert_eq!(returned_value.as_str().unwrap_or(""), "4 3")
+            }
+            _ => unreachable!(),
+        };
+
+        tab.wait_for_element("#rdf-graph-search")?.click()?;
+        tab.type_str("bob")?;
+
+        std::thread::sleep(std::time::Duration::from_secs(1));
+
+        let remote_object = tab.evaluate(
+            r#"
+            document.querySelector('#rdf-graph .graph-node-match').getAttribute('data-label');
+            "#,
+            false,
+        )?;
+
+        match remote_object.value {
+            Some(returned_value) => {
+                assert_eq!(returned_value.as_str().unwrap_or(""), "bob")
+            }
+            _ => unreachable!(),
+        };
+
+        Ok(())
+    }
+}
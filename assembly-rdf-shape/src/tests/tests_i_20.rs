@@ -0,0 +1,72 @@
+#[cfg(test)]
+mod tests_i_20 {
+    use headless_chrome::Browser;
+    use std::error::Error;
+
+    const E_23: &str = r#"
+PREFIX : <http://example.org/>
+PREFIX schema: <http://schema.org/>
+PREFIX foaf: <http://xmlns.com/foaf/0.1/>
+
+:alice a schema:Person ;
+       schema:name "Alice"@en, "Alicia"@es ;
+       schema:knows [ schema:name "Carol" ] .
+:bob a schema:Person ;
+     schema:birthDate "1990-01-01"^^<http://www.w3.org/2001/XMLSchema#date> .
+"#;
+
+    #[test]
+    fn i_20_1() -> Result<(), Box<dyn Error>> {
+        println!("Running test: Show detailed RDF statistics in the analysis modal");
+
+        let browser = Browser::default()?;
+        let tab = browser.new_tab()?;
+
+        tab.navigate_to("http://localhost:8000/")?;
+        let selector = "#editors-container";
+        tab.wait_for_element(selector)?;
+
+        tab.evaluate(
+            &format!(
+                r#"
+            window.yateInstance.setValue(`{}`);
+            "#,
+                E_23
+            ),
+            false,
+        )?;
+        tab.evaluate(
+            r#"
+            $('#analyze-rdf').click();
+            "#,
+            false,
+        )?;
+
+        std::thread::sleep(std::time::Duration::from_secs(4));
+
+        let remote_object = tab.evaluate(
+            r#"
+            const cells = (id) => Array.from(document.querySelectorAll(id + ' td')).map((cell) => cell.textContent);
+            [
+                cells('#rdf-statistics-summary').join(' '),
+                cells('#rdf-statistics-classes').join(' '),
+                cells('#rdf-statistics-languages').join(' '),
+                document.querySelectorAll('#rdf-statistics-prefixes .unused-prefix').length
+            ].join(' | ');
+            "#,
+            false,
+        )?;
+
+        match remote_object.value {
+            Some(returned_value) => {
+                assert_eq!(
+                    returned_value.as_str().unwrap_or(""),
+                    "Distinct subjects 3 Distinct predicates 4 Distinct objects 6 Blank nodes 1 Classes 1 | schema:Person 2 | en 1 es 1 | 1"
+                )
+            }
+            _ => unreachable!(),
+        };
+
+        Ok(())
+    }
+}
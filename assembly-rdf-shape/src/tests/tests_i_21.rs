@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod tests_i_21 {
+    use headless_chrome::Browser;
+    use std::error::Error;
+
+    const E_24: &str = r#"
+PREFIX : <http://example.org/>
+PREFIX schema: <http://schema.org/>
+PREFIX xsd: <http://www.w3.org/2001/XMLSchema#>
+PREFIX foaf: <http://xmlns.com/foaf/0.1/>
+
+:User {
+  schema:name xsd:string ;
+  schema:knows @:User*
+}
+
+:Company {
+  schema:legalName xsd:string
+}
+"#;
+
+    #[test]
+    fn i_21_1() -> Result<(), Box<dyn Error>> {
+        println!("Running test: List the shapes and warnings of the ShEx analysis");
+
+        let browser = Browser::default()?;
+        let tab = browser.new_tab()?;
+
+        tab.navigate_to("http://localhost:8000/")?;
+        let selector = "#editors-container";
+        tab.wait_for_element(selector)?;
+
+        tab.evaluate(
+            &format!(
+                r#"
+            window.yasheInstance.setValue(`{}`);
+            "#,
+                E_24
+            ),
+            false,
+        )?;
+        tab.evaluate(
+            r#"
+            $('#analyze-shex').click();
+            "#,
+            false,
+        )?;
+
+        std::thread::sleep(std::time::Duration::from_secs(4));
+
+        let remote_object = tab.evaluate(
+            r#"
+            [
+                Array.from(document.querySelectorAll('#shex-shapes tbody tr')).map((row) => row.cells[0].textContent.trim() + '@' + row.cells[1].textContent).join(' '),
+                document.querySelectorAll('#shex-prefixes tbody tr').length,
+                document.querySelectorAll('#shex-warnings li').length
+            ].join(' | ');
+            "#,
+            false,
+        )?;
+
+        match remote_object.value {
+            Some(returned_value) => {
+                assert_eq!(
+                    returned_value.as_str().unwrap_or(""),
+                    ":User@7 :Company@12 | 4 | 4"
+                )
+            }
+            _ => unreachable!(),
+        };
+
+        Ok(())
+    }
+}
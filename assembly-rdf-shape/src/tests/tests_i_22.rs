@@ -0,0 +1,57 @@
+#[cfg(test)]
+mod tests_i_22 {
+    use headless_chrome::Browser;
+    use std::error::Error;
+
+    #[test]
+    fn i_22_1() -> Result<(), Box<dyn Error>> {
+        println!("Running test: Search and load an example from the manifest");
+
+        let browser = Browser::default()?;
+        let tab = browser.new_tab()?;
+
+        tab.navigate_to("http://localhost:8000/")?;
+        let selector = "#editors-container";
+        tab.wait_for_element(selector)?;
+        tab.wait_for_element("#example-users")?;
+
+        tab.wait_for_element("#examples-search")?.click()?;
+        tab.type_str("recursion")?;
+
+        std::thread::sleep(std::time::Duration::from_secs(1));
+
+        let remote_object = tab.evaluate(
+            r#"
+            Array.from(document.querySelectorAll('.dropdown-content .dropdown-btn')).map((link) => link.id).join(' ');
+            "#,
+            false,
+        )?;
+
+        match remote_object.value {
+            Some(returned_value) => {
+                assert_eq!(returned_value.as_str().unwrap_or(""), "example-users")
+            }
+            _ => unreachable!(),
+        };
+
+        tab.evaluate(
+            r#"
+            document.querySelectorAll('#example-users')[0].click();
+            "#,
+            false,
+        )?;
+
+        std::thread::sleep(std::time::Duration::from_secs(3));
+
+        let remote_object = tab.evaluate(
+            r#"
+            window.yasheInstance.getValue().includes(':User {');
+            "#,
+            false,
+        )?;
+
+        assert!(remote_object.value.unwrap().as_bool().unwrap_or(false));
+
+        Ok(())
+    }
+}
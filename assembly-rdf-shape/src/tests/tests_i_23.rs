@@ -0,0 +1,83 @@
+#[cfg(test)]
+mod tests_i_23 {
+    use headless_chrome::Browser;
+    use std::error::Error;
+
+    const E_25: &str = r#"
+PREFIX : <http://example.org/>
+PREFIX schema: <http://schema.org/>
+
+:alice schema:name "Alice" .
+"#;
+
+    #[test]
+    fn i_23_1() -> Result<(), Box<dyn Error>> {
+        println!("Running test: Save the current editors as a user example and load it again");
+
+        let browser = Browser::default()?;
+        let tab = browser.new_tab()?;
+
+        tab.navigate_to("http://localhost:8000/")?;
+        let selector = "#editors-container";
+        tab.wait_for_element(selector)?;
+
+        tab.evaluate(
+            r#"
+            window.localStorage.clear();
+            "#,
+            false,
+        )?;
+        tab.reload(false, None)?;
+        tab.wait_for_element(selector)?;
+
+        tab.evaluate(
+            &format!(
+                r#"
+            window.yateInstance.setValue(`{}`);
+            "#,
+                E_25
+            ),
+            false,
+        )?;
+
+        tab.wait_for_element("#user-example-name")?.click()?;
+        tab.type_str("Alice only")?;
+        tab.evaluate(
+            r#"
+            $('#save-user-example').click();
+            "#,
+            false,
+        )?;
+
+        tab.reload(false, None)?;
+        tab.wait_for_element(selector)?;
+        tab.wait_for_element("#user-example-0")?;
+
+        tab.evaluate(
+            r#"
+            window.yateInstance.setValue('');
+            document.querySelectorAll('#user-example-0')[0].click();
+            "#,
+            false,
+        )?;
+
+        std::thread::sleep(std::time::Duration::from_secs(1));
+
+        let remote_object = tab.evaluate(
+            r#"
+            document.querySelector('#user-example-0').textContent.trim() + ' ' +
+            window.yateInstance.getValue().includes('"Alice"');
+            "#,
+            false,
+        )?;
+
+        match remote_object.value {
+            Some(returned_value) => {
+                assert_eq!(returned_value.as_str().unwrap_or(""), "Alice only true")
+            }
+            _ => unreachable!(),
+        };
+
+        Ok(())
+    }
+}
@@ -0,0 +1,89 @@
+#[cfg(test)]
+mod tests_i_24 {
+    use headless_chrome::Browser;
+    use std::error::Error;
+
+    const MANIFEST: &str = r##"{
+  "@context": "https://raw.githubusercontent.com/shexSpec/shexTest/master/context.jsonld",
+  "@graph": [{
+    "@id": "https://raw.githubusercontent.com/shexSpec/shexTest/master/validation/manifest",
+    "@type": "mf:Manifest",
+    "entries": [{
+      "@id": "#1dot_pass",
+      "@type": "sht:ValidationTest",
+      "name": "1dot_pass",
+      "comment": "<S> { <p1> . } on { <s1> <p1> <o1> }",
+      "action": {
+        "schema": "../schemas/1dot.shex",
+        "shape": "http://a.example/S1",
+        "data": "Is1_Ip1_Io1.ttl",
+        "focus": "http://a.example/s1"
+      }
+    }]
+  }]
+}"##;
+
+    const SCHEMA: &str = "<http://a.example/S1> {\\n  <http://a.example/p1> .\\n}\\n";
+
+    const DATA: &str = "<http://a.example/s1> <http://a.example/p1> <http://a.example/o1> .\\n";
+
+    #[test]
+    fn i_24_1() -> Result<(), Box<dyn Error>> {
+        println!(
+            "Running test: Import a shexTest manifest and check the result of one of its tests"
+        );
+
+        let browser = Browser::default()?;
+        let tab = browser.new_tab()?;
+
+        tab.navigate_to("http://localhost:8000/")?;
+        let selector = "#editors-container";
+        tab.wait_for_element(selector)?;
+
+        tab.evaluate(
+            &format!(
+                r#"
+            const files = new DataTransfer();
+            files.items.add(new File([`{}`], 'manifest.jsonld'));
+            files.items.add(new File(["{}"], '1dot.shex'));
+            files.items.add(new File(["{}"], 'Is1_Ip1_Io1.ttl'));
+            const input = document.querySelector('#import-shex-tests');
+            input.files = files.files;
+            input.dispatchEvent(new Event('change'));
+            "#,
+                MANIFEST, SCHEMA, DATA
+            ),
+            false,
+        )?;
+
+        tab.wait_for_element("#shex-test-0")?;
+        tab.evaluate(
+            r#"
+            document.querySelectorAll('#shex-test-0')[0].click();
+            "#,
+            false,
+        )?;
+
+        std::thread::sleep(std::time::Duration::from_secs(1));
+
+        tab.wait_for_element("#validate-btn")?.click()?;
+        tab.wait_for_element("#test-outcome")?;
+
+        let remote_object = tab.evaluate(
+            r#"
+            document.querySelector('#test-outcome').classList.contains('test-passed') + ' ' +
+            document.querySelector('#shex-test-0').classList.contains('test-passed');
+            "#,
+            false,
+        )?;
+
+        match remote_object.value {
+            Some(returned_value) => {
+                assert_eq!(returned_value.as_str().unwrap_or(""), "true true")
+            }
+            _ => unreachable!(),
+        };
+
+        Ok(())
+    }
+}
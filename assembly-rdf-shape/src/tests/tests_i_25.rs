@@ -0,0 +1,171 @@
+#[cfg(test)]
+mod tests_i_25 {
+    use headless_chrome::Browser;
+    use std::error::Error;
+
+    const SHEX: &str = r#"
+PREFIX : <http://example.org/>
+PREFIX xsd: <http://www.w3.org/2001/XMLSchema#>
+
+:User {
+  :name xsd:string
+}
+"#;
+
+    const VALID_DATA: &str = r#"PREFIX : <http://example.org/>
+:alice :name "Alice" ."#;
+
+    const INVALID_DATA: &str = r#"PREFIX : <http://example.org/>
+:alice :name 23 ."#;
+
+    #[test]
+    fn i_25_1() -> Result<(), Box<dyn Error>> {
+        println!("Running test: Validate two data files in a batch and show the source file of each result");
+
+        let browser = Browser::default()?;
+        let tab = browser.new_tab()?;
+
+        tab.navigate_to("http://localhost:8000/")?;
+        let selector = "#editors-container";
+        tab.wait_for_element(selector)?;
+
+        tab.evaluate(
+            &format!(
+                r#"
+            window.yasheInstance.setValue(`{}`);
+            document.querySelector('#shapemap-editor').value = ':alice@:User';
+            document.querySelector('#shapemap-editor').dispatchEvent(new Event('input', {{ bubbles: true }}));
+            "#,
+                SHEX
+            ),
+            false,
+        )?;
+
+        tab.evaluate(
+            &format!(
+                r#"
+            const files = new DataTransfer();
+            files.items.add(new File([`{}`], 'alice-valid.ttl'));
+            files.items.add(new File([`{}`], 'alice-invalid.ttl'));
+            const input = document.querySelector('#batch-files');
+            input.files = files.files;
+            input.dispatchEvent(new Event('change'));
+            "#,
+                VALID_DATA, INVALID_DATA
+            ),
+            false,
+        )?;
+
+        tab.wait_for_element("#validate-batch-btn")?.click()?;
+        tab.wait_for_element("#batch-summary")?;
+
+        let remote_object = tab.evaluate(
+            r#"
+            Array.from(document.querySelectorAll('#result-table tr'))
+                .slice(1)
+                .map(row => row.cells[0].textContent.trim() + ':' + row.cells[3].textContent.trim())
+                .sort()
+                .join(' ');
+            "#,
+            false,
+        )?;
+
+        match remote_object.value {
+            Some(returned_value) => {
+                assert_eq!(
+                    returned_value.as_str().unwrap_or(""),
+                    "alice-invalid.ttl:Invalid alice-valid.ttl:Valid"
+                )
+            }
+            _ => unreachable!(),
+        };
+
+        Ok(())
+    }
+
+    #[test]
+    fn i_25_2() -> Result<(), Box<dyn Error>> {
+        println!("Running test: Validate each file of a batch with its own data format");
+
+        let browser = Browser::default()?;
+        let tab = browser.new_tab()?;
+
+        tab.navigate_to("http://localhost:8000/")?;
+        let selector = "#editors-container";
+        tab.wait_for_element(selector)?;
+
+        tab.evaluate(
+            &format!(
+                r#"
+            window.yasheInstance.setValue(`{}`);
+            document.querySelector('#shapemap-editor').value = '<http://example.org/alice>@<http://example.org/User>';
+            document.querySelector('#shapemap-editor').dispatchEvent(new Event('input', {{ bubbles: true }}));
+            "#,
+                SHEX
+            ),
+            false,
+        )?;
+
+        tab.evaluate(
+            &format!(
+                r#"
+            const files = new DataTransfer();
+            files.items.add(new File([`{}`], 'alice-valid.ttl'));
+            files.items.add(new File(['<http://example.org/alice> <http://example.org/name> 23 .'], 'alice-invalid.nt'));
+            const input = document.querySelector('#batch-files');
+            input.files = files.files;
+            input.dispatchEvent(new Event('change'));
+            "#,
+                VALID_DATA
+            ),
+            false,
+        )?;
+
+        std::thread::sleep(std::time::Duration::from_secs(1));
+
+        let remote_object = tab.evaluate(
+            r#"
+            Array.from(document.querySelectorAll('#batch-file-list li'))
+                .map(item => item.textContent.trim())
+                .join(' ');
+            "#,
+            false,
+        )?;
+
+        match remote_object.value {
+            Some(returned_value) => {
+                assert_eq!(
+                    returned_value.as_str().unwrap_or(""),
+                    "alice-invalid.nt (N-Triples) alice-valid.ttl (Turtle)"
+                )
+            }
+            _ => unreachable!(),
+        };
+
+        tab.wait_for_element("#validate-batch-btn")?.click()?;
+        tab.wait_for_element("#batch-summary")?;
+
+        let remote_object = tab.evaluate(
+            r#"
+            Array.from(document.querySelectorAll('#result-table tr'))
+                .slice(1)
+                .map(row => row.cells[0].textContent.trim() + ':' + row.cells[3].textContent.trim())
+                .sort()
+                .join(' ');
+            "#,
+            false,
+        )?;
+
+        match remote_object.value {
+            Some(returned_value) => {
+                assert_eq!(
+                    returned_value.as_str().unwrap_or(""),
+                    "alice-invalid.nt:Invalid alice-valid.ttl:Valid"
+                )
+            }
+            _ => unreachable!(),
+        };
+
+        Ok(())
+    }
+}
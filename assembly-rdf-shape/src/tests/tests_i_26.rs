@@ -0,0 +1,170 @@
+#[cfg(test)]
+mod tests_i_26 {
+    use headless_chrome::Browser;
+    use std::error::Error;
+
+    const RDF: &str = r#"
+PREFIX : <http://example.org/>
+
+:alice :name "Alice" ;
+       :age 23 .
+"#;
+
+    const SHEX_V1: &str = r#"
+PREFIX : <http://example.org/>
+PREFIX xsd: <http://www.w3.org/2001/XMLSchema#>
+
+:User {
+  :name xsd:string
+}
+"#;
+
+    const SHEX_V2: &str = r#"
+PREFIX : <http://example.org/>
+PREFIX xsd: <http://www.w3.org/2001/XMLSchema#>
+
+:User CLOSED {
+  :name xsd:string
+}
+"#;
+
+    const SHEXJ_V2: &str = r#"{
+  "@context": "http://www.w3.org/ns/shex.jsonld",
+  "type": "Schema",
+  "shapes": [
+    {
+      "type": "Shape",
+      "id": "http://example.org/User",
+      "closed": true,
+      "expression": {
+        "type": "TripleConstraint",
+        "predicate": "http://example.org/name",
+        "valueExpr": {
+          "type": "NodeConstraint",
+          "datatype": "http://www.w3.org/2001/XMLSchema#string"
+        }
+      }
+    }
+  ]
+}"#;
+
+    #[test]
+    fn i_26_1() -> Result<(), Box<dyn Error>> {
+        println!("Running test: Compare the validation of the same data with two schema versions");
+
+        let browser = Browser::default()?;
+        let tab = browser.new_tab()?;
+
+        tab.navigate_to("http://localhost:8000/")?;
+        let selector = "#editors-container";
+        tab.wait_for_element(selector)?;
+
+        tab.evaluate(
+            &format!(
+                r#"
+            window.yateInstance.setValue(`{}`);
+            window.yasheInstance.setValue(`{}`);
+            document.querySelector('#shapemap-editor').value = ':alice@:User';
+            document.querySelector('#shapemap-editor').dispatchEvent(new Event('input', {{ bubbles: true }}));
+            "#,
+                RDF, SHEX_V1
+            ),
+            false,
+        )?;
+
+        tab.wait_for_element("#add-shex-tab")?.click()?;
+        tab.wait_for_element("#shex-tab-1")?;
+        tab.evaluate(
+            &format!(
+                r#"
+            window.yasheInstance.setValue(`{}`);
+            "#,
+                SHEX_V2
+            ),
+            false,
+        )?;
+
+        tab.wait_for_element("#compare-schemas-btn")?.click()?;
+        tab.wait_for_element("#comparison-matrix")?;
+
+        let remote_object = tab.evaluate(
+            r#"
+            Array.from(document.querySelectorAll('#comparison-matrix tr'))
+                .map(row => Array.from(row.cells).map(cell => cell.textContent.trim()).join(' '))
+                .join(' | ');
+            "#,
+            false,
+        )?;
+
+        match remote_object.value {
+            Some(returned_value) => {
+                assert_eq!(
+                    returned_value.as_str().unwrap_or(""),
+                    "Node Shape Schema 1 Schema 2 | :alice :User Valid Invalid"
+                )
+            }
+            _ => unreachable!(),
+        };
+
+        Ok(())
+    }
+
+    #[test]
+    fn i_26_2() -> Result<(), Box<dyn Error>> {
+        println!("Running test: Compare schema versions written in different ShEx formats");
+
+        let browser = Browser::default()?;
+        let tab = browser.new_tab()?;
+
+        tab.navigate_to("http://localhost:8000/")?;
+        let selector = "#editors-container";
+        tab.wait_for_element(selector)?;
+
+        tab.evaluate(
+            &format!(
+                r#"
+            window.yateInstance.setValue(`{}`);
+            window.yasheInstance.setValue(`{}`);
+            document.querySelector('#shapemap-editor').value = ':alice@:User';
+            document.querySelector('#shapemap-editor').dispatchEvent(new Event('input', {{ bubbles: true }}));
+            "#,
+                RDF, SHEX_V1
+            ),
+            false,
+        )?;
+
+        tab.wait_for_element("#add-shex-tab")?.click()?;
+        tab.wait_for_element("#shex-tab-1")?;
+        tab.evaluate(
+            &format!(
+                r#"
+            window.yasheInstance.setValue(`{}`);
+            "#,
+                SHEXJ_V2
+            ),
+            false,
+        )?;
+
+        tab.wait_for_element("#compare-schemas-btn")?.click()?;
+        tab.wait_for_element("#comparison-matrix")?;
+
+        let remote_object = tab.evaluate(
+            r#"
+            Array.from(document.querySelectorAll('#comparison-matrix tr'))
+                .slice(1)
+                .map(row => Array.from(row.cells).slice(2).map(cell => cell.textContent.trim()).join(' '))
+                .join(' | ');
+            "#,
+            false,
+        )?;
+
+        match remote_object.value {
+            Some(returned_value) => {
+                assert_eq!(returned_value.as_str().unwrap_or(""), "Valid Invalid")
+            }
+            _ => unreachable!(),
+        };
+
+        Ok(())
+    }
+}
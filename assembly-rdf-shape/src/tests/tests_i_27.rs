@@ -0,0 +1,228 @@
+#[cfg(test)]
+mod tests_i_27 {
+    use headless_chrome::Browser;
+    use std::error::Error;
+
+    const RDF_ALICE: &str = r#"
+PREFIX : <http://example.org/>
+
+:alice :name "Alice" .
+"#;
+
+    const RDF_BOB: &str = r#"
+PREFIX : <http://example.org/>
+
+:bob :name 23 .
+"#;
+
+    const SHEX: &str = r#"
+PREFIX : <http://example.org/>
+PREFIX xsd: <http://www.w3.org/2001/XMLSchema#>
+
+:User {
+  :name xsd:string
+}
+"#;
+
+    #[test]
+    fn i_27_1() -> Result<(), Box<dyn Error>> {
+        println!(
+            "Running test: Validate two merged data tabs and restore the tabs after reloading"
+        );
+
+        let browser = Browser::default()?;
+        let tab = browser.new_tab()?;
+
+        tab.navigate_to("http://localhost:8000/")?;
+        let selector = "#editors-container";
+        tab.wait_for_element(selector)?;
+
+        tab.evaluate(
+            r#"
+            window.localStorage.clear();
+            "#,
+            false,
+        )?;
+        tab.reload(false, None)?;
+        tab.wait_for_element(selector)?;
+
+        tab.evaluate(
+            &format!(
+                r#"
+            window.yateInstance.setValue(`{}`);
+            window.yasheInstance.setValue(`{}`);
+            document.querySelector('#shapemap-editor').value = ':alice@:User, :bob@:User';
+            document.querySelector('#shapemap-editor').dispatchEvent(new Event('input', {{ bubbles: true }}));
+            "#,
+                RDF_ALICE, SHEX
+            ),
+            false,
+        )?;
+
+        tab.wait_for_element("#add-rdf-tab")?.click()?;
+        tab.wait_for_element("#rdf-tab-1")?;
+        tab.evaluate(
+            &format!(
+                r#"
+            window.yateInstance.setValue(`{}`);
+            "#,
+                RDF_BOB
+            ),
+            false,
+        )?;
+
+        tab.wait_for_element("#validate-btn")?.click()?;
+        tab.wait_for_element("#result-table")?;
+
+        let remote_object = tab.evaluate(
+            r#"
+            Array.from(document.querySelectorAll('#result-table tr'))
+                .slice(1)
+                .map(row => row.cells[0].textContent.trim() + ':' + row.cells[2].textContent.trim())
+                .sort()
+                .join(' ');
+            "#,
+            false,
+        )?;
+
+        match remote_object.value {
+            Some(returned_value) => {
+                assert_eq!(
+                    returned_value.as_str().unwrap_or(""),
+                    ":alice:Valid :bob:Invalid"
+                )
+            }
+            _ => unreachable!(),
+        };
+
+        std::thread::sleep(std::time::Duration::from_secs(1));
+        tab.reload(false, None)?;
+        tab.wait_for_element("#rdf-tab-1")?;
+
+        let remote_object = tab.evaluate(
+            r#"
+            document.querySelector('#rdf-tab-1').textContent.trim() + ' ' +
+            window.yateInstance.getValue().includes(':bob');
+            "#,
+            false,
+        )?;
+
+        match remote_object.value {
+            Some(returned_value) => {
+                assert_eq!(returned_value.as_str().unwrap_or(""), "Data 2 true")
+            }
+            _ => unreachable!(),
+        };
+
+        Ok(())
+    }
+
+    #[test]
+    fn i_27_2() -> Result<(), Box<dyn Error>> {
+        println!("Running test: Report a JSON ShapeMap tab that cannot be merged");
+
+        let browser = Browser::default()?;
+        let tab = browser.new_tab()?;
+
+        tab.navigate_to("http://localhost:8000/")?;
+        let selector = "#editors-container";
+        tab.wait_for_element(selector)?;
+
+        tab.evaluate(
+            r#"
+            window.localStorage.clear();
+            "#,
+            false,
+        )?;
+        tab.reload(false, None)?;
+        tab.wait_for_element(selector)?;
+
+        tab.evaluate(
+            &format!(
+                r#"
+            window.yateInstance.setValue(`{}`);
+            window.yasheInstance.setValue(`{}`);
+            document.querySelector('#select-shapemap').value = 'JSON';
+            document.querySelector('#select-shapemap').dispatchEvent(new Event('change', {{ bubbles: true }}));
+            document.querySelector('#shapemap-editor').value = '[{{"node": "http://example.org/alice", "shape": "http://example.org/User"}}]';
+            document.querySelector('#shapemap-editor').dispatchEvent(new Event('input', {{ bubbles: true }}));
+            "#,
+                RDF_ALICE, SHEX
+            ),
+            false,
+        )?;
+
+        tab.wait_for_element("#add-shapemap-tab")?.click()?;
+        tab.wait_for_element("#shapemap-tab-1")?;
+        tab.evaluate(
+            r#"
+            document.querySelector('#shapemap-editor').value = '[{"node": "http://example.org/bob",';
+            document.querySelector('#shapemap-editor').dispatchEvent(new Event('input', { bubbles: true }));
+            "#,
+            false,
+        )?;
+
+        tab.wait_for_element("#validate-btn")?.click()?;
+        tab.wait_for_element("#shapemap-merge-error")?;
+
+        let remote_object = tab.evaluate(
+            r#"
+            document.querySelector('#shapemap-merge-error').textContent.includes('is not a valid JSON ShapeMap') + ' ' +
+            (document.querySelector('#result-table') === null);
+            "#,
+            false,
+        )?;
+
+        match remote_object.value {
+            Some(returned_value) => {
+                assert_eq!(returned_value.as_str().unwrap_or(""), "true true")
+            }
+            _ => unreachable!(),
+        };
+
+        Ok(())
+    }
+
+    #[test]
+    fn i_27_3() -> Result<(), Box<dyn Error>> {
+        println!("Running test: Discard a stored workspace with a panel without tabs");
+
+        let browser = Browser::default()?;
+        let tab = browser.new_tab()?;
+
+        tab.navigate_to("http://localhost:8000/")?;
+        let selector = "#editors-container";
+        tab.wait_for_element(selector)?;
+
+        tab.evaluate(
+            r#"
+            window.localStorage.setItem('rdf-validator-workspace', JSON.stringify({
+                rdf: { label: 'Data', tabs: [], active: 0 },
+                shex: { label: 'Schema', tabs: [{ name: 'Schema 1', content: '' }], active: 3 },
+                shapemap: { label: 'ShapeMap', tabs: [{ name: 'ShapeMap 1', content: '' }], active: 0 },
+                merge_data: true
+            }));
+            "#,
+            false,
+        )?;
+        tab.reload(false, None)?;
+        tab.wait_for_element("#rdf-tab-0")?;
+
+        let remote_object = tab.evaluate(
+            r#"
+            document.querySelector('#rdf-tab-0').textContent.trim() + ' ' +
+            document.querySelector('#shex-tab-0').textContent.trim();
+            "#,
+            false,
+        )?;
+
+        match remote_object.value {
+            Some(returned_value) => {
+                assert_eq!(returned_value.as_str().unwrap_or(""), "Data 1 Schema 1")
+            }
+            _ => unreachable!(),
+        };
+
+        Ok(())
+    }
+}
@@ -0,0 +1,70 @@
+#[cfg(test)]
+mod tests_i_28 {
+    use headless_chrome::Browser;
+    use std::error::Error;
+
+    const RDF: &str = r#"
+PREFIX : <http://example.org/>
+
+:alice :name "Alice" .
+"#;
+
+    const SHEX: &str = r#"
+PREFIX : <http://example.org/>
+PREFIX xsd: <http://www.w3.org/2001/XMLSchema#>
+
+:User {
+  :name xsd:string
+}
+"#;
+
+    #[test]
+    fn i_28_1() -> Result<(), Box<dyn Error>> {
+        println!("Running test: Cancel a validation and ignore its response");
+
+        let browser = Browser::default()?;
+        let tab = browser.new_tab()?;
+
+        tab.navigate_to("http://localhost:8000/")?;
+        let selector = "#editors-container";
+        tab.wait_for_element(selector)?;
+
+        tab.evaluate(
+            &format!(
+                r#"
+            window.yateInstance.setValue(`{}`);
+            window.yasheInstance.setValue(`{}`);
+            document.querySelector('#shapemap-editor').value = ':alice@:User';
+            document.querySelector('#shapemap-editor').dispatchEvent(new Event('input', {{ bubbles: true }}));
+            "#,
+                RDF, SHEX
+            ),
+            false,
+        )?;
+
+        tab.wait_for_element("#validate-btn")?.click()?;
+        tab.wait_for_element("#cancel-validation")?.click()?;
+
+        std::thread::sleep(std::time::Duration::from_secs(5));
+
+        let remote_object = tab.evaluate(
+            r#"
+            (document.querySelector('#result-table') === null) + ' ' +
+            (document.querySelector('.spinner') === null);
+            "#,
+            false,
+        )?;
+
+        match remote_object.value {
+            Some(returned_value) => {
+                assert_eq!(returned_value.as_str().unwrap_or(""), "true true")
+            }
+            _ => unreachable!(),
+        };
+
+        tab.wait_for_element("#validate-btn")?.click()?;
+        tab.wait_for_element("#result-table")?;
+
+        Ok(())
+    }
+}
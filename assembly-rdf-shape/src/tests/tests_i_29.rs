@@ -0,0 +1,89 @@
+#[cfg(test)]
+mod tests_i_29 {
+    use headless_chrome::Browser;
+    use std::error::Error;
+
+    const RDF: &str = r#"
+PREFIX : <http://example.org/>
+
+:alice :name "Alice" .
+"#;
+
+    const SHEX: &str = r#"
+PREFIX : <http://example.org/>
+PREFIX xsd: <http://www.w3.org/2001/XMLSchema#>
+
+:User {
+  :name xsd:string
+}
+"#;
+
+    #[test]
+    fn i_29_1() -> Result<(), Box<dyn Error>> {
+        println!("Running test: Retry a validation after transient server errors");
+
+        let browser = Browser::default()?;
+        let tab = browser.new_tab()?;
+
+        tab.navigate_to("http://localhost:8000/")?;
+        let selector = "#editors-container";
+        tab.wait_for_element(selector)?;
+
+        tab.evaluate(
+            r#"
+            window.localStorage.setItem('rdf-validator-request-policy',
+                JSON.stringify({ timeout_secs: 60, max_attempts: 3, initial_backoff_ms: 2000 }));
+            "#,
+            false,
+        )?;
+        tab.reload(false, None)?;
+        tab.wait_for_element(selector)?;
+
+        // Las dos primeras validaciones devuelven un error transitorio del servidor
+        tab.evaluate(
+            &format!(
+                r#"
+            window.failedValidations = 0;
+            const originalFetch = window.fetch;
+            window.fetch = function (input, init) {{
+                const url = typeof input === 'string' ? input : input.url;
+                if (url.endsWith('/schema/validate') && window.failedValidations < 2) {{
+                    window.failedValidations += 1;
+                    return Promise.resolve(new Response('', {{ status: 503 }}));
+                }}
+                return originalFetch.call(window, input, init);
+            }};
+            window.yateInstance.setValue(`{}`);
+            window.yasheInstance.setValue(`{}`);
+            document.querySelector('#shapemap-editor').value = ':alice@:User';
+            document.querySelector('#shapemap-editor').dispatchEvent(new Event('input', {{ bubbles: true }}));
+            "#,
+                RDF, SHEX
+            ),
+            false,
+        )?;
+
+        tab.wait_for_element("#validate-btn")?.click()?;
+        let retry_status = tab.wait_for_element("#retry-status")?.get_inner_text()?;
+        assert!(retry_status.starts_with("Retrying (2/3)"));
+
+        tab.wait_for_element("#result-table")?;
+
+        let remote_object = tab.evaluate(
+            r#"
+            window.failedValidations + ' ' +
+            document.querySelectorAll('#result-table tr')[1].cells[2].textContent.trim();
+            "#,
+            false,
+        )?;
+
+        match remote_object.value {
+            Some(returned_value) => {
+                assert_eq!(returned_value.as_str().unwrap_or(""), "2 Valid")
+            }
+            _ => unreachable!(),
+        };
+
+        Ok(())
+    }
+}
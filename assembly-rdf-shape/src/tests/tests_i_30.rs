@@ -0,0 +1,93 @@
+#[cfg(test)]
+mod tests_i_30 {
+    use headless_chrome::Browser;
+    use std::error::Error;
+
+    const RDF: &str = r#"
+PREFIX : <http://example.org/>
+
+:alice :name "Alice" .
+"#;
+
+    const SHEX: &str = r#"
+PREFIX : <http://example.org/>
+PREFIX xsd: <http://www.w3.org/2001/XMLSchema#>
+
+:User {
+  :name xsd:string
+}
+"#;
+
+    #[test]
+    fn i_30_1() -> Result<(), Box<dyn Error>> {
+        println!("Running test: Reuse a cached validation and validate again without the cache");
+
+        let browser = Browser::default()?;
+        let tab = browser.new_tab()?;
+
+        tab.navigate_to("http://localhost:8000/")?;
+        let selector = "#editors-container";
+        tab.wait_for_element(selector)?;
+
+        tab.evaluate(
+            r#"
+            window.localStorage.removeItem('rdf-validator-request-policy');
+            "#,
+            false,
+        )?;
+        tab.reload(false, None)?;
+        tab.wait_for_element(selector)?;
+
+        // Cuenta las validaciones que llegan al servidor
+        tab.evaluate(
+            &format!(
+                r#"
+            window.sentValidations = 0;
+            const originalFetch = window.fetch;
+            window.fetch = function (input, init) {{
+                const url = typeof input === 'string' ? input : input.url;
+                if (url.endsWith('/schema/validate')) {{
+                    window.sentValidations += 1;
+                }}
+                return originalFetch.call(window, input, init);
+            }};
+            window.yateInstance.setValue(`{}`);
+            window.yasheInstance.setValue(`{}`);
+            document.querySelector('#shapemap-editor').value = ':alice@:User';
+            document.querySelector('#shapemap-editor').dispatchEvent(new Event('input', {{ bubbles: true }}));
+            "#,
+                RDF, SHEX
+            ),
+            false,
+        )?;
+
+        tab.wait_for_element("#validate-btn")?.click()?;
+        tab.wait_for_element("#result-table")?;
+        assert!(tab.find_element("#cached-result").is_err());
+
+        tab.wait_for_element("#validate-btn")?.click()?;
+        tab.wait_for_element("#cached-result")?;
+        tab.wait_for_element("#result-table")?;
+
+        tab.wait_for_element("#revalidate-without-cache")?.click()?;
+        std::thread::sleep(std::time::Duration::from_secs(5));
+        tab.wait_for_element("#result-table")?;
+
+        let remote_object = tab.evaluate(
+            r#"
+            window.sentValidations + ' ' +
+            (document.querySelector('#cached-result') === null);
+            "#,
+            false,
+        )?;
+
+        match remote_object.value {
+            Some(returned_value) => {
+                assert_eq!(returned_value.as_str().unwrap_or(""), "2 true")
+            }
+            _ => unreachable!(),
+        };
+
+        Ok(())
+    }
+}
@@ -0,0 +1,178 @@
+#[cfg(test)]
+mod tests_i_31 {
+    use headless_chrome::Browser;
+    use std::error::Error;
+
+    const RDF: &str = r#"
+PREFIX : <http://example.org/>
+
+:alice :name "Alice" .
+:bob :name "Bob" .
+:carol :name "Carol" .
+"#;
+
+    const RDF_EDITED: &str = r#"
+PREFIX : <http://example.org/>
+
+:alice :name "Alice" .
+:bob :name 23 .
+:carol :name "Carol" .
+"#;
+
+    const SHEX: &str = r#"
+PREFIX : <http://example.org/>
+PREFIX xsd: <http://www.w3.org/2001/XMLSchema#>
+
+:User {
+  :name xsd:string
+}
+"#;
+
+    const RDF_BLANK: &str = r#"
+PREFIX : <http://example.org/>
+
+:alice :name "Alice" ;
+       :address [ :city "Oviedo" ] .
+:bob :name "Bob" .
+"#;
+
+    const RDF_BLANK_EDITED: &str = r#"
+PREFIX : <http://example.org/>
+
+:alice :name "Alice" ;
+       :address [ :city "Gijón" ] .
+:bob :name "Bob" .
+"#;
+
+    #[test]
+    fn i_31_1() -> Result<(), Box<dyn Error>> {
+        println!("Running test: Re-validate only the nodes affected by a data change");
+
+        let browser = Browser::default()?;
+        let tab = browser.new_tab()?;
+
+        tab.navigate_to("http://localhost:8000/")?;
+        let selector = "#editors-container";
+        tab.wait_for_element(selector)?;
+
+        tab.evaluate(
+            &format!(
+                r#"
+            window.yateInstance.setValue(`{}`);
+            window.yasheInstance.setValue(`{}`);
+            document.querySelector('#shapemap-editor').value = ':alice@:User, :bob@:User, :carol@:User';
+            document.querySelector('#shapemap-editor').dispatchEvent(new Event('input', {{ bubbles: true }}));
+            "#,
+                RDF, SHEX
+            ),
+            false,
+        )?;
+
+        tab.wait_for_element("#incremental-validation")?.click()?;
+        tab.wait_for_element("#validate-btn")?.click()?;
+        tab.wait_for_element("#result-table")?;
+
+        tab.evaluate(
+            &format!(
+                r#"
+            window.yateInstance.setValue(`{}`);
+            "#,
+                RDF_EDITED
+            ),
+            false,
+        )?;
+
+        tab.wait_for_element("#validate-btn")?.click()?;
+        let summary = tab
+            .wait_for_element("#incremental-summary")?
+            .get_inner_text()?;
+        assert!(summary.contains("1 of 3 associations re-validated"));
+
+        let remote_object = tab.evaluate(
+            r#"
+            Array.from(document.querySelectorAll('#result-table tr'))
+                .slice(1)
+                .map(row => row.cells[0].textContent.trim() + ':' + row.cells[2].textContent.trim())
+                .join(' ');
+            "#,
+            false,
+        )?;
+
+        match remote_object.value {
+            Some(returned_value) => {
+                assert_eq!(
+                    returned_value.as_str().unwrap_or(""),
+                    ":alice:Valid :bob:Invalid :carol:Valid"
+                )
+            }
+            _ => unreachable!(),
+        };
+
+        Ok(())
+    }
+
+    #[test]
+    fn i_31_2() -> Result<(), Box<dyn Error>> {
+        println!("Running test: Validate all the nodes when a change involves a blank node");
+
+        let browser = Browser::default()?;
+        let tab = browser.new_tab()?;
+
+        tab.navigate_to("http://localhost:8000/")?;
+        let selector = "#editors-container";
+        tab.wait_for_element(selector)?;
+
+        tab.evaluate(
+            &format!(
+                r#"
+            window.yateInstance.setValue(`{}`);
+            window.yasheInstance.setValue(`{}`);
+            document.querySelector('#shapemap-editor').value = ':alice@:User, :bob@:User';
+            document.querySelector('#shapemap-editor').dispatchEvent(new Event('input', {{ bubbles: true }}));
+            "#,
+                RDF_BLANK, SHEX
+            ),
+            false,
+        )?;
+
+        tab.wait_for_element("#incremental-validation")?.click()?;
+        tab.wait_for_element("#validate-btn")?.click()?;
+        tab.wait_for_element("#result-table")?;
+
+        tab.evaluate(
+            &format!(
+                r#"
+            window.yateInstance.setValue(`{}`);
+            "#,
+                RDF_BLANK_EDITED
+            ),
+            false,
+        )?;
+
+        tab.wait_for_element("#validate-btn")?.click()?;
+        std::thread::sleep(std::time::Duration::from_secs(4));
+
+        let remote_object = tab.evaluate(
+            r#"
+            (document.querySelector('#incremental-summary') === null) + ' ' +
+                Array.from(document.querySelectorAll('#result-table tr'))
+                    .slice(1)
+                    .map(row => row.cells[0].textContent.trim() + ':' + row.cells[2].textContent.trim())
+                    .join(' ');
+            "#,
+            false,
+        )?;
+
+        match remote_object.value {
+            Some(returned_value) => {
+                assert_eq!(
+                    returned_value.as_str().unwrap_or(""),
+                    "true :alice:Valid :bob:Valid"
+                )
+            }
+            _ => unreachable!(),
+        };
+
+        Ok(())
+    }
+}
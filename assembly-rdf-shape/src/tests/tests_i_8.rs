@@ -0,0 +1,221 @@
+#[cfg(test)]
+mod tests_i_8 {
+    use headless_chrome::Browser;
+    use std::error::Error;
+
+    const E_10: &str = r#"
+prefix :       <http://example.org/>
+prefix xsd:    <http://www.w3.org/2001/XMLSchema#>
+
+:book1  :title      "Book One" ;
+        :related    :book2 .
+
+:book2  :title      "Book Two" ;
+        :related    :book1 .
+"#;
+
+    const E_14: &str = r#"
+PREFIX : <http://example.org/>
+PREFIX schema: <http://schema.org/>
+PREFIX xsd: <http://www.w3.org/2001/XMLSchema#>
+
+:User {
+  schema:name xsd:string ;
+  schema:birthDate xsd:date? ;
+  schema:knows IRI @:User*
+}
+"#;
+
+    #[test]
+    fn i_8_1() -> Result<(), Box<dyn Error>> {
+        println!("Running test: Convert Turtle RDF to N-Triples");
+
+        let browser = Browser::default()?;
+        let tab = browser.new_tab()?;
+
+        tab.navigate_to("http://localhost:8000/")?;
+        let selector = "#editors-container";
+        tab.wait_for_element(selector)?;
+
+        tab.evaluate(
+            &format!(
+                r#"
+            window.yateInstance.setValue(`{}`);
+            "#,
+                E_10.replace("`", "\\`")
+            ),
+            false,
+        )?;
+
+        tab.evaluate(
+            r#"
+            document.getElementById('select-convert-rdf').value = 'N-Triples';
+            // Trigger change event
+            var event = new Event('change', { bubbles: true });
+            document.getElementById('select-convert-rdf').dispatchEvent(event);
+            "#,
+            false,
+        )?;
+        tab.evaluate(
+            r#"
+            $('#convert-rdf').click();
+            "#,
+            false,
+        )?;
+
+        std::thread::sleep(std::time::Duration::from_secs(2));
+
+        let remote_object = tab.evaluate(
+            r#"
+            window.yateInstance.getValue();
+            "#,
+            false,
+        )?;
+
+        match remote_object.value {
+            Some(returned_value) => {
+                assert!(returned_value
+                    .as_str()
+                    .unwrap_or("")
+                    .contains("<http://example.org/book1> <http://example.org/title> \"Book One\""))
+            }
+            _ => unreachable!(),
+        };
+
+        let remote_object = tab.evaluate(
+            r#"
+            document.getElementById('select-rdf').value;
+            "#,
+            false,
+        )?;
+
+        match remote_object.value {
+            Some(returned_value) => {
+                assert_eq!(returned_value.as_str().unwrap_or(""), "N-Triples")
+            }
+            _ => unreachable!(),
+        };
+
+        Ok(())
+    }
+
+    #[test]
+    fn i_8_2() -> Result<(), Box<dyn Error>> {
+        println!("Running test: Convert invalid RDF");
+
+        let browser = Browser::default()?;
+        let tab = browser.new_tab()?;
+
+        tab.navigate_to("http://localhost:8000/")?;
+        let selector = "#editors-container";
+        tab.wait_for_element(selector)?;
+
+        tab.evaluate(
+            r#"
+            window.yateInstance.setValue(`invalid rdf entry`);
+            "#,
+            false,
+        )?;
+
+        tab.evaluate(
+            r#"
+            $('#convert-rdf').click();
+            "#,
+            false,
+        )?;
+
+        std::thread::sleep(std::time::Duration::from_secs(2));
+
+        let remote_object = tab.evaluate(
+            r#"
+            (function() {
+                let modalBody = document.getElementById('modal-body');
+                return modalBody ? modalBody.textContent : '';
+            })()
+            "#,
+            false,
+        )?;
+
+        match remote_object.value {
+            Some(returned_value) => {
+                assert_eq!(
+                    returned_value.as_str().unwrap_or("").trim(),
+                    "Status: RDF could not be converted to Turtle."
+                )
+            }
+            _ => unreachable!(),
+        };
+
+        Ok(())
+    }
+
+    #[test]
+    fn i_8_3() -> Result<(), Box<dyn Error>> {
+        println!("Running test: Convert ShExC schema to ShExJ");
+
+        let browser = Browser::default()?;
+        let tab = browser.new_tab()?;
+
+        tab.navigate_to("http://localhost:8000/")?;
+        let selector = "#editors-container";
+        tab.wait_for_element(selector)?;
+
+        tab.evaluate(
+            &format!(
+                r#"
+            window.yasheInstance.setValue(`{}`);
+            "#,
+                E_14.replace("`", "\\`")
+            ),
+            false,
+        )?;
+
+        tab.evaluate(
+            r#"
+            document.getElementById('select-convert-shex').value = 'ShExJ';
+            // Trigger change event
+            var event = new Event('change', { bubbles: true });
+            document.getElementById('select-convert-shex').dispatchEvent(event);
+            "#,
+            false,
+        )?;
+        tab.evaluate(
+            r#"
+            $('#convert-shex').click();
+            "#,
+            false,
+        )?;
+
+        std::thread::sleep(std::time::Duration::from_secs(2));
+
+        let remote_object = tab.evaluate(
+            r#"
+            JSON.parse(window.yasheInstance.getValue()).type;
+            "#,
+            false,
+        )?;
+
+        match remote_object.value {
+            Some(returned_value) => {
+                assert_eq!(returned_value.as_str().unwrap_or(""), "Schema")
+            }
+            _ => unreachable!(),
+        };
+
+        let remote_object = tab.evaluate(
+            r#"
+            document.getElementById('select-shex').value;
+            "#,
+            false,
+        )?;
+
+        match remote_object.value {
+            Some(returned_value) => {
+                assert_eq!(returned_value.as_str().unwrap_or(""), "ShExJ")
+            }
+            _ => unreachable!(),
+        };
+
+        Ok(())
+    }
+}
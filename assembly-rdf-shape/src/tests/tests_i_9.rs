@@ -0,0 +1,211 @@
+#[cfg(test)]
+mod tests_i_9 {
+    use headless_chrome::Browser;
+    use std::error::Error;
+
+    const E_7: &str = r#"
+    [{"@id":"_:b0","http://example.org/name":[{"@value":"Unknown title"}]},{"@id":"http://example.org/a","http://example.org/name":[{"@value":"Title A"}],"http://example.org/related":[{"@id":"http://example.org/b"}]},{"@id":"http://example.org/b","http://example.org/related":[{"@id":"http://example.org/a"}],"http://example.org/name":[{"@value":"Title B"}]},{"@id":"http://example.org/c","http://example.org/name":[{"@value":"Title C1"},{"@value":"Title C2"}]},{"@id":"http://example.org/d","http://example.org/name":[{"@value":234}]},{"@id":"http://example.org/e","http://example.org/namme":[{"@value":"Title E"}]},{"@id":"http://example.org/f","http://example.org/name":[{"@value":"Title F"}],"http://example.org/related":[{"@id":"http://example.org/a"},{"@id":"_:b0"}]}]
+    "#;
+
+    const E_SHEXC_WITH_SHEX_PREFIX: &str = r#"
+PREFIX :       <http://example.org/>
+PREFIX shex:   <http://www.w3.org/ns/shex#>
+PREFIX xsd:    <http://www.w3.org/2001/XMLSchema#>
+
+:User {
+  :name xsd:string
+}
+    "#;
+
+    #[test]
+    fn i_9_1() -> Result<(), Box<dyn Error>> {
+        println!("Running test: Detect JSON-LD RDF format before analysis");
+
+        let browser = Browser::default()?;
+        let tab = browser.new_tab()?;
+
+        tab.navigate_to("http://localhost:8000/")?;
+        let selector = "#editors-container";
+        tab.wait_for_element(selector)?;
+
+        tab.evaluate(
+            &format!(
+                r#"
+            window.yateInstance.setValue(`{}`);
+            "#,
+                E_7.replace("`", "\\`")
+            ),
+            false,
+        )?;
+
+        tab.evaluate(
+            r#"
+            $('#analyze-rdf').click();
+            "#,
+            false,
+        )?;
+
+        std::thread::sleep(std::time::Duration::from_secs(2));
+
+        let remote_object = tab.evaluate(
+            r#"
+            document.getElementById('select-rdf').value;
+            "#,
+            false,
+        )?;
+
+        match remote_object.value {
+            Some(returned_value) => {
+                assert_eq!(returned_value.as_str().unwrap_or(""), "JSON-LD")
+            }
+            _ => unreachable!(),
+        };
+
+        let remote_object = tab.evaluate(
+            r#"
+            (function() {
+                let modalBody = document.getElementById('modal-body');
+                return modalBody ? modalBody.textContent : '';
+            })()
+            "#,
+            false,
+        )?;
+
+        match remote_object.value {
+            Some(returned_value) => {
+                assert_eq!(
+                    returned_value.as_str().unwrap_or("").trim(),
+                    "Status: Well formed RDFNumber of statements: 12"
+                )
+            }
+            _ => unreachable!(),
+        };
+
+        tab.wait_for_element("#format-notice")?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn i_9_2() -> Result<(), Box<dyn Error>> {
+        println!("Running test: Detect JSON ShapeMap format while typing");
+
+        let browser = Browser::default()?;
+        let tab = browser.new_tab()?;
+
+        tab.navigate_to("http://localhost:8000/")?;
+        let selector = "#editors-container";
+        tab.wait_for_element(selector)?;
+
+        tab.evaluate(
+            r#"
+            document.querySelector('#shapemap-editor').value = '[{"node": "http://example.org/a", "shape": "http://example.org/Book"}]';
+            var event = new Event('input', { bubbles: true });
+            document.querySelector('#shapemap-editor').dispatchEvent(event);
+            "#,
+            false,
+        )?;
+
+        std::thread::sleep(std::time::Duration::from_secs(1));
+
+        let remote_object = tab.evaluate(
+            r#"
+            document.getElementById('select-shapemap').value;
+            "#,
+            false,
+        )?;
+
+        match remote_object.value {
+            Some(returned_value) => {
+                assert_eq!(returned_value.as_str().unwrap_or(""), "JSON")
+            }
+            _ => unreachable!(),
+        };
+
+        Ok(())
+    }
+
+    #[test]
+    fn i_9_3() -> Result<(), Box<dyn Error>> {
+        println!("Running test: Keep ShExC when the schema declares the ShEx namespace");
+
+        let browser = Browser::default()?;
+        let tab = browser.new_tab()?;
+
+        tab.navigate_to("http://localhost:8000/")?;
+        let selector = "#editors-container";
+        tab.wait_for_element(selector)?;
+
+        tab.evaluate(
+            &format!(
+                r#"
+            window.yasheInstance.setValue(`{}`);
+            $('#analyze-shex').click();
+            "#,
+                E_SHEXC_WITH_SHEX_PREFIX.replace("`", "\\`")
+            ),
+            false,
+        )?;
+
+        std::thread::sleep(std::time::Duration::from_secs(2));
+
+        let remote_object = tab.evaluate(
+            r#"
+            document.getElementById('select-shex').value;
+            "#,
+            false,
+        )?;
+
+        match remote_object.value {
+            Some(returned_value) => {
+                assert_eq!(returned_value.as_str().unwrap_or(""), "ShExC")
+            }
+            _ => unreachable!(),
+        };
+
+        Ok(())
+    }
+
+    #[test]
+    fn i_9_4() -> Result<(), Box<dyn Error>> {
+        println!("Running test: Keep the RDF format chosen by the user when validating");
+
+        let browser = Browser::default()?;
+        let tab = browser.new_tab()?;
+
+        tab.navigate_to("http://localhost:8000/")?;
+        let selector = "#editors-container";
+        tab.wait_for_element(selector)?;
+
+        tab.evaluate(
+            &format!(
+                r#"
+            window.yateInstance.setValue(`{}`);
+            document.getElementById('select-rdf').value = 'RDF/XML';
+            document.getElementById('select-rdf').dispatchEvent(new Event('change', {{ bubbles: true }}));
+            $('#validate-btn').click();
+            "#,
+                E_7.replace("`", "\\`")
+            ),
+            false,
+        )?;
+
+        std::thread::sleep(std::time::Duration::from_secs(2));
+
+        let remote_object = tab.evaluate(
+            r#"
+            document.getElementById('select-rdf').value;
+            "#,
+            false,
+        )?;
+
+        match remote_object.value {
+            Some(returned_value) => {
+                assert_eq!(returned_value.as_str().unwrap_or(""), "RDF/XML")
+            }
+            _ => unreachable!(),
+        };
+
+        Ok(())
+    }
+}